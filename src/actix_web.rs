@@ -0,0 +1,399 @@
+//! Actix Web integration.
+//!
+//! [`ConstantPath`] and [`ConstantQuery`] extract a single constant string parameter, named by the
+//! [`parameter_name`](crate::Options::parameter_name) option or the type name, and reject other values with a
+//! [`ConstantRejection`], whose status is set by a [`ConstantConfig`] in the app data. [`ConstantError`] and
+//! [`ProblemDetails`] respond with their status and a JSON body, and a [`ConstantError`] can be returned as the error
+//! of a handler.
+
+use std::{
+    error::Error,
+    fmt,
+    future::{Ready, ready},
+};
+
+use actix_web::{
+    FromRequest, HttpRequest, HttpResponse, ResponseError,
+    dev::Payload,
+    http::{
+        StatusCode,
+        header::{self, ContentType},
+    },
+    web::Query,
+};
+use serde::Serialize;
+
+use crate::{ConstantString, parse};
+
+#[cfg(doc)]
+use crate::{ConstantError, ProblemDetails};
+
+/// Name of the parameter of the constant string `C`, applying its [`Options`](crate::Options).
+pub fn parameter_name<C: ConstantString>() -> &'static str {
+    C::OPTIONS.parameter_name.unwrap_or(C::NAME)
+}
+
+/// Response with the status and `value` serialized as JSON with the content type.
+///
+/// A status that is not a valid HTTP status code responds with `500 Internal Server Error`. Used by the
+/// [`Responder`](actix_web::Responder) and [`ResponseError`] implementations of [`ConstantError`] and
+/// [`ProblemDetails`].
+pub fn json_response<T: Serialize>(status: u16, content_type: &str, value: &T) -> HttpResponse {
+    HttpResponse::build(StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR))
+        .insert_header((header::CONTENT_TYPE, content_type))
+        .json(value)
+}
+
+/// Statuses of the rejections of [`ConstantPath`] and [`ConstantQuery`].
+///
+/// Registered with [`App::app_data`](actix_web::App::app_data) or [`Scope::app_data`](actix_web::Scope::app_data).
+/// Defaults to `404 Not Found` for path parameters and `422 Unprocessable Entity` for query parameters.
+///
+/// # Example
+/// ```
+/// # extern crate actix_web;
+/// #
+/// use actix_web::{App, http::StatusCode};
+/// use constant_string::actix_web::ConstantConfig;
+///
+/// let app = App::new().app_data(ConstantConfig::default().with_path_status(StatusCode::BAD_REQUEST));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ConstantConfig {
+    path_status: StatusCode,
+    query_status: StatusCode,
+}
+
+impl ConstantConfig {
+    /// Set the status of mismatched path parameters.
+    pub fn with_path_status(mut self, status: StatusCode) -> Self {
+        self.path_status = status;
+        self
+    }
+
+    /// Set the status of missing or mismatched query parameters.
+    pub fn with_query_status(mut self, status: StatusCode) -> Self {
+        self.query_status = status;
+        self
+    }
+
+    /// Status of mismatched path parameters.
+    pub fn path_status(&self) -> StatusCode {
+        self.path_status
+    }
+
+    /// Status of missing or mismatched query parameters.
+    pub fn query_status(&self) -> StatusCode {
+        self.query_status
+    }
+
+    fn from_request(req: &HttpRequest) -> Self {
+        req.app_data::<Self>().copied().unwrap_or_default()
+    }
+}
+
+impl Default for ConstantConfig {
+    fn default() -> Self {
+        Self {
+            path_status: StatusCode::NOT_FOUND,
+            query_status: StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+}
+
+/// Rejection of a constant string parameter.
+///
+/// Responds with the status and a plain text message, such as the [`ParseError`](crate::ParseError) of a
+/// mismatched value.
+#[derive(Debug)]
+pub struct ConstantRejection {
+    status: StatusCode,
+    message: String,
+}
+
+impl ConstantRejection {
+    fn new(status: StatusCode, message: impl fmt::Display) -> Self {
+        Self {
+            status,
+            message: message.to_string(),
+        }
+    }
+
+    /// Status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Message of the response.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ConstantRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ConstantRejection {}
+
+impl ResponseError for ConstantRejection {
+    fn status_code(&self) -> StatusCode {
+        self.status
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status)
+            .insert_header(ContentType::plaintext())
+            .body(self.message.clone())
+    }
+}
+
+/// Extractor of the constant string `C` from a path parameter.
+///
+/// The parameter is named by the [`parameter_name`](crate::Options::parameter_name) option or the type name, or is
+/// the only parameter of the route. A mismatched value is rejected with the
+/// [`path_status`](ConstantConfig::path_status) of the [`ConstantConfig`].
+///
+/// # Example
+/// ```
+/// # extern crate actix_web;
+/// #
+/// use actix_web::{App, web};
+/// use constant_string::{actix_web::ConstantPath, constant_string};
+///
+/// constant_string!(Version, VERSION, "v1", parameter_name = "version");
+///
+/// async fn users(_: ConstantPath<Version>) -> &'static str {
+///     "users"
+/// }
+///
+/// let app = App::new().route("/{version}/users", web::get().to(users));
+/// ```
+#[derive(Debug, Default)]
+pub struct ConstantPath<C>(pub C);
+
+impl<C: ConstantString> FromRequest for ConstantPath<C> {
+    type Error = ConstantRejection;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let params = req.match_info();
+        let name = parameter_name::<C>();
+        let value = match params.get(name) {
+            Some(value) => value,
+            None => match params.iter().collect::<Vec<_>>().as_slice() {
+                [(_, value)] => value,
+                _ => {
+                    return ready(Err(ConstantRejection::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format_args!("No path parameter named {name:?}"),
+                    )));
+                }
+            },
+        };
+
+        ready(parse(value).map(Self).map_err(|error| {
+            ConstantRejection::new(ConstantConfig::from_request(req).path_status, error)
+        }))
+    }
+}
+
+/// Extractor of the constant string `C` from a query parameter.
+///
+/// The parameter is named by the [`parameter_name`](crate::Options::parameter_name) option or the type name. A
+/// missing or mismatched value is rejected with the [`query_status`](ConstantConfig::query_status) of the
+/// [`ConstantConfig`].
+#[derive(Debug, Default)]
+pub struct ConstantQuery<C>(pub C);
+
+impl<C: ConstantString> FromRequest for ConstantQuery<C> {
+    type Error = ConstantRejection;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Self::from_query(req))
+    }
+}
+
+impl<C: ConstantString> ConstantQuery<C> {
+    fn from_query(req: &HttpRequest) -> Result<Self, ConstantRejection> {
+        let Query(params) = Query::<Vec<(String, String)>>::from_query(req.query_string())
+            .map_err(|error| ConstantRejection::new(error.status_code(), error))?;
+
+        let status = ConstantConfig::from_request(req).query_status;
+        let name = parameter_name::<C>();
+        let (_, value) = params.iter().find(|(key, _)| key == name).ok_or_else(|| {
+            ConstantRejection::new(status, format_args!("Missing query parameter {name:?}"))
+        })?;
+
+        parse(value)
+            .map(Self)
+            .map_err(|error| ConstantRejection::new(status, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        App,
+        body::to_bytes,
+        http::{StatusCode, header},
+        test::{TestRequest, call_service, init_service},
+        web,
+    };
+
+    use super::{ConstantConfig, ConstantPath, ConstantQuery};
+    use crate::{ProblemDetails, constant_error, constant_string};
+
+    constant_string!(Version, VERSION, "v1", parameter_name = "version");
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_error!(
+        NotFoundError,
+        NotFoundErrorCode,
+        NOT_FOUND_ERROR_CODE,
+        "notFound",
+        status = 404,
+        message = "Entity not found",
+    );
+
+    async fn user(_: ConstantPath<Version>, path: web::Path<(String, u32)>) -> String {
+        path.into_inner().1.to_string()
+    }
+
+    async fn error(ConstantPath(code): ConstantPath<NotFound>) -> String {
+        String::from(&*code)
+    }
+
+    async fn search(ConstantQuery(code): ConstantQuery<NotFound>) -> String {
+        String::from(&*code)
+    }
+
+    async fn entity(path: web::Path<u32>) -> Result<&'static str, NotFoundError> {
+        match path.into_inner() {
+            1 => Ok("entity"),
+            id => Err(NotFoundError::with_message(format!(
+                "Entity {id} not found"
+            ))),
+        }
+    }
+
+    async fn problem() -> ProblemDetails<NotFoundErrorCode> {
+        NotFoundError::default().into()
+    }
+
+    async fn request(uri: &str, config: ConstantConfig) -> (StatusCode, Option<String>, String) {
+        let app = init_service(
+            App::new()
+                .app_data(config)
+                .route("/{version}/users/{id}", web::get().to(user))
+                .route("/errors/{code}", web::get().to(error))
+                .route("/search", web::get().to(search))
+                .route("/entities/{id}", web::get().to(entity))
+                .route("/problem", web::get().to(problem)),
+        )
+        .await;
+        let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let body = to_bytes(response.into_body()).await.unwrap_or_default();
+        (
+            status,
+            content_type,
+            String::from_utf8_lossy(&body).into_owned(),
+        )
+    }
+
+    async fn body(uri: &str) -> (StatusCode, String) {
+        let (status, _, body) = request(uri, ConstantConfig::default()).await;
+        (status, body)
+    }
+
+    #[actix_web::test]
+    async fn path() {
+        assert_eq!(body("/v1/users/1").await, (StatusCode::OK, "1".to_owned()));
+        assert_eq!(
+            body("/v2/users/1").await,
+            (
+                StatusCode::NOT_FOUND,
+                r#"invalid value "v2", expected constant "v1" for Version"#.to_owned()
+            )
+        );
+        assert_eq!(
+            body("/errors/not_found").await,
+            (StatusCode::OK, "notFound".to_owned())
+        );
+        assert_eq!(
+            request(
+                "/v2/users/1",
+                ConstantConfig::default().with_path_status(StatusCode::BAD_REQUEST)
+            )
+            .await
+            .0,
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn query() {
+        assert_eq!(
+            body("/search?NotFound=notFound").await,
+            (StatusCode::OK, "notFound".to_owned())
+        );
+        assert_eq!(
+            body("/search?NotFound=other").await,
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                r#"invalid value "other", expected constant "notFound" or alias "not_found" for NotFound"#
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            body("/search").await,
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                r#"Missing query parameter "NotFound""#.to_owned()
+            )
+        );
+        assert_eq!(
+            request(
+                "/search",
+                ConstantConfig::default().with_query_status(StatusCode::BAD_REQUEST)
+            )
+            .await
+            .0,
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn responder() {
+        assert_eq!(
+            body("/entities/1").await,
+            (StatusCode::OK, "entity".to_owned())
+        );
+        assert_eq!(
+            request("/entities/42", ConstantConfig::default()).await,
+            (
+                StatusCode::NOT_FOUND,
+                Some("application/json".to_owned()),
+                r#"{"code":"notFound","message":"Entity 42 not found"}"#.to_owned()
+            )
+        );
+        assert_eq!(
+            request("/problem", ConstantConfig::default()).await,
+            (
+                StatusCode::NOT_FOUND,
+                Some("application/problem+json".to_owned()),
+                r#"{"type":"notFound","title":"Entity not found","status":404,"detail":"Entity not found"}"#
+                    .to_owned()
+            )
+        );
+    }
+}
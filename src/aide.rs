@@ -0,0 +1,59 @@
+//! Aide integration.
+//!
+//! Implements [`JsonSchema`](schemars_0_9::JsonSchema) from schemars 0.9, which [`aide`](https://docs.rs/aide/latest/aide/)
+//! uses to generate the schemas of operation inputs and outputs, such as `Json<T>`, `Path<T>` and `Query<T>`.
+
+use std::borrow::Cow;
+
+use schemars_0_9::Schema;
+
+use crate::{ConstantString, schema};
+
+/// JSON Schema of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`JsonSchema`](schemars_0_9::JsonSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn schema<C: ConstantString>() -> Schema {
+    schema::json::<C>().into()
+}
+
+/// Schema name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`JsonSchema`](schemars_0_9::JsonSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> Cow<'static, str> {
+    Cow::Borrowed(C::OPTIONS.schema_name.unwrap_or(C::NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars_0_9::{JsonSchema, SchemaGenerator};
+    use serde_json::json;
+
+    use crate::constant_string;
+
+    #[test]
+    fn schema() {
+        constant_string!(
+            NotFound,
+            NOT_FOUND,
+            "notFound",
+            schema_name = "NotFoundCode"
+        );
+
+        let mut generator = SchemaGenerator::default();
+
+        assert_eq!(NotFound::schema_name(), "NotFoundCode");
+        assert_eq!(
+            generator.subschema_for::<NotFound>(),
+            json!({ "$ref": "#/$defs/NotFoundCode" })
+        );
+        assert_eq!(
+            generator.definitions()["NotFoundCode"],
+            json!({
+                "type": "string",
+                "enum": ["notFound"],
+                "default": "notFound",
+                "examples": ["notFound"],
+            })
+        );
+    }
+}
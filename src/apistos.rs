@@ -0,0 +1,78 @@
+//! Apistos integration.
+//!
+//! Implements [`JsonSchema`](apistos_schemars::JsonSchema) from the schemars 0.8 fork used by
+//! [`apistos`](https://docs.rs/apistos/latest/apistos/), and [`ApiComponent`](apistos_core::ApiComponent) for
+//! constant strings used as request or response bodies.
+
+use apistos_models::reference_or::ReferenceOr;
+
+use crate::ConstantString;
+
+crate::schema::schemars_0_8_schema!(
+    /// JSON Schema of the constant string `C`, applying its [`Options`](crate::Options).
+    ///
+    /// Used by the [`JsonSchema`](apistos_schemars::JsonSchema) implementation of
+    /// [`constant_string`](crate::constant_string).
+    apistos_schemars
+);
+
+/// Schema name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`JsonSchema`](apistos_schemars::JsonSchema) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> String {
+    C::OPTIONS.schema_name.unwrap_or(C::NAME).to_owned()
+}
+
+/// Named schema component of the constant string `C`.
+///
+/// Used by the [`ApiComponent`](apistos_core::ApiComponent) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn component<C: ConstantString>() -> Option<(String, ReferenceOr<apistos_models::Schema>)> {
+    Some((name::<C>(), ReferenceOr::Object(schema::<C>())))
+}
+
+#[cfg(test)]
+mod tests {
+    use apistos_core::ApiComponent;
+    use apistos_schemars::{JsonSchema, r#gen::SchemaGenerator};
+    use serde_json::json;
+
+    use crate::constant_string;
+
+    constant_string!(
+        NotFound,
+        NOT_FOUND,
+        "notFound",
+        schema_name = "NotFoundCode"
+    );
+
+    #[test]
+    fn json_schema() {
+        let mut generator = SchemaGenerator::default();
+
+        assert_eq!(NotFound::schema_name(), "NotFoundCode");
+        assert_eq!(
+            serde_json::to_value(generator.subschema_for::<NotFound>())
+                .expect("serializable value"),
+            json!({ "$ref": "#/definitions/NotFoundCode" })
+        );
+    }
+
+    #[test]
+    fn api_component() {
+        let (name, schema) = NotFound::schema().expect("schema");
+
+        assert_eq!(name, "NotFoundCode");
+        assert!(NotFound::child_schemas().is_empty());
+        assert_eq!(
+            serde_json::to_value(schema).expect("serializable value"),
+            json!({
+                "type": "string",
+                "enum": ["notFound"],
+                "default": "notFound",
+                "examples": ["notFound"],
+            })
+        );
+    }
+}
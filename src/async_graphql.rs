@@ -0,0 +1,449 @@
+//! Async GraphQL integration.
+//!
+//! Constant strings are exposed as custom scalars, named by the [`schema_name`](crate::Options::schema_name) option
+//! or the type name, that only accept the constant. Use [`constant_graphql_enum`](crate::constant_graphql_enum) for a
+//! GraphQL enum of a group of constants.
+
+use std::{any, borrow::Cow, sync::Arc};
+
+use async_graphql::{
+    InputType, InputValueError, InputValueResult, OutputType, Value,
+    registry::{Deprecation, MetaEnumValue, MetaType, MetaTypeId, Registry},
+};
+
+use crate::{ConstantString, Options, parse};
+
+/// Scalar name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`InputType`] and [`OutputType`] implementations of [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> Cow<'static, str> {
+    Cow::Borrowed(C::OPTIONS.schema_name.unwrap_or(C::NAME))
+}
+
+/// Scalar type of the constant string `C`, applying its [`Options`](crate::Options).
+pub fn meta_type<C: ConstantString + 'static>() -> MetaType {
+    MetaType::Scalar {
+        name: name::<C>().into_owned(),
+        description: C::OPTIONS.description.map(ToOwned::to_owned),
+        is_valid: Some(Arc::new(is_valid::<C>)),
+        visible: None,
+        inaccessible: false,
+        tags: Vec::new(),
+        specified_by_url: None,
+        directive_invocations: Vec::new(),
+        requires_scopes: Vec::new(),
+    }
+}
+
+/// Register the scalar type of the constant string `C` as an input type.
+///
+/// Used by the [`InputType`] implementation of [`constant_string`](crate::constant_string).
+pub fn create_input_type_info<C: ConstantString + InputType + 'static>(
+    registry: &mut Registry,
+) -> String {
+    registry.create_input_type::<C, _>(MetaTypeId::Scalar, |_| meta_type::<C>())
+}
+
+/// Register the scalar type of the constant string `C` as an output type.
+///
+/// Used by the [`OutputType`] implementation of [`constant_string`](crate::constant_string).
+pub fn create_output_type_info<C: ConstantString + OutputType + 'static>(
+    registry: &mut Registry,
+) -> String {
+    registry.create_output_type::<C, _>(MetaTypeId::Scalar, |_| meta_type::<C>())
+}
+
+/// Parse the constant string `C` from a GraphQL value, applying its [`Options`](crate::Options).
+///
+/// Used by the [`ScalarType`](async_graphql::ScalarType) implementation of [`constant_string`](crate::constant_string).
+pub fn parse_value<C: ConstantString + InputType>(value: Value) -> InputValueResult<C> {
+    match value {
+        Value::String(value) => parse(&value).map_err(InputValueError::custom),
+        value => Err(InputValueError::expected_type(value)),
+    }
+}
+
+/// Whether a GraphQL value is the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`ScalarType`](async_graphql::ScalarType) implementation of [`constant_string`](crate::constant_string).
+pub fn is_valid<C: ConstantString>(value: &Value) -> bool {
+    matches!(value, Value::String(value) if parse::<C>(value).is_ok())
+}
+
+/// GraphQL value of the constant string `C`.
+///
+/// Used by the [`ScalarType`](async_graphql::ScalarType) implementation of [`constant_string`](crate::constant_string).
+pub fn to_value<C: ConstantString>() -> Value {
+    Value::String(C::VALUE.to_owned())
+}
+
+/// Whether `name` is a valid name of a GraphQL enum value.
+///
+/// Used by [`constant_graphql_enum`](crate::constant_graphql_enum) to check the names at compile time.
+pub const fn is_enum_value_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    if bytes.is_empty() || bytes[0].is_ascii_digit() {
+        return false;
+    }
+
+    let mut index = 0;
+    while index < bytes.len() {
+        if !(bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_') {
+            return false;
+        }
+        index += 1;
+    }
+
+    !matches!(bytes, b"true" | b"false" | b"null")
+}
+
+/// Enum type named `name` with values of the names and options of its items.
+pub fn enum_meta_type(
+    name: &str,
+    rust_typename: &'static str,
+    items: &[(&'static str, Options)],
+) -> MetaType {
+    MetaType::Enum {
+        name: name.to_owned(),
+        description: None,
+        enum_values: items
+            .iter()
+            .map(|(name, options)| {
+                (
+                    (*name).to_owned(),
+                    MetaEnumValue {
+                        name: (*name).to_owned(),
+                        description: options.description.map(ToOwned::to_owned),
+                        deprecation: if options.deprecated {
+                            Deprecation::Deprecated { reason: None }
+                        } else {
+                            Deprecation::NoDeprecated
+                        },
+                        visible: None,
+                        inaccessible: false,
+                        tags: Vec::new(),
+                        directive_invocations: Vec::new(),
+                    },
+                )
+            })
+            .collect(),
+        visible: None,
+        inaccessible: false,
+        tags: Vec::new(),
+        rust_typename: Some(rust_typename),
+        directive_invocations: Vec::new(),
+        requires_scopes: Vec::new(),
+    }
+}
+
+/// Register the enum type `T` with the names and options of its items as an input type.
+///
+/// Used by the [`InputType`] implementation of [`constant_graphql_enum`](crate::constant_graphql_enum).
+pub fn create_enum_input_type_info<T: InputType>(
+    registry: &mut Registry,
+    items: &[(&'static str, Options)],
+) -> String {
+    registry.create_input_type::<T, _>(MetaTypeId::Enum, |_| {
+        enum_meta_type(&T::type_name(), any::type_name::<T>(), items)
+    })
+}
+
+/// Register the enum type `T` with the names and options of its items as an output type.
+///
+/// Used by the [`OutputType`] implementation of [`constant_graphql_enum`](crate::constant_graphql_enum).
+pub fn create_enum_output_type_info<T: OutputType>(
+    registry: &mut Registry,
+    items: &[(&'static str, Options)],
+) -> String {
+    registry.create_output_type::<T, _>(MetaTypeId::Enum, |_| {
+        enum_meta_type(&T::type_name(), any::type_name::<T>(), items)
+    })
+}
+
+/// Define a GraphQL enum of a group of constant string types.
+///
+/// Each variant maps to a constant string, and its GraphQL value is named by the constant or by an explicit
+/// `name`, e.g. for screaming case or for constants that aren't valid GraphQL names, which fail to compile. The
+/// descriptions and deprecations of the values come from the [`Options`](crate::Options) of the constants. The enum is
+/// named by the type or an explicit `name`, and gets `as_str()` returning the constant of the variant and [`From`]
+/// for each constant.
+///
+/// # Example
+/// ```
+/// # extern crate async_graphql;
+/// #
+/// use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+/// use constant_string::{constant_graphql_enum, constant_string};
+///
+/// constant_string!(TimeoutErrorCode, TIMEOUT_ERROR_CODE, "timeout");
+/// constant_string!(UnavailableErrorCode, UNAVAILABLE_ERROR_CODE, "service.unavailable");
+///
+/// constant_graphql_enum! {
+///     /// Code of an error worth retrying.
+///     pub enum RetryableErrorCode(name = "RetryableCode") {
+///         Timeout = TimeoutErrorCode,
+///         Unavailable(name = "UNAVAILABLE") = UnavailableErrorCode,
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn code(&self) -> RetryableErrorCode {
+///         UnavailableErrorCode.into()
+///     }
+/// }
+///
+/// let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+/// assert!(sdl.contains("enum RetryableCode {\n\ttimeout\n\tUNAVAILABLE\n}"));
+/// assert_eq!(RetryableErrorCode::Unavailable.as_str(), "service.unavailable");
+/// ```
+#[macro_export]
+macro_rules! constant_graphql_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident $((name = $type_name:literal))? {
+            $($(#[$variant_meta:meta])* $variant:ident $((name = $item:literal))? = $constant:ty),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+            ::core::cmp::PartialEq,
+        )]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        #[allow(dead_code, deprecated)]
+        impl $name {
+            const ITEMS: &'static [(&'static str, $crate::Options)] = &[$((
+                $crate::constant_graphql_enum!(@name $constant $(, $item)?),
+                <$constant as $crate::ConstantString>::OPTIONS,
+            )),+];
+
+            /// Value of the constant string of the variant.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => <$constant as $crate::ConstantString>::VALUE),+
+                }
+            }
+        }
+
+        $(
+            const _: () = ::core::assert!(
+                $crate::async_graphql::is_enum_value_name(
+                    $crate::constant_graphql_enum!(@name $constant $(, $item)?)
+                ),
+                ::core::concat!(
+                    "invalid GraphQL enum value name of ",
+                    ::core::stringify!($variant),
+                    ", set a valid `name`"
+                )
+            );
+
+            #[allow(deprecated)]
+            impl ::core::convert::From<$constant> for $name {
+                fn from(_: $constant) -> Self {
+                    Self::$variant
+                }
+            }
+        )+
+
+        #[allow(deprecated)]
+        impl $crate::__private::async_graphql::EnumType for $name {
+            fn items() -> &'static [$crate::__private::async_graphql::resolver_utils::EnumItem<Self>] {
+                const ITEMS: &[$crate::__private::async_graphql::resolver_utils::EnumItem<$name>] = &[$(
+                    $crate::__private::async_graphql::resolver_utils::EnumItem {
+                        name: $crate::constant_graphql_enum!(@name $constant $(, $item)?),
+                        value: $name::$variant,
+                    }
+                ),+];
+
+                ITEMS
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::async_graphql::InputType for $name {
+            type RawValueType = Self;
+
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed($crate::constant_graphql_enum!(@type_name $name $(, $type_name)?))
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> ::std::string::String {
+                $crate::async_graphql::create_enum_input_type_info::<Self>(registry, Self::ITEMS)
+            }
+
+            fn parse(
+                value: ::core::option::Option<$crate::__private::async_graphql::Value>,
+            ) -> $crate::__private::async_graphql::InputValueResult<Self> {
+                $crate::__private::async_graphql::resolver_utils::parse_enum(
+                    ::core::option::Option::unwrap_or_default(value),
+                )
+            }
+
+            fn to_value(&self) -> $crate::__private::async_graphql::Value {
+                $crate::__private::async_graphql::resolver_utils::enum_value(*self)
+            }
+
+            fn as_raw_value(&self) -> ::core::option::Option<&Self::RawValueType> {
+                ::core::option::Option::Some(self)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::async_graphql::OutputType for $name {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed($crate::constant_graphql_enum!(@type_name $name $(, $type_name)?))
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> ::std::string::String {
+                $crate::async_graphql::create_enum_output_type_info::<Self>(registry, Self::ITEMS)
+            }
+
+            async fn resolve(
+                &self,
+                _: &$crate::__private::async_graphql::ContextSelectionSet<'_>,
+                _: &$crate::__private::async_graphql::Positioned<
+                    $crate::__private::async_graphql::parser::types::Field,
+                >,
+            ) -> $crate::__private::async_graphql::ServerResult<
+                $crate::__private::async_graphql::Value,
+            > {
+                ::core::result::Result::Ok($crate::__private::async_graphql::resolver_utils::enum_value(*self))
+            }
+        }
+    };
+    (@name $constant:ty) => {
+        <$constant as $crate::ConstantString>::VALUE
+    };
+    (@name $constant:ty, $name:literal) => {
+        $name
+    };
+    (@type_name $name:ident) => {
+        ::core::stringify!($name)
+    };
+    (@type_name $name:ident, $type_name:literal) => {
+        $type_name
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, value};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Conflict, CONFLICT, "entity.conflict", deprecated);
+    constant_string!(
+        Described,
+        DESCRIBED,
+        "described",
+        schema_name = "DescribedCode",
+        description = "Described constant.",
+    );
+
+    constant_graphql_enum! {
+        enum ErrorCode(name = "EntityErrorCode") {
+            NotFound = NotFound,
+            Conflict(name = "CONFLICT") = Conflict,
+            Described = Described,
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn code(&self, code: NotFound) -> NotFound {
+            code
+        }
+
+        async fn described(&self) -> Described {
+            Described
+        }
+
+        async fn error_code(&self, code: ErrorCode) -> ErrorCode {
+            code
+        }
+    }
+
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+    }
+
+    #[test]
+    fn sdl() {
+        let sdl = schema().sdl();
+
+        assert!(sdl.contains("scalar NotFound\n"), "{sdl}");
+        assert!(
+            sdl.contains("\"\"\"\nDescribed constant.\n\"\"\"\nscalar DescribedCode\n"),
+            "{sdl}"
+        );
+        assert!(sdl.contains("code(code: NotFound!): NotFound!"), "{sdl}");
+        assert!(
+            sdl.contains(
+                "enum EntityErrorCode {\n\tnotFound\n\tCONFLICT @deprecated\n\t\"\"\"\n\tDescribed constant.\n\t\"\"\"\n\tdescribed\n}"
+            ),
+            "{sdl}"
+        );
+        assert!(
+            sdl.contains("errorCode(code: EntityErrorCode!): EntityErrorCode!"),
+            "{sdl}"
+        );
+    }
+
+    #[test]
+    fn graphql_enum() {
+        use super::is_enum_value_name;
+
+        assert_eq!(ErrorCode::Conflict.as_str(), "entity.conflict");
+        assert_eq!(ErrorCode::from(NotFound), ErrorCode::NotFound);
+        assert!(is_enum_value_name("_not_found2"));
+        assert!(!is_enum_value_name(""));
+        assert!(!is_enum_value_name("2xx"));
+        assert!(!is_enum_value_name("entity.conflict"));
+        assert!(!is_enum_value_name("null"));
+    }
+
+    #[tokio::test]
+    async fn execute() {
+        let response = schema()
+            .execute(r#"{ code(code: "not_found") described }"#)
+            .await;
+        assert_eq!(
+            response.data,
+            value!({ "code": "notFound", "described": "described" })
+        );
+
+        let response = schema().execute(r#"{ code(code: "other") }"#).await;
+        assert_eq!(
+            response.errors[0].message,
+            r#"Invalid value for argument "code", expected type "NotFound""#
+        );
+
+        let response = schema()
+            .execute("{ errorCode(code: CONFLICT) other: errorCode(code: described) }")
+            .await;
+        assert_eq!(
+            response.data,
+            value!({ "errorCode": "CONFLICT", "other": "described" })
+        );
+
+        let response = schema().execute("{ errorCode(code: notFound2) }").await;
+        assert!(!response.errors.is_empty());
+    }
+}
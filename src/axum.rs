@@ -0,0 +1,247 @@
+//! Axum integration.
+//!
+//! [`ConstantPath`] and [`ConstantQuery`] extract a single constant string parameter, named by the
+//! [`parameter_name`](crate::Options::parameter_name) option or the type name, and reject other values with a
+//! [`ConstantRejection`]. This encodes fixed path segments, such as an API version, in the handler signature.
+
+use std::{error::Error, fmt};
+
+use axum::{
+    extract::{FromRequestParts, Query, RawPathParams},
+    http::{StatusCode, request::Parts},
+    response::{IntoResponse, Response},
+};
+
+use crate::{ConstantString, parse};
+
+/// Name of the parameter of the constant string `C`, applying its [`Options`](crate::Options).
+pub fn parameter_name<C: ConstantString>() -> &'static str {
+    C::OPTIONS.parameter_name.unwrap_or(C::NAME)
+}
+
+/// Rejection of a constant string parameter.
+///
+/// Responds with the status and a plain text message, such as the [`ParseError`](crate::ParseError) of a
+/// mismatched value.
+#[derive(Debug)]
+pub struct ConstantRejection {
+    status: StatusCode,
+    message: String,
+}
+
+impl ConstantRejection {
+    fn new(status: StatusCode, message: impl fmt::Display) -> Self {
+        Self {
+            status,
+            message: message.to_string(),
+        }
+    }
+
+    /// Status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Message of the response.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ConstantRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ConstantRejection {}
+
+impl IntoResponse for ConstantRejection {
+    fn into_response(self) -> Response {
+        (self.status, self.message).into_response()
+    }
+}
+
+/// Extractor of the constant string `C` from a path parameter.
+///
+/// The parameter is named by the [`parameter_name`](crate::Options::parameter_name) option or the type name, or is
+/// the only parameter of the route. A mismatched value is rejected with `404 Not Found`.
+///
+/// # Example
+/// ```
+/// # extern crate axum;
+/// #
+/// use axum::{Router, routing::get};
+/// use constant_string::{axum::ConstantPath, constant_string};
+///
+/// constant_string!(Version, VERSION, "v1", parameter_name = "version");
+///
+/// async fn users(_: ConstantPath<Version>) -> &'static str {
+///     "users"
+/// }
+///
+/// let router: Router = Router::new().route("/{version}/users", get(users));
+/// ```
+#[derive(Debug, Default)]
+pub struct ConstantPath<C>(pub C);
+
+impl<C, S> FromRequestParts<S> for ConstantPath<C>
+where
+    C: ConstantString,
+    S: Send + Sync,
+{
+    type Rejection = ConstantRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let params = RawPathParams::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| {
+                ConstantRejection::new(rejection.status(), rejection.body_text())
+            })?;
+
+        let name = parameter_name::<C>();
+        let value = match params.iter().find(|(key, _)| *key == name) {
+            Some((_, value)) => value,
+            None => match params.iter().collect::<Vec<_>>().as_slice() {
+                [(_, value)] => value,
+                _ => {
+                    return Err(ConstantRejection::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format_args!("No path parameter named {name:?}"),
+                    ));
+                }
+            },
+        };
+
+        parse(value)
+            .map(Self)
+            .map_err(|error| ConstantRejection::new(StatusCode::NOT_FOUND, error))
+    }
+}
+
+/// Extractor of the constant string `C` from a query parameter.
+///
+/// The parameter is named by the [`parameter_name`](crate::Options::parameter_name) option or the type name. A
+/// missing or mismatched value is rejected with `422 Unprocessable Entity`.
+#[derive(Debug, Default)]
+pub struct ConstantQuery<C>(pub C);
+
+impl<C, S> FromRequestParts<S> for ConstantQuery<C>
+where
+    C: ConstantString,
+    S: Send + Sync,
+{
+    type Rejection = ConstantRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let Query(params) =
+            Query::<Vec<(String, String)>>::try_from_uri(&parts.uri).map_err(|rejection| {
+                ConstantRejection::new(rejection.status(), rejection.body_text())
+            })?;
+
+        let name = parameter_name::<C>();
+        let (_, value) = params.iter().find(|(key, _)| key == name).ok_or_else(|| {
+            ConstantRejection::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format_args!("Missing query parameter {name:?}"),
+            )
+        })?;
+
+        parse(value)
+            .map(Self)
+            .map_err(|error| ConstantRejection::new(StatusCode::UNPROCESSABLE_ENTITY, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        extract::Path,
+        http::{Request, StatusCode},
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use super::{ConstantPath, ConstantQuery};
+    use crate::constant_string;
+
+    constant_string!(Version, VERSION, "v1", parameter_name = "version");
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    async fn user(_: ConstantPath<Version>, Path((_, id)): Path<(String, u32)>) -> String {
+        id.to_string()
+    }
+
+    async fn error(ConstantPath(code): ConstantPath<NotFound>) -> String {
+        String::from(&*code)
+    }
+
+    async fn search(ConstantQuery(code): ConstantQuery<NotFound>) -> String {
+        String::from(&*code)
+    }
+
+    async fn request(uri: &str) -> (StatusCode, String) {
+        let router = Router::new()
+            .route("/{version}/users/{id}", get(user))
+            .route("/errors/{code}", get(error))
+            .route("/search", get(search));
+        let response = router
+            .oneshot(
+                Request::get(uri)
+                    .body(Body::empty())
+                    .expect("valid request"),
+            )
+            .await
+            .expect("infallible service");
+
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("readable body");
+        (status, String::from_utf8_lossy(&body).into_owned())
+    }
+
+    #[tokio::test]
+    async fn path() {
+        assert_eq!(
+            request("/v1/users/1").await,
+            (StatusCode::OK, "1".to_owned())
+        );
+        assert_eq!(
+            request("/v2/users/1").await,
+            (
+                StatusCode::NOT_FOUND,
+                r#"invalid value "v2", expected constant "v1" for Version"#.to_owned()
+            )
+        );
+        assert_eq!(
+            request("/errors/not_found").await,
+            (StatusCode::OK, "notFound".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn query() {
+        assert_eq!(
+            request("/search?NotFound=notFound").await,
+            (StatusCode::OK, "notFound".to_owned())
+        );
+        assert_eq!(
+            request("/search?NotFound=other").await,
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                r#"invalid value "other", expected constant "notFound" or alias "not_found" for NotFound"#
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            request("/search").await,
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                r#"Missing query parameter "NotFound""#.to_owned()
+            )
+        );
+    }
+}
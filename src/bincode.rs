@@ -0,0 +1,91 @@
+//! Bincode integration.
+//!
+//! Implements the native [`Encode`] and [`Decode`] traits of bincode 2. Constant strings are encoded as a string and
+//! the decoded value is validated against their [`Options`](crate::Options). With the
+//! [`compact`](crate::Options::compact) option, nothing is encoded.
+
+use bincode::{
+    Decode, Encode,
+    de::Decoder,
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+};
+
+use crate::ConstantString;
+
+/// Encode the constant string `C` as a string, or nothing if it is [`compact`](crate::Options::compact).
+///
+/// Used by the [`Encode`] implementation of [`constant_string`](crate::constant_string).
+pub fn encode<C: ConstantString, E: Encoder>(encoder: &mut E) -> Result<(), EncodeError> {
+    if C::OPTIONS.compact {
+        Ok(())
+    } else {
+        C::VALUE.encode(encoder)
+    }
+}
+
+/// Decode the constant string `C` from a string, or nothing if it is [`compact`](crate::Options::compact), failing
+/// with a [`ParseError`](crate::ParseError) message on a mismatch.
+///
+/// Used by the [`Decode`] implementation of [`constant_string`](crate::constant_string).
+pub fn decode<C: ConstantString, D: Decoder>(decoder: &mut D) -> Result<C, DecodeError> {
+    if C::OPTIONS.compact {
+        Ok(C::default())
+    } else {
+        crate::parse(&String::decode(decoder)?)
+            .map_err(|error| DecodeError::OtherString(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bincode::{Decode, Encode, config, error::DecodeError};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Compact, COMPACT, "compact", compact);
+
+    #[derive(Debug, Decode, Encode, PartialEq)]
+    struct Error {
+        code: NotFound,
+        compact: Compact,
+        status: u16,
+    }
+
+    #[test]
+    fn encode() {
+        let error = Error {
+            code: NotFound,
+            compact: Compact,
+            status: 404,
+        };
+        let bytes = bincode::encode_to_vec(&error, config::standard()).expect("encodable value");
+
+        assert_eq!(bytes, b"\x08notFound\xfb\x94\x01");
+        assert_eq!(
+            bincode::decode_from_slice::<Error, _>(&bytes, config::standard())
+                .expect("decodable value"),
+            (error, bytes.len())
+        );
+    }
+
+    #[test]
+    fn decode() {
+        let bytes =
+            bincode::encode_to_vec("not_found", config::standard()).expect("encodable value");
+        assert!(bincode::decode_from_slice::<NotFound, _>(&bytes, config::standard()).is_ok());
+        assert!(
+            bincode::borrow_decode_from_slice::<NotFound, _>(&bytes, config::standard()).is_ok()
+        );
+
+        let bytes = bincode::encode_to_vec("missing", config::standard()).expect("encodable value");
+        let error = bincode::decode_from_slice::<NotFound, _>(&bytes, config::standard())
+            .expect_err("mismatched value");
+        assert!(matches!(
+            error,
+            DecodeError::OtherString(message)
+                if message == r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+//! Borsh integration.
+//!
+//! Constant strings are written as a length-prefixed string and the read value is validated against their
+//! [`Options`](crate::Options).
+
+use borsh::{
+    BorshDeserialize, BorshSerialize,
+    io::{Error, ErrorKind, Read, Result, Write},
+};
+
+use crate::ConstantString;
+
+/// Serialize the constant string `C` as a string.
+///
+/// Used by the [`BorshSerialize`] implementation of [`constant_string`](crate::constant_string).
+pub fn serialize<C: ConstantString, W: Write>(writer: &mut W) -> Result<()> {
+    C::VALUE.serialize(writer)
+}
+
+/// Deserialize the constant string `C` from a string, failing with a [`ParseError`](crate::ParseError) on a mismatch.
+///
+/// Used by the [`BorshDeserialize`] implementation of [`constant_string`](crate::constant_string).
+pub fn deserialize_reader<C: ConstantString, R: Read>(reader: &mut R) -> Result<C> {
+    crate::parse(&String::deserialize_reader(reader)?)
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+    struct Error {
+        code: NotFound,
+        status: u16,
+    }
+
+    #[test]
+    fn serialize() {
+        let error = Error {
+            code: NotFound,
+            status: 404,
+        };
+        let bytes = borsh::to_vec(&error).expect("serializable value");
+
+        assert_eq!(bytes, b"\x08\0\0\0notFound\x94\x01");
+        assert_eq!(
+            borsh::from_slice::<Error>(&bytes).expect("deserializable value"),
+            error
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let bytes = borsh::to_vec("not_found").expect("serializable value");
+        assert!(borsh::from_slice::<NotFound>(&bytes).is_ok());
+
+        let bytes = borsh::to_vec("missing").expect("serializable value");
+        let error = borsh::from_slice::<NotFound>(&bytes).expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! BSON integration.
+//!
+//! Constant strings are converted to BSON strings and deserialized from documents with their
+//! [`Deserialize`](serde::Deserialize) implementation, which accepts both borrowed and owned strings.
+
+use bson::Bson;
+
+use crate::ConstantString;
+
+/// BSON string of the constant string `C`.
+///
+/// Used by the [`From`] implementation of [`constant_string`](crate::constant_string).
+pub fn to_bson<C: ConstantString>() -> Bson {
+    Bson::String(C::VALUE.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use bson::{Bson, doc};
+    use serde::{Deserialize, Serialize};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Error {
+        code: NotFound,
+        message: String,
+    }
+
+    #[test]
+    fn from() {
+        assert_eq!(Bson::from(NotFound), Bson::String("notFound".to_owned()));
+        assert_eq!(doc! { "code": NotFound }, doc! { "code": "notFound" });
+    }
+
+    #[test]
+    fn document() {
+        let error = Error {
+            code: NotFound,
+            message: "Not found.".to_owned(),
+        };
+        let document = bson::serialize_to_document(&error).expect("serializable value");
+        assert_eq!(
+            document,
+            doc! { "code": "notFound", "message": "Not found." }
+        );
+
+        assert_eq!(
+            bson::deserialize_from_document::<Error>(document.clone())
+                .expect("deserializable value"),
+            error
+        );
+        assert_eq!(
+            bson::deserialize_from_slice::<Error>(
+                &bson::serialize_to_vec(&document).expect("serializable value")
+            )
+            .expect("deserializable value"),
+            error
+        );
+        assert_eq!(
+            bson::deserialize_from_bson::<NotFound>(Bson::String("not_found".to_owned()))
+                .expect("deserializable value"),
+            NotFound
+        );
+
+        assert!(
+            bson::deserialize_from_document::<Error>(doc! { "code": "missing", "message": "" })
+                .is_err()
+        );
+        assert!(
+            bson::deserialize_from_document::<Error>(doc! { "code": 404, "message": "" }).is_err()
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Clap integration.
+//!
+//! Constant strings implement [`ValueParserFactory`](clap::builder::ValueParserFactory), so they can be used as
+//! argument types, and [`Clone`], which clap requires of argument values. Use
+//! [`constant_value_parser`](crate::constant_value_parser) for arguments accepting a group of constants.
+
+use std::{ffi::OsStr, marker::PhantomData};
+
+use clap::{
+    Arg, Command, Error,
+    builder::{PossibleValue, StringValueParser, TypedValueParser},
+    error::ErrorKind,
+};
+
+use crate::ConstantString;
+
+/// Possible value of the constant string `C`, with its aliases.
+pub fn possible_value<C: ConstantString>() -> PossibleValue {
+    PossibleValue::new(C::VALUE).aliases(C::OPTIONS.aliases.iter().copied())
+}
+
+/// Parser of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// The constant is listed as the possible value in `--help`. Used by the
+/// [`ValueParserFactory`](clap::builder::ValueParserFactory) implementation of
+/// [`constant_string`](crate::constant_string).
+pub struct ConstantValueParser<C>(PhantomData<fn() -> C>);
+
+impl<C> ConstantValueParser<C> {
+    /// Create a parser of the constant string `C`.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C> Clone for ConstantValueParser<C> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<C> Default for ConstantValueParser<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> TypedValueParser for ConstantValueParser<C>
+where
+    C: ConstantString + Clone + Send + Sync + 'static,
+{
+    type Value = C;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<C, Error> {
+        let value = StringValueParser::new().parse_ref(cmd, arg, value)?;
+
+        crate::parse(&value)
+            .map_err(|error| Error::raw(ErrorKind::InvalidValue, error).with_cmd(cmd))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(std::iter::once(possible_value::<C>())))
+    }
+}
+
+/// Parser of the value of one of a group of constant string types.
+///
+/// Expands to a [`PossibleValuesParser`](clap::builder::PossibleValuesParser) of [`String`], listing the value and
+/// aliases of every member.
+///
+/// # Example
+/// ```
+/// # extern crate clap;
+/// #
+/// use clap::Parser;
+/// use constant_string::{constant_string, constant_value_parser};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     #[arg(long, value_parser = constant_value_parser!(NotFoundErrorCode, ConflictErrorCode))]
+///     code: String,
+///     #[arg(long)]
+///     not_found: NotFoundErrorCode,
+/// }
+///
+/// let cli = Cli::try_parse_from(["cli", "--code", "conflict", "--not-found", "notFound"]).expect("valid arguments");
+/// assert_eq!(cli.code, "conflict");
+/// assert!(Cli::try_parse_from(["cli", "--code", "other", "--not-found", "notFound"]).is_err());
+/// ```
+#[macro_export]
+macro_rules! constant_value_parser {
+    ($($constant:ty),+ $(,)?) => {
+        $crate::__private::clap::builder::PossibleValuesParser::new([
+            $($crate::clap::possible_value::<$constant>()),+
+        ])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{Parser, error::ErrorKind};
+
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Conflict, CONFLICT, "conflict");
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[arg(long)]
+        code: NotFound,
+        #[arg(long, default_value = "notFound")]
+        maybe_code: MaybeConstant<NotFound>,
+        #[arg(long, default_value = "other")]
+        other_code: ConstantOr<NotFound>,
+        #[arg(long, value_parser = constant_value_parser!(NotFound, Conflict))]
+        group_code: Option<String>,
+    }
+
+    #[test]
+    fn parse() {
+        let cli = Cli::try_parse_from(["cli", "--code", "not_found", "--other-code", "notFound"])
+            .expect("valid arguments");
+        assert_eq!(cli.code, NotFound);
+        assert_eq!(cli.maybe_code.0, NotFound);
+        assert!(cli.other_code.is_constant());
+        assert_eq!(cli.group_code, None);
+
+        let cli = Cli::try_parse_from(["cli", "--code", "notFound", "--group-code", "conflict"])
+            .expect("valid arguments");
+        assert_eq!(cli.other_code, ConstantOr::Other("other".to_owned()));
+        assert_eq!(cli.group_code.as_deref(), Some("conflict"));
+
+        let error = Cli::try_parse_from(["cli", "--code", "other"]).expect_err("mismatched value");
+        assert_eq!(error.kind(), ErrorKind::InvalidValue);
+        assert!(error.to_string().contains(
+            r#"invalid value "other", expected constant "notFound" or alias "not_found" for NotFound"#
+        ));
+
+        let error = Cli::try_parse_from(["cli", "--code", "notFound", "--group-code", "other"])
+            .expect_err("mismatched value");
+        assert_eq!(error.kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn help() {
+        use clap::CommandFactory;
+
+        assert_eq!(
+            Cli::command().render_help().to_string(),
+            "\
+Usage: constant-string [OPTIONS] --code <CODE>
+
+Options:
+      --code <CODE>              [possible values: notFound]
+      --maybe-code <MAYBE_CODE>  [default: notFound] [possible values: notFound]
+      --other-code <OTHER_CODE>  [default: other]
+      --group-code <GROUP_CODE>  [possible values: notFound, conflict]
+  -h, --help                     Print help
+"
+        );
+    }
+}
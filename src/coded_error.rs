@@ -0,0 +1,207 @@
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::ConstantString;
+
+/// Error with the constant string code `C`, an optional message and optional details of type `D`.
+///
+/// Unlike [`ConstantError`](crate::ConstantError), the code needs no status or default message, so an error type is
+/// a type alias of a code. Serialized as an object with a `code` property and `message` and `details` properties,
+/// which are omitted when unset. The default details `()` are never set.
+///
+/// # Example
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use constant_string::{CodedError, constant_string};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+///
+/// type NotFoundError = CodedError<NotFoundErrorCode>;
+///
+/// let error = NotFoundError::new().with_message("Entity 42 not found");
+/// assert_eq!(error.to_string(), "notFound: Entity 42 not found");
+/// assert_eq!(
+///     serde_json::to_string(&error).expect("serializable value"),
+///     r#"{"code":"notFound","message":"Entity 42 not found"}"#
+/// );
+/// ```
+#[derive(Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CodedError<C, D = ()> {
+    /// Code of the error.
+    pub code: C,
+
+    /// Human-readable message of the error.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Cow<'static, str>>,
+
+    /// Details of the error.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub details: Option<D>,
+}
+
+impl<C: ConstantString, D> CodedError<C, D> {
+    /// Error with only the code.
+    pub fn new() -> Self {
+        Self {
+            code: C::default(),
+            message: None,
+            details: None,
+        }
+    }
+
+    /// Set the `message`.
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set the `details`.
+    pub fn with_details(mut self, details: D) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    /// Message, if set.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl<C: ConstantString, D: Clone> Clone for CodedError<C, D> {
+    fn clone(&self) -> Self {
+        Self {
+            code: C::default(),
+            message: self.message.clone(),
+            details: self.details.clone(),
+        }
+    }
+}
+
+impl<C: ConstantString, D> fmt::Display for CodedError<C, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(C::VALUE)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ConstantString + fmt::Debug, D: fmt::Debug> core::error::Error for CodedError<C, D> {}
+
+#[cfg(feature = "utoipa")]
+impl<C, D> utoipa::PartialSchema for CodedError<C, D>
+where
+    C: ConstantString + utoipa::PartialSchema,
+    D: utoipa::PartialSchema,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::utoipa::coded_error_schema::<C, D>()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<C, D> utoipa::ToSchema for CodedError<C, D>
+where
+    C: ConstantString + utoipa::ToSchema,
+    D: utoipa::PartialSchema,
+{
+    fn name() -> std::borrow::Cow<'static, str> {
+        format!("CodedError_{}", C::name()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodedError;
+    use crate::constant_string;
+
+    constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+
+    type NotFoundError = CodedError<NotFoundErrorCode>;
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+    #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+    struct EntityDetails {
+        id: u32,
+    }
+
+    #[test]
+    fn coded_error() {
+        let error = NotFoundError::new();
+        assert_eq!(error.message(), None);
+        assert_eq!(error.to_string(), "notFound");
+
+        let error = CodedError::<NotFoundErrorCode, EntityDetails>::new()
+            .with_message("Entity not found")
+            .with_details(EntityDetails { id: 42 });
+        assert_eq!(error.message(), Some("Entity not found"));
+        assert_eq!(error.to_string(), "notFound: Entity not found");
+        assert_eq!(error.clone(), error);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        assert_eq!(
+            to_string(&NotFoundError::new()).ok().as_deref(),
+            Some(r#"{"code":"notFound"}"#)
+        );
+        assert_eq!(
+            to_string(
+                &CodedError::<NotFoundErrorCode, _>::new().with_details(EntityDetails { id: 42 })
+            )
+            .ok()
+            .as_deref(),
+            Some(r#"{"code":"notFound","details":{"id":42}}"#)
+        );
+        assert_eq!(
+            from_str::<NotFoundError>(r#"{"code":"notFound","message":"Missing"}"#).ok(),
+            Some(NotFoundError::new().with_message("Missing"))
+        );
+        assert!(from_str::<NotFoundError>(r#"{"code":"conflict"}"#).is_err());
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(NotFoundError::name(), "CodedError_NotFoundErrorCode");
+
+        let schema =
+            serde_json::to_value(CodedError::<NotFoundErrorCode, EntityDetails>::schema()).ok();
+        assert_eq!(
+            schema
+                .as_ref()
+                .and_then(|schema| schema.get("required"))
+                .cloned(),
+            Some(serde_json::json!(["code"]))
+        );
+        assert_eq!(
+            schema
+                .as_ref()
+                .and_then(|schema| schema.pointer("/properties/code/enum"))
+                .cloned(),
+            Some(serde_json::json!(["notFound"]))
+        );
+        assert_eq!(
+            schema
+                .as_ref()
+                .and_then(|schema| schema.pointer("/properties/details/required"))
+                .cloned(),
+            Some(serde_json::json!(["id"]))
+        );
+    }
+}
@@ -0,0 +1,1890 @@
+//! Code generation from catalogs.
+//!
+//! A catalog lists constant strings in a JSON, TOML or YAML file shared with other languages. [`generate`] reads it
+//! from a build script and writes a [`constant_string`](crate::constant_string) invocation for each constant, with its
+//! description as documentation and its aliases as options, and a slice of values for each group. Catalogs can also
+//! be collected from the schemas of an OpenAPI document with [`generate_openapi`], a JSON Schema with
+//! [`generate_json_schema`] or the string literal union types of a TypeScript file with [`generate_typescript`].
+//!
+//! In the other direction, [`typescript`] exports constant strings as TypeScript definitions for frontends, and [`zod`]
+//! as Zod schemas for their runtime validation. With the `inventory` feature, [`TypeScriptConstant::all`] exports all
+//! constant strings linked into the binary, e.g. from a small binary printing the definitions.
+//!
+//! # Example
+//! A catalog in `error_codes.yaml`:
+//! ```yaml
+//! constants:
+//!   - name: NotFoundErrorCode
+//!     value: notFound
+//!     description: The resource was not found.
+//!     aliases: [not_found]
+//!   - name: ConflictErrorCode
+//!     value: conflict
+//! groups:
+//!   - name: ERROR_CODES
+//!     constants: [NotFoundErrorCode, ConflictErrorCode]
+//! ```
+//!
+//! Generated in `build.rs`:
+//! ```no_run
+//! use std::{env, path::Path};
+//!
+//! fn main() {
+//!     let out_dir = env::var("OUT_DIR").expect("build script");
+//!     constant_string::codegen::generate("error_codes.yaml", Path::new(&out_dir).join("error_codes.rs"))
+//!         .expect("valid catalog");
+//! }
+//! ```
+//!
+//! Included in the crate:
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/error_codes.rs"));
+//! ```
+
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Write},
+    fs, io,
+    iter::Peekable,
+    path::{Path, PathBuf},
+    str::Chars,
+};
+
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::{ConstantString, typescript::string_literal};
+
+/// Format of a catalog file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// JSON.
+    Json,
+    /// TOML, with constants as an array of tables.
+    Toml,
+    /// YAML.
+    Yaml,
+}
+
+impl Format {
+    /// Format of a file from its extension: `json`, `toml`, `yaml` or `yml`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Catalog of constant strings.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Catalog {
+    /// Constant strings.
+    pub constants: Vec<Constant>,
+
+    /// Groups of constant strings.
+    pub groups: Vec<Group>,
+}
+
+/// Constant string in a [`Catalog`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Constant {
+    /// Name of the type.
+    pub name: String,
+
+    /// Name of the constant holding the value. Defaults to the name in screaming snake case.
+    pub code_name: Option<String>,
+
+    /// Value.
+    pub value: String,
+
+    /// Description, used as documentation and for the [`description`](crate::Options::description) option.
+    pub description: Option<String>,
+
+    /// Value for the [`aliases`](crate::Options::aliases) option.
+    pub aliases: Vec<String>,
+
+    /// Value for the [`case_insensitive`](crate::Options::case_insensitive) option.
+    pub case_insensitive: bool,
+
+    /// Deprecate the type and set the [`deprecated`](crate::Options::deprecated) option.
+    pub deprecated: bool,
+}
+
+impl Constant {
+    /// Name of the constant holding the value.
+    pub fn code_name(&self) -> String {
+        self.code_name
+            .clone()
+            .unwrap_or_else(|| screaming_snake_case(&self.name))
+    }
+}
+
+/// Group of constant strings in a [`Catalog`], generated as a slice of their values and optionally an enum.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Group {
+    /// Name of the slice.
+    pub name: String,
+
+    /// Name of an enum with a unit variant for each constant string, convertible from them, with `COUNT`, `VARIANTS`,
+    /// `iter()` and `as_str()` to enumerate the variants and their values. Variants are named after the constant
+    /// strings, without the enum name as a suffix or prefix.
+    pub enum_name: Option<String>,
+
+    /// Description, used as documentation.
+    pub description: Option<String>,
+
+    /// Also generate a perfect hash [`phf::Set`](https://docs.rs/phf/latest/phf/struct.Set.html) of the values,
+    /// suffixed with `_SET`, and with an enum a [`phf::Map`](https://docs.rs/phf/latest/phf/struct.Map.html) from the
+    /// values to its variants, suffixed with `_MAP`, for constant-time membership checks. Requires `phf` as a direct
+    /// dependency.
+    pub phf: bool,
+
+    /// Names of the constant strings in the group.
+    pub constants: Vec<String>,
+}
+
+impl Catalog {
+    /// Parse a catalog from `source` in `format`.
+    pub fn parse(source: &str, format: Format) -> Result<Self, CodegenError> {
+        deserialize(source, format)
+    }
+
+    /// Read a catalog from a file, in the [`Format`] of its extension.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, CodegenError> {
+        read(path.as_ref(), Self::parse)
+    }
+
+    /// Collect a catalog from the component schemas of an OpenAPI document in `source`.
+    ///
+    /// Each string schema with a `const` or a single-value `enum` becomes a constant string, with its description and
+    /// deprecation. It is named after the component, followed by the names of the properties leading to it in pascal
+    /// case, so the `code` property of an `Error` component becomes `ErrorCode`.
+    pub fn from_openapi(source: &str, format: Format) -> Result<Self, CodegenError> {
+        let document: Value = deserialize(source, format)?;
+        let mut catalog = Self::default();
+
+        if let Some(schemas) = document
+            .pointer("/components/schemas")
+            .and_then(Value::as_object)
+        {
+            for (name, schema) in schemas {
+                catalog.collect(pascal_case(name), schema);
+            }
+        }
+
+        Ok(catalog)
+    }
+
+    /// Read a catalog from an OpenAPI document in a file, in the [`Format`] of its extension.
+    pub fn read_openapi(path: impl AsRef<Path>) -> Result<Self, CodegenError> {
+        read(path.as_ref(), Self::from_openapi)
+    }
+
+    /// Collect a catalog from a JSON Schema in `source`.
+    ///
+    /// Like [`from_openapi`](Self::from_openapi), for the definitions in `$defs` and `definitions` named after the
+    /// definition, and the schema and its properties named after its `title`.
+    pub fn from_json_schema(source: &str, format: Format) -> Result<Self, CodegenError> {
+        let schema: Value = deserialize(source, format)?;
+        let mut catalog = Self::default();
+
+        for definitions in ["$defs", "definitions"] {
+            if let Some(definitions) = schema.get(definitions).and_then(Value::as_object) {
+                for (name, schema) in definitions {
+                    catalog.collect(pascal_case(name), schema);
+                }
+            }
+        }
+
+        let name = schema
+            .get("title")
+            .and_then(Value::as_str)
+            .map(pascal_case)
+            .unwrap_or_default();
+        catalog.collect(name, &schema);
+
+        Ok(catalog)
+    }
+
+    /// Read a catalog from a JSON Schema in a file, in the [`Format`] of its extension.
+    pub fn read_json_schema(path: impl AsRef<Path>) -> Result<Self, CodegenError> {
+        read(path.as_ref(), Self::from_json_schema)
+    }
+
+    /// Collect a catalog from the string literal union types in TypeScript `source`.
+    ///
+    /// Each type alias of a union of string literals, such as `type ErrorCode = "notFound" | "conflict";`, becomes a
+    /// constant string for each literal, named after it in pascal case followed by the type name, such as
+    /// `NotFoundErrorCode`, or preceded by the type name if the literal starts with a digit. The literals form a group
+    /// named after the type in screaming snake case, with an enum named after the type. The JSDoc comment of the type
+    /// is used as the description of the group.
+    pub fn from_typescript(source: &str) -> Result<Self, CodegenError> {
+        let tokens = tokenize(source)?;
+        let mut catalog = Self::default();
+
+        let mut index = 0;
+        while index < tokens.len() {
+            if let [
+                Token::Ident(keyword),
+                Token::Ident(name),
+                Token::Punct('='),
+                rest @ ..,
+            ] = &tokens[index..]
+                && keyword == "type"
+                && let Some((values, length)) = union(rest)
+            {
+                let description = tokens[..index]
+                    .iter()
+                    .rev()
+                    .find(|token| {
+                        !matches!(token, Token::Ident(modifier) if modifier == "export" || modifier == "declare")
+                    })
+                    .and_then(|token| match token {
+                        Token::Doc(doc) => Some(doc.clone()),
+                        _ => None,
+                    });
+
+                let constants = values
+                    .into_iter()
+                    .map(|value| {
+                        let value_name = pascal_case(&value);
+                        Constant {
+                            name: if value_name.starts_with(|char: char| char.is_ascii_digit()) {
+                                format!("{name}{value_name}")
+                            } else {
+                                format!("{value_name}{name}")
+                            },
+                            value,
+                            ..Constant::default()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                catalog.groups.push(Group {
+                    name: screaming_snake_case(name),
+                    enum_name: Some(name.clone()),
+                    description,
+                    constants: constants
+                        .iter()
+                        .map(|constant| constant.name.clone())
+                        .collect(),
+                    ..Group::default()
+                });
+                catalog.constants.extend(constants);
+
+                index += 3 + length;
+            } else {
+                index += 1;
+            }
+        }
+
+        Ok(catalog)
+    }
+
+    /// Read a catalog from the string literal union types in a TypeScript file.
+    pub fn read_typescript(path: impl AsRef<Path>) -> Result<Self, CodegenError> {
+        Self::from_typescript(&fs::read_to_string(path)?)
+    }
+
+    /// Collect constant strings from `schema` and its properties.
+    ///
+    /// A schema without a name, such as an untitled JSON Schema, is skipped, but its properties are collected.
+    fn collect(&mut self, name: String, schema: &Value) {
+        if let Some(value) = single_value(schema).filter(|_| !name.is_empty()) {
+            self.constants.push(Constant {
+                name: name.clone(),
+                value: value.to_owned(),
+                description: schema
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(str::to_owned),
+                deprecated: schema
+                    .get("deprecated")
+                    .and_then(Value::as_bool)
+                    .unwrap_or_default(),
+                ..Constant::default()
+            });
+        }
+
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (property, schema) in properties {
+                self.collect(format!("{name}{}", pascal_case(property)), schema);
+            }
+        }
+    }
+
+    /// Generate Rust code for the catalog.
+    ///
+    /// Fails if a name isn't a valid identifier, is defined twice or a group refers to an unknown constant.
+    pub fn generate(&self) -> Result<String, CodegenError> {
+        let mut names = HashSet::new();
+        let mut code = "// @generated by constant-string from a catalog. Do not edit.\n".to_owned();
+
+        for constant in &self.constants {
+            let code_name = constant.code_name();
+            for name in [&constant.name, &code_name] {
+                if !is_identifier(name) {
+                    return Err(CodegenError::Identifier(name.clone()));
+                }
+                if !names.insert(name.clone()) {
+                    return Err(CodegenError::Duplicate(name.clone()));
+                }
+            }
+
+            code.push_str("\n::constant_string::constant_string!(\n");
+            write_doc(&mut code, "    ", constant.description.as_deref());
+            if constant.deprecated {
+                code.push_str("    #[deprecated]\n");
+            }
+            writeln!(code, "    {},", constant.name)?;
+            writeln!(code, "    {code_name},")?;
+            writeln!(code, "    {:?},", constant.value)?;
+            if !constant.aliases.is_empty() {
+                writeln!(code, "    aliases = {:?},", constant.aliases)?;
+            }
+            if constant.case_insensitive {
+                code.push_str("    case_insensitive,\n");
+            }
+            if constant.deprecated {
+                code.push_str("    deprecated,\n");
+            }
+            if let Some(description) = &constant.description {
+                writeln!(code, "    description = {description:?},")?;
+            }
+            code.push_str(");\n");
+        }
+
+        for group in &self.groups {
+            for name in [Some(&group.name), group.enum_name.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                if !is_identifier(name) {
+                    return Err(CodegenError::Identifier(name.clone()));
+                }
+                if !names.insert(name.clone()) {
+                    return Err(CodegenError::Duplicate(name.clone()));
+                }
+            }
+
+            let members = group
+                .constants
+                .iter()
+                .map(|name| {
+                    self.constants
+                        .iter()
+                        .find(|constant| &constant.name == name)
+                        .ok_or_else(|| CodegenError::UnknownConstant {
+                            group: group.name.clone(),
+                            constant: name.clone(),
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            code.push('\n');
+            write_doc(&mut code, "", group.description.as_deref());
+            writeln!(
+                code,
+                "pub const {}: &[&str] = &[{}];",
+                group.name,
+                members
+                    .iter()
+                    .map(|constant| constant.code_name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+
+            if let Some(enum_name) = &group.enum_name {
+                write_enum(&mut code, enum_name, group.description.as_deref(), &members)?;
+            }
+
+            if group.phf {
+                for suffix in ["_SET", "_MAP"] {
+                    let name = format!("{}{suffix}", group.name);
+                    if !names.insert(name.clone()) {
+                        return Err(CodegenError::Duplicate(name));
+                    }
+                }
+
+                write_phf(&mut code, group, &members)?;
+            }
+        }
+
+        Ok(code)
+    }
+}
+
+/// Write an enum named `name` with a unit variant for each of `members`.
+fn write_enum(
+    code: &mut String,
+    name: &str,
+    description: Option<&str>,
+    members: &[&Constant],
+) -> Result<(), CodegenError> {
+    let variants = members
+        .iter()
+        .map(|constant| variant_name(&constant.name, name))
+        .collect::<Vec<_>>();
+
+    let mut unique = HashSet::new();
+    if let Some(variant) = variants.iter().find(|variant| !unique.insert(**variant)) {
+        return Err(CodegenError::Duplicate(format!("{name}::{variant}")));
+    }
+
+    code.push('\n');
+    write_doc(code, "", description);
+    code.push_str("#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]\n");
+    writeln!(code, "pub enum {name} {{")?;
+    for (constant, variant) in members.iter().zip(&variants) {
+        writeln!(code, "    /// [`{}`].", constant.name)?;
+        writeln!(code, "    {variant},")?;
+    }
+    code.push_str("}\n");
+
+    writeln!(
+        code,
+        "\n#[allow(deprecated)]\nimpl {name} {{\n    /// Number of variants.\n    pub const COUNT: usize = {};\n",
+        variants.len()
+    )?;
+    code.push_str(
+        "    /// Variants in declaration order.\n    pub const VARIANTS: &'static [Self] = &[",
+    );
+    for (index, variant) in variants.iter().enumerate() {
+        if index > 0 {
+            code.push_str(", ");
+        }
+        write!(code, "Self::{variant}")?;
+    }
+    code.push_str("];\n\n");
+    code.push_str("    /// Iterate over the variants in declaration order.\n    pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {\n        Self::VARIANTS.iter().copied()\n    }\n\n");
+    code.push_str("    /// Value of the constant string of the variant.\n    pub const fn as_str(&self) -> &'static str {\n        match self {\n");
+    for (constant, variant) in members.iter().zip(&variants) {
+        writeln!(
+            code,
+            "            Self::{variant} => {},",
+            constant.code_name()
+        )?;
+    }
+    code.push_str("        }\n    }\n}\n");
+
+    for (constant, variant) in members.iter().zip(&variants) {
+        writeln!(
+            code,
+            "\n#[allow(deprecated)]\nimpl ::core::convert::From<{0}> for {name} {{\n    fn from(_: {0}) -> Self {{\n        Self::{variant}\n    }}\n}}",
+            constant.name
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Name of the variant for the constant string `constant_name` in the enum `enum_name`.
+fn variant_name<'a>(constant_name: &'a str, enum_name: &str) -> &'a str {
+    [
+        constant_name.strip_suffix(enum_name),
+        constant_name.strip_prefix(enum_name),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|variant| is_identifier(variant))
+    .unwrap_or(constant_name)
+}
+
+/// Write a perfect hash set of the values of `members` and, if `group` has an enum, a map to its variants.
+fn write_phf(code: &mut String, group: &Group, members: &[&Constant]) -> Result<(), CodegenError> {
+    let mut values = HashSet::new();
+    for constant in members {
+        if !values.insert(constant.value.as_str()) {
+            return Err(CodegenError::Duplicate(constant.value.clone()));
+        }
+    }
+
+    let mut set = phf_codegen::Set::new();
+    for constant in members {
+        set.entry(constant.value.as_str());
+    }
+    writeln!(
+        code,
+        "\n/// Values of [`{0}`] as a perfect hash set.\npub static {0}_SET: ::phf::Set<&'static str> = {1};",
+        group.name,
+        set.build()
+    )?;
+
+    if let Some(enum_name) = &group.enum_name {
+        let mut map = phf_codegen::Map::new();
+        for constant in members {
+            map.entry(
+                constant.value.as_str(),
+                format!("{enum_name}::{}", variant_name(&constant.name, enum_name)),
+            );
+        }
+        writeln!(
+            code,
+            "\n/// Variants of [`{enum_name}`] by value as a perfect hash map.\npub static {0}_MAP: ::phf::Map<&'static str, {enum_name}> = {1};",
+            group.name,
+            map.build()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Generate Rust code from the catalog at `input` and write it to `output`.
+///
+/// Intended for build scripts: prints `cargo::rerun-if-changed` for `input`, so the code is regenerated when the
+/// catalog changes. Include the output with [`include!`].
+pub fn generate(input: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<(), CodegenError> {
+    write(input.as_ref(), output.as_ref(), |path| Catalog::read(path))
+}
+
+/// Generate Rust code from the OpenAPI document at `input` and write it to `output`.
+///
+/// Like [`generate`], with the catalog collected by [`Catalog::from_openapi`].
+pub fn generate_openapi(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+) -> Result<(), CodegenError> {
+    write(input.as_ref(), output.as_ref(), |path| {
+        Catalog::read_openapi(path)
+    })
+}
+
+/// Generate Rust code from the JSON Schema at `input` and write it to `output`.
+///
+/// Like [`generate`], with the catalog collected by [`Catalog::from_json_schema`].
+pub fn generate_json_schema(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+) -> Result<(), CodegenError> {
+    write(input.as_ref(), output.as_ref(), |path| {
+        Catalog::read_json_schema(path)
+    })
+}
+
+/// Generate Rust code from the TypeScript file at `input` and write it to `output`.
+///
+/// Like [`generate`], with the catalog collected by [`Catalog::from_typescript`].
+pub fn generate_typescript(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+) -> Result<(), CodegenError> {
+    write(input.as_ref(), output.as_ref(), |path| {
+        Catalog::read_typescript(path)
+    })
+}
+
+/// Constant string exported to TypeScript by [`typescript`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TypeScriptConstant {
+    /// Name of the type and constant.
+    pub name: &'static str,
+
+    /// Value.
+    pub value: &'static str,
+
+    /// Description, used as the JSDoc comment.
+    pub description: Option<&'static str>,
+
+    /// Mark the type and constant as `@deprecated`.
+    pub deprecated: bool,
+}
+
+impl TypeScriptConstant {
+    /// Export the constant string `C`, with its [`description`](crate::Options::description) and
+    /// [`deprecated`](crate::Options::deprecated) options.
+    pub fn of<C: ConstantString>() -> Self {
+        Self {
+            name: C::NAME,
+            value: C::VALUE,
+            description: C::OPTIONS.description,
+            deprecated: C::OPTIONS.deprecated,
+        }
+    }
+
+    /// Export all constant strings registered with the `inventory` feature, sorted by name.
+    ///
+    /// A constant defined by several invocations of [`constant_string`](crate::constant_string) with the same name and
+    /// value, e.g. in different modules, is exported once.
+    ///
+    /// # Example
+    /// ```
+    /// use constant_string::{codegen::{TypeScriptConstant, typescript}, constant_string};
+    ///
+    /// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+    ///
+    /// assert!(typescript(&TypeScriptConstant::all()).contains(
+    ///     r#"export const NotFoundErrorCode: NotFoundErrorCode = "notFound";"#
+    /// ));
+    /// ```
+    #[cfg(feature = "inventory")]
+    pub fn all() -> Vec<Self> {
+        let mut constants = crate::inventory::all()
+            .map(|constant| Self {
+                name: constant.name,
+                value: constant.value,
+                description: constant.options.description,
+                deprecated: constant.options.deprecated,
+            })
+            .collect::<Vec<_>>();
+        constants.sort_by_key(|constant| (constant.name, constant.value));
+        constants.dedup();
+        constants
+    }
+}
+
+/// Generate a TypeScript module exporting a string literal type and a constant with the same name for each of
+/// `constants`, so frontends can import the exact values.
+///
+/// The constants are listed with [`constant_typescript`](crate::constant_typescript), or collected from all constant
+/// strings with [`TypeScriptConstant::all`] and the `inventory` feature.
+///
+/// # Example
+/// ```
+/// use constant_string::{codegen::typescript, constant_string, constant_typescript};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound", description = "Not found.");
+///
+/// assert_eq!(
+///     typescript(&constant_typescript!(NotFoundErrorCode)),
+///     r#"// @generated by constant-string. Do not edit.
+///
+/// /** Not found. */
+/// export type NotFoundErrorCode = "notFound";
+/// /** Not found. */
+/// export const NotFoundErrorCode: NotFoundErrorCode = "notFound";
+/// "#
+/// );
+/// ```
+pub fn typescript(constants: &[TypeScriptConstant]) -> String {
+    write_typescript_constants(constants, |constant| {
+        format!(
+            "export const {0}: {0} = {1};\n",
+            constant.name,
+            string_literal(constant.value)
+        )
+    })
+}
+
+/// Generate a TypeScript declaration file, like [`typescript`] with ambient constants.
+pub fn typescript_declarations(constants: &[TypeScriptConstant]) -> String {
+    write_typescript_constants(constants, |constant| {
+        format!("export declare const {0}: {0};\n", constant.name)
+    })
+}
+
+/// Array of [`TypeScriptConstant`] for constant string types, for [`typescript`].
+///
+/// See [`typescript`] for an example.
+#[macro_export]
+macro_rules! constant_typescript {
+    ($($constant:ty),* $(,)?) => {
+        [$($crate::codegen::TypeScriptConstant::of::<$constant>()),*]
+    };
+}
+
+/// Group of constant strings exported to TypeScript as an enum schema by [`zod`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TypeScriptGroup {
+    /// Name of the schema and type.
+    pub name: &'static str,
+
+    /// Values of the members.
+    pub values: &'static [&'static str],
+
+    /// Description, used as the JSDoc comment.
+    pub description: Option<&'static str>,
+}
+
+impl TypeScriptGroup {
+    /// Export the group named `name` with the `values` of its members, such as the `ALL` of a
+    /// [`constant_string_group`](crate::constant_string_group).
+    pub const fn new(name: &'static str, values: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            values,
+            description: None,
+        }
+    }
+}
+
+/// Generate a TypeScript module exporting a Zod schema and its inferred type with the same name for each of
+/// `constants` and `groups`, so frontends validate payloads against the exact values.
+///
+/// Constants are exported as `z.literal` and groups as `z.enum` of the values of their members.
+///
+/// # Example
+/// ```
+/// use constant_string::{
+///     codegen::{TypeScriptGroup, zod},
+///     constant_string, constant_string_group, constant_typescript,
+/// };
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound", description = "Not found.");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// constant_string_group! {
+///     pub struct ErrorCode;
+///     pub trait ErrorCodeMember;
+///     NotFoundErrorCode, ConflictErrorCode
+/// }
+///
+/// assert_eq!(
+///     zod(
+///         &constant_typescript!(NotFoundErrorCode),
+///         &[TypeScriptGroup::new("ErrorCode", ErrorCode::ALL)],
+///     ),
+///     r#"// @generated by constant-string. Do not edit.
+///
+/// import { z } from "zod";
+///
+/// /** Not found. */
+/// export const NotFoundErrorCode = z.literal("notFound");
+/// /** Not found. */
+/// export type NotFoundErrorCode = z.infer<typeof NotFoundErrorCode>;
+///
+/// export const ErrorCode = z.enum(["notFound", "conflict"]);
+/// export type ErrorCode = z.infer<typeof ErrorCode>;
+/// "#
+/// );
+/// ```
+pub fn zod(constants: &[TypeScriptConstant], groups: &[TypeScriptGroup]) -> String {
+    let mut code =
+        "// @generated by constant-string. Do not edit.\n\nimport { z } from \"zod\";\n".to_owned();
+
+    let schemas = constants
+        .iter()
+        .map(|constant| {
+            (
+                constant.name,
+                jsdoc_comment(constant.description, constant.deprecated),
+                format!("z.literal({})", string_literal(constant.value)),
+            )
+        })
+        .chain(groups.iter().map(|group| {
+            let schema = match group.values {
+                [] => "z.never()".to_owned(),
+                values => format!(
+                    "z.enum([{}])",
+                    values
+                        .iter()
+                        .map(|value| string_literal(value))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+
+            (group.name, jsdoc_comment(group.description, false), schema)
+        }));
+
+    for (name, doc, schema) in schemas {
+        code.push('\n');
+        code.push_str(&doc);
+        code.push_str(&format!("export const {name} = {schema};\n"));
+        code.push_str(&doc);
+        code.push_str(&format!("export type {name} = z.infer<typeof {name}>;\n"));
+    }
+
+    code
+}
+
+/// Write the TypeScript module for `constants` to `path`, or the declaration file if it ends with `.d.ts`.
+pub fn write_typescript(
+    path: impl AsRef<Path>,
+    constants: &[TypeScriptConstant],
+) -> Result<(), CodegenError> {
+    let path = path.as_ref();
+    let code = if path.to_string_lossy().ends_with(".d.ts") {
+        typescript_declarations(constants)
+    } else {
+        typescript(constants)
+    };
+
+    fs::write(path, code)?;
+
+    Ok(())
+}
+
+/// Write the type of each of `constants`, followed by its constant from `constant`.
+fn write_typescript_constants(
+    constants: &[TypeScriptConstant],
+    constant: impl Fn(&TypeScriptConstant) -> String,
+) -> String {
+    let mut code = "// @generated by constant-string. Do not edit.\n".to_owned();
+
+    for item in constants {
+        let doc = jsdoc_comment(item.description, item.deprecated);
+
+        code.push('\n');
+        code.push_str(&doc);
+        code.push_str(&format!(
+            "export type {} = {};\n",
+            item.name,
+            string_literal(item.value)
+        ));
+        code.push_str(&doc);
+        code.push_str(&constant(item));
+    }
+
+    code
+}
+
+/// JSDoc comment with `description`, marked as `@deprecated` if `deprecated`.
+fn jsdoc_comment(description: Option<&str>, deprecated: bool) -> String {
+    let mut lines = description
+        .into_iter()
+        .flat_map(str::lines)
+        .map(|line| line.replace("*/", "*\\/"))
+        .collect::<Vec<_>>();
+    if deprecated {
+        lines.push("@deprecated".to_owned());
+    }
+
+    match lines.as_slice() {
+        [] => String::new(),
+        [line] => format!("/** {line} */\n"),
+        lines => {
+            let mut comment = "/**\n".to_owned();
+            for line in lines {
+                comment.push_str(" *");
+                if !line.is_empty() {
+                    comment.push(' ');
+                    comment.push_str(line);
+                }
+                comment.push('\n');
+            }
+            comment.push_str(" */\n");
+            comment
+        }
+    }
+}
+
+/// Read the catalog at `input` with `read` and write its code to `output`.
+fn write(
+    input: &Path,
+    output: &Path,
+    read: fn(&Path) -> Result<Catalog, CodegenError>,
+) -> Result<(), CodegenError> {
+    println!("cargo::rerun-if-changed={}", input.display());
+
+    fs::write(output, read(input)?.generate()?)?;
+
+    Ok(())
+}
+
+/// Read `path` and parse it with `parse`, in the [`Format`] of its extension.
+fn read<T>(
+    path: &Path,
+    parse: fn(&str, Format) -> Result<T, CodegenError>,
+) -> Result<T, CodegenError> {
+    let format = Format::from_path(path).ok_or_else(|| CodegenError::Format(path.to_path_buf()))?;
+
+    parse(&fs::read_to_string(path)?, format)
+}
+
+/// Deserialize `source` in `format`.
+fn deserialize<T: DeserializeOwned>(source: &str, format: Format) -> Result<T, CodegenError> {
+    match format {
+        Format::Json => serde_json::from_str(source).map_err(CodegenError::Json),
+        Format::Toml => toml::from_str(source).map_err(CodegenError::Toml),
+        Format::Yaml => serde_yaml::from_str(source).map_err(CodegenError::Yaml),
+    }
+}
+
+/// TypeScript token.
+#[derive(Debug)]
+enum Token {
+    /// JSDoc comment, without the delimiters and leading asterisks.
+    Doc(String),
+    /// Identifier or keyword.
+    Ident(String),
+    /// String literal, unescaped.
+    String(String),
+    /// Any other character, including the backtick of a skipped template literal.
+    Punct(char),
+}
+
+/// Split TypeScript `source` into tokens, skipping whitespace, comments other than JSDoc and template literals.
+fn tokenize(source: &str) -> Result<Vec<Token>, CodegenError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '/' if chars.next_if_eq(&'/').is_some() => {
+                chars.by_ref().find(|&char| char == '\n');
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                let mut comment = String::new();
+                loop {
+                    match chars.next() {
+                        Some('*') if chars.next_if_eq(&'/').is_some() => break,
+                        Some(char) => comment.push(char),
+                        None => return Err(CodegenError::TypeScript("unterminated comment")),
+                    }
+                }
+                if let Some(doc) = comment.strip_prefix('*') {
+                    tokens.push(Token::Doc(jsdoc(doc)));
+                }
+            }
+            '`' => {
+                if chars.by_ref().all(|char| char != '`') {
+                    return Err(CodegenError::TypeScript("unterminated template literal"));
+                }
+                tokens.push(Token::Punct('`'));
+            }
+            '"' | '\'' => {
+                let quote = char;
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(char) if char == quote => break,
+                        Some('\\') => value.extend(unescape(&mut chars)?),
+                        Some('\n') | None => {
+                            return Err(CodegenError::TypeScript("unterminated string literal"));
+                        }
+                        Some(char) => value.push(char),
+                    }
+                }
+                tokens.push(Token::String(value));
+            }
+            char if char.is_alphanumeric() || char == '_' || char == '$' => {
+                let mut ident = String::from(char);
+                while let Some(char) =
+                    chars.next_if(|&char| char.is_alphanumeric() || char == '_' || char == '$')
+                {
+                    ident.push(char);
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            char if char.is_whitespace() => {}
+            char => tokens.push(Token::Punct(char)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Character of the escape sequence after a backslash in a string literal, or `None` for a line continuation.
+///
+/// Unknown escapes, such as `\a`, stand for the escaped character. Legacy octal escapes are rejected, as are lone
+/// surrogates, which a Rust string can't hold.
+fn unescape(chars: &mut Peekable<Chars<'_>>) -> Result<Option<char>, CodegenError> {
+    let char = match chars.next() {
+        Some('b') => '\u{8}',
+        Some('f') => '\u{c}',
+        Some('n') => '\n',
+        Some('r') => '\r',
+        Some('t') => '\t',
+        Some('v') => '\u{b}',
+        Some('0') if chars.peek().is_none_or(|char| !char.is_ascii_digit()) => '\0',
+        Some('0'..='9') => return Err(CodegenError::TypeScript("unsupported octal escape")),
+        Some('x') => char::from_u32(hex_escape(chars, 2)?)
+            .ok_or(CodegenError::TypeScript("invalid hexadecimal escape"))?,
+        Some('u') => {
+            let mut code = unicode_escape(chars)?;
+            if (0xd800..0xdc00).contains(&code) {
+                let mut low = chars.clone();
+                if low.next() == Some('\\')
+                    && low.next() == Some('u')
+                    && let Ok(low_code @ 0xdc00..0xe000) = unicode_escape(&mut low)
+                {
+                    code = 0x10000 + ((code - 0xd800) << 10) + (low_code - 0xdc00);
+                    *chars = low;
+                }
+            }
+            char::from_u32(code).ok_or(CodegenError::TypeScript("invalid unicode escape"))?
+        }
+        Some('\r') => {
+            chars.next_if_eq(&'\n');
+            return Ok(None);
+        }
+        Some('\n' | '\u{2028}' | '\u{2029}') => return Ok(None),
+        Some(char) => char,
+        None => return Err(CodegenError::TypeScript("unterminated string literal")),
+    };
+
+    Ok(Some(char))
+}
+
+/// Code point of a `\uXXXX` or `\u{X...}` escape sequence, after the `u`.
+fn unicode_escape(chars: &mut Peekable<Chars<'_>>) -> Result<u32, CodegenError> {
+    if chars.next_if_eq(&'{').is_none() {
+        return hex_escape(chars, 4)
+            .map_err(|_| CodegenError::TypeScript("invalid unicode escape"));
+    }
+
+    let mut code = None;
+    loop {
+        match (chars.next(), code) {
+            (Some('}'), Some(code)) => return Ok(code),
+            (Some(char), _) if let Some(digit) = char.to_digit(16) => {
+                let next = code.unwrap_or(0) * 16 + digit;
+                if next > 0x10ffff {
+                    return Err(CodegenError::TypeScript("invalid unicode escape"));
+                }
+                code = Some(next);
+            }
+            _ => return Err(CodegenError::TypeScript("invalid unicode escape")),
+        }
+    }
+}
+
+/// Value of exactly `digits` hexadecimal digits of an escape sequence.
+fn hex_escape(chars: &mut Peekable<Chars<'_>>, digits: usize) -> Result<u32, CodegenError> {
+    (0..digits).try_fold(0, |code, _| {
+        chars
+            .next()
+            .and_then(|char| char.to_digit(16))
+            .map(|digit| code * 16 + digit)
+            .ok_or(CodegenError::TypeScript("invalid hexadecimal escape"))
+    })
+}
+
+/// Text of a JSDoc comment, up to its first block tag.
+fn jsdoc(comment: &str) -> String {
+    comment
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').map_or(line, str::trim_start)
+        })
+        .take_while(|line| !line.starts_with('@'))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_owned()
+}
+
+/// Values of a union of string literals at the start of `tokens`, with the number of tokens it spans.
+///
+/// The union must be followed by a semicolon, the next statement or the end of the source.
+fn union(tokens: &[Token]) -> Option<(Vec<String>, usize)> {
+    let mut values = Vec::new();
+    let mut index = usize::from(matches!(tokens.first(), Some(Token::Punct('|'))));
+
+    loop {
+        let Some(Token::String(value)) = tokens.get(index) else {
+            return None;
+        };
+        values.push(value.clone());
+        index += 1;
+
+        match tokens.get(index) {
+            Some(Token::Punct('|')) => index += 1,
+            Some(Token::Punct(';')) => return Some((values, index + 1)),
+            None | Some(Token::Doc(_) | Token::Ident(_)) => return Some((values, index)),
+            Some(_) => return None,
+        }
+    }
+}
+
+/// Value of a string schema with a `const` or a single-value `enum`.
+fn single_value(schema: &Value) -> Option<&str> {
+    if schema.get("type").is_some_and(|r#type| r#type != "string") {
+        return None;
+    }
+
+    match schema.get("const") {
+        Some(value) => value.as_str(),
+        None => match schema.get("enum")?.as_array()?.as_slice() {
+            [value] => value.as_str(),
+            _ => None,
+        },
+    }
+}
+
+/// Error generating code from a catalog.
+#[derive(Debug)]
+pub enum CodegenError {
+    /// Reading the catalog or writing the code failed.
+    Io(io::Error),
+    /// The catalog is invalid JSON.
+    Json(serde_json::Error),
+    /// The catalog is invalid TOML.
+    Toml(toml::de::Error),
+    /// The catalog is invalid YAML.
+    Yaml(serde_yaml::Error),
+    /// The format of the catalog file can't be determined from its extension.
+    Format(PathBuf),
+    /// A name isn't a valid Rust identifier.
+    Identifier(String),
+    /// A name is defined more than once.
+    Duplicate(String),
+    /// The TypeScript source is invalid.
+    TypeScript(&'static str),
+    /// A group refers to a constant string not in the catalog.
+    UnknownConstant {
+        /// Name of the group.
+        group: String,
+        /// Name of the unknown constant string.
+        constant: String,
+    },
+    /// Formatting the code failed.
+    Fmt(fmt::Error),
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+            Self::Json(error) => write!(f, "invalid JSON catalog: {error}"),
+            Self::Toml(error) => write!(f, "invalid TOML catalog: {error}"),
+            Self::Yaml(error) => write!(f, "invalid YAML catalog: {error}"),
+            Self::Format(path) => write!(
+                f,
+                "unknown catalog format of {:?}, expected extension json, toml, yaml or yml",
+                path.display()
+            ),
+            Self::TypeScript(message) => write!(f, "invalid TypeScript: {message}"),
+            Self::Identifier(name) => write!(f, "invalid identifier {name:?}"),
+            Self::Duplicate(name) => write!(f, "duplicate name {name:?}"),
+            Self::UnknownConstant { group, constant } => {
+                write!(f, "unknown constant {constant:?} in group {group:?}")
+            }
+            Self::Fmt(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for CodegenError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Json(error) => Some(error),
+            Self::Toml(error) => Some(error),
+            Self::Yaml(error) => Some(error),
+            Self::Fmt(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CodegenError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<fmt::Error> for CodegenError {
+    fn from(error: fmt::Error) -> Self {
+        Self::Fmt(error)
+    }
+}
+
+/// Write `description` as documentation lines with `indent`.
+fn write_doc(code: &mut String, indent: &str, description: Option<&str>) {
+    for line in description.into_iter().flat_map(str::lines) {
+        code.push_str(indent);
+        code.push_str("///");
+        if !line.is_empty() {
+            code.push(' ');
+            code.push_str(line);
+        }
+        code.push('\n');
+    }
+}
+
+/// Strict and reserved keywords of Rust 2024, which can't be used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Whether `name` is a valid Rust identifier other than a keyword.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+        && name != "_"
+        && !KEYWORDS.contains(&name)
+}
+
+/// Convert a name to pascal case, capitalizing each word separated by characters other than letters and digits.
+fn pascal_case(name: &str) -> String {
+    name.split(|char: char| !char.is_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// Convert a name in camel case to screaming snake case.
+fn screaming_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (index, char) in chars.iter().enumerate() {
+        if index > 0 && char.is_uppercase() {
+            let previous = chars[index - 1];
+            let next_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_lowercase)
+            {
+                result.push('_');
+            }
+        }
+        result.extend(char.to_uppercase());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Catalog, CodegenError, Constant, Format, Token, TypeScriptGroup, pascal_case,
+        screaming_snake_case, tokenize, typescript_declarations, variant_name,
+    };
+    use crate::constant_string;
+
+    const GENERATED: &str = r#"// @generated by constant-string from a catalog. Do not edit.
+
+::constant_string::constant_string!(
+    /// The resource was not found.
+    ///
+    /// Returned by all endpoints.
+    NotFoundErrorCode,
+    NOT_FOUND_ERROR_CODE,
+    "notFound",
+    aliases = ["not_found"],
+    description = "The resource was not found.\n\nReturned by all endpoints.",
+);
+
+::constant_string::constant_string!(
+    #[deprecated]
+    ConflictErrorCode,
+    CONFLICT,
+    "conflict",
+    case_insensitive,
+    deprecated,
+);
+
+/// Error codes.
+pub const ERROR_CODES: &[&str] = &[NOT_FOUND_ERROR_CODE, CONFLICT];
+"#;
+
+    #[test]
+    fn generate() {
+        let yaml = r#"
+constants:
+  - name: NotFoundErrorCode
+    value: notFound
+    description: |-
+      The resource was not found.
+
+      Returned by all endpoints.
+    aliases: [not_found]
+  - name: ConflictErrorCode
+    code_name: CONFLICT
+    value: conflict
+    case_insensitive: true
+    deprecated: true
+groups:
+  - name: ERROR_CODES
+    description: Error codes.
+    constants: [NotFoundErrorCode, ConflictErrorCode]
+"#;
+        let toml = r#"
+[[constants]]
+name = "NotFoundErrorCode"
+value = "notFound"
+description = """
+The resource was not found.
+
+Returned by all endpoints."""
+aliases = ["not_found"]
+
+[[constants]]
+name = "ConflictErrorCode"
+code_name = "CONFLICT"
+value = "conflict"
+case_insensitive = true
+deprecated = true
+
+[[groups]]
+name = "ERROR_CODES"
+description = "Error codes."
+constants = ["NotFoundErrorCode", "ConflictErrorCode"]
+"#;
+        let json = r#"{
+  "constants": [
+    {
+      "name": "NotFoundErrorCode",
+      "value": "notFound",
+      "description": "The resource was not found.\n\nReturned by all endpoints.",
+      "aliases": ["not_found"]
+    },
+    {
+      "name": "ConflictErrorCode",
+      "code_name": "CONFLICT",
+      "value": "conflict",
+      "case_insensitive": true,
+      "deprecated": true
+    }
+  ],
+  "groups": [
+    {
+      "name": "ERROR_CODES",
+      "description": "Error codes.",
+      "constants": ["NotFoundErrorCode", "ConflictErrorCode"]
+    }
+  ]
+}"#;
+
+        for (source, format) in [
+            (yaml, Format::Yaml),
+            (toml, Format::Toml),
+            (json, Format::Json),
+        ] {
+            assert_eq!(
+                Catalog::parse(source, format)
+                    .and_then(|catalog| catalog.generate())
+                    .map_err(|error| error.to_string())
+                    .as_deref(),
+                Ok(GENERATED)
+            );
+        }
+    }
+
+    #[test]
+    fn generate_errors() {
+        let generate = |source: &str| {
+            Catalog::parse(source, Format::Yaml)
+                .and_then(|catalog| catalog.generate())
+                .map_err(|error| error.to_string())
+                .err()
+        };
+
+        assert_eq!(
+            generate("constants: [{ name: Not Found, value: notFound }]").as_deref(),
+            Some(r#"invalid identifier "Not Found""#)
+        );
+        assert_eq!(
+            generate("constants: [{ name: type, value: type }]").as_deref(),
+            Some(r#"invalid identifier "type""#)
+        );
+        assert_eq!(
+            generate("constants: [{ name: Self, value: self, code_name: SELF_CODE }]").as_deref(),
+            Some(r#"invalid identifier "Self""#)
+        );
+        assert_eq!(
+            generate("constants: [{ name: NotFound, value: a }, { name: NotFound, value: b }]")
+                .as_deref(),
+            Some(r#"duplicate name "NotFound""#)
+        );
+        assert_eq!(
+            generate("groups: [{ name: CODES, constants: [NotFound] }]").as_deref(),
+            Some(r#"unknown constant "NotFound" in group "CODES""#)
+        );
+        assert_eq!(
+            generate(
+                "constants: [{ name: NotFound, value: a }, { name: NotFoundErrorCode, value: b }]
+groups: [{ name: CODES, enum_name: ErrorCode, constants: [NotFound, NotFoundErrorCode] }]"
+            )
+            .as_deref(),
+            Some(r#"duplicate name "ErrorCode::NotFound""#)
+        );
+        assert!(matches!(
+            Catalog::parse("constants: [{ code: notFound }]", Format::Yaml),
+            Err(CodegenError::Yaml(_))
+        ));
+        assert!(matches!(
+            Catalog::read("catalog.csv"),
+            Err(CodegenError::Format(_))
+        ));
+    }
+
+    #[test]
+    fn from_openapi() {
+        let yaml = r##"
+openapi: 3.1.0
+info:
+  title: Partner
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    NotFoundErrorCode:
+      type: string
+      enum: [notFound]
+      description: The resource was not found.
+    conflict_error:
+      type: object
+      properties:
+        code:
+          const: conflict
+          deprecated: true
+        status:
+          type: integer
+          enum: [409]
+        reason:
+          type: string
+          enum: [locked, duplicate]
+        details:
+          $ref: "#/components/schemas/NotFoundErrorCode"
+"##;
+
+        assert_eq!(
+            Catalog::from_openapi(yaml, Format::Yaml).ok(),
+            Some(Catalog {
+                constants: vec![
+                    Constant {
+                        name: "NotFoundErrorCode".to_owned(),
+                        value: "notFound".to_owned(),
+                        description: Some("The resource was not found.".to_owned()),
+                        ..Constant::default()
+                    },
+                    Constant {
+                        name: "ConflictErrorCode".to_owned(),
+                        value: "conflict".to_owned(),
+                        deprecated: true,
+                        ..Constant::default()
+                    },
+                ],
+                groups: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn from_json_schema() {
+        let json = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "title": "config",
+  "type": "object",
+  "properties": {
+    "version": { "const": "v2", "description": "Version of the configuration." },
+    "mode": { "$ref": "#/$defs/strict_mode" }
+  },
+  "$defs": {
+    "strict_mode": { "type": "string", "enum": ["strict"] }
+  },
+  "definitions": {
+    "LegacyMode": { "const": "legacy", "deprecated": true }
+  }
+}"##;
+
+        assert_eq!(
+            Catalog::from_json_schema(json, Format::Json)
+                .ok()
+                .map(|catalog| catalog.constants),
+            Some(vec![
+                Constant {
+                    name: "StrictMode".to_owned(),
+                    value: "strict".to_owned(),
+                    ..Constant::default()
+                },
+                Constant {
+                    name: "LegacyMode".to_owned(),
+                    value: "legacy".to_owned(),
+                    deprecated: true,
+                    ..Constant::default()
+                },
+                Constant {
+                    name: "ConfigVersion".to_owned(),
+                    value: "v2".to_owned(),
+                    description: Some("Version of the configuration.".to_owned()),
+                    ..Constant::default()
+                },
+            ])
+        );
+        assert_eq!(
+            Catalog::from_json_schema(r#"{ "const": "untitled" }"#, Format::Json).ok(),
+            Some(Catalog::default())
+        );
+    }
+
+    #[test]
+    fn from_typescript() {
+        let typescript = r#"
+// Codes returned by the API.
+/**
+ * Error code.
+ *
+ * @see https://example.com/errors
+ */
+export type ErrorCode =
+    | "notFound" // Missing.
+    | 'conflict';
+
+type Status = "200" | "404"
+export type Mixed = "a" | number;
+const message = "type Quoted = 'quoted';";
+type Template = `${ErrorCode}`;
+"#;
+
+        assert_eq!(
+            Catalog::from_typescript(typescript)
+                .and_then(|catalog| catalog.generate())
+                .map_err(|error| error.to_string())
+                .as_deref(),
+            Ok(
+                r#"// @generated by constant-string from a catalog. Do not edit.
+
+::constant_string::constant_string!(
+    NotFoundErrorCode,
+    NOT_FOUND_ERROR_CODE,
+    "notFound",
+);
+
+::constant_string::constant_string!(
+    ConflictErrorCode,
+    CONFLICT_ERROR_CODE,
+    "conflict",
+);
+
+::constant_string::constant_string!(
+    Status200,
+    STATUS200,
+    "200",
+);
+
+::constant_string::constant_string!(
+    Status404,
+    STATUS404,
+    "404",
+);
+
+/// Error code.
+pub const ERROR_CODE: &[&str] = &[NOT_FOUND_ERROR_CODE, CONFLICT_ERROR_CODE];
+
+/// Error code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorCode {
+    /// [`NotFoundErrorCode`].
+    NotFound,
+    /// [`ConflictErrorCode`].
+    Conflict,
+}
+
+#[allow(deprecated)]
+impl ErrorCode {
+    /// Number of variants.
+    pub const COUNT: usize = 2;
+
+    /// Variants in declaration order.
+    pub const VARIANTS: &'static [Self] = &[Self::NotFound, Self::Conflict];
+
+    /// Iterate over the variants in declaration order.
+    pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+        Self::VARIANTS.iter().copied()
+    }
+
+    /// Value of the constant string of the variant.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotFound => NOT_FOUND_ERROR_CODE,
+            Self::Conflict => CONFLICT_ERROR_CODE,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl ::core::convert::From<NotFoundErrorCode> for ErrorCode {
+    fn from(_: NotFoundErrorCode) -> Self {
+        Self::NotFound
+    }
+}
+
+#[allow(deprecated)]
+impl ::core::convert::From<ConflictErrorCode> for ErrorCode {
+    fn from(_: ConflictErrorCode) -> Self {
+        Self::Conflict
+    }
+}
+
+pub const STATUS: &[&str] = &[STATUS200, STATUS404];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Status {
+    /// [`Status200`].
+    Status200,
+    /// [`Status404`].
+    Status404,
+}
+
+#[allow(deprecated)]
+impl Status {
+    /// Number of variants.
+    pub const COUNT: usize = 2;
+
+    /// Variants in declaration order.
+    pub const VARIANTS: &'static [Self] = &[Self::Status200, Self::Status404];
+
+    /// Iterate over the variants in declaration order.
+    pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+        Self::VARIANTS.iter().copied()
+    }
+
+    /// Value of the constant string of the variant.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Status200 => STATUS200,
+            Self::Status404 => STATUS404,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl ::core::convert::From<Status200> for Status {
+    fn from(_: Status200) -> Self {
+        Self::Status200
+    }
+}
+
+#[allow(deprecated)]
+impl ::core::convert::From<Status404> for Status {
+    fn from(_: Status404) -> Self {
+        Self::Status404
+    }
+}
+"#
+            ),
+        );
+        assert!(matches!(
+            Catalog::from_typescript(r#"type ErrorCode = "notFound"#),
+            Err(CodegenError::TypeScript("unterminated string literal"))
+        ));
+    }
+
+    #[test]
+    fn from_typescript_keywords() {
+        let code = Catalog::from_typescript(r#"type Kind = "self" | "other" | "type";"#)
+            .and_then(|catalog| catalog.generate())
+            .expect("generated code");
+
+        for line in [
+            "    SelfKind,\n    SELF_KIND,\n    \"self\",\n",
+            "pub enum Kind {\n    /// [`SelfKind`].\n    SelfKind,\n    /// [`OtherKind`].\n    Other,\n    /// [`TypeKind`].\n    Type,\n}",
+            "            Self::SelfKind => SELF_KIND,\n",
+        ] {
+            assert!(code.contains(line), "{line:?} in {code}");
+        }
+    }
+
+    #[test]
+    fn typescript_escapes() {
+        fn string(source: &str) -> Result<String, String> {
+            match tokenize(source)
+                .map_err(|error| error.to_string())?
+                .as_slice()
+            {
+                [Token::String(value)] => Ok(value.clone()),
+                tokens => Err(format!("{tokens:?}")),
+            }
+        }
+
+        for (source, value) in [
+            (r#""a\nb\rc\td""#, "a\nb\rc\td"),
+            (r#""\b\f\v\0""#, "\u{8}\u{c}\u{b}\0"),
+            (r#""\x41\x7e""#, "A~"),
+            (r#""\u0041\u00e9""#, "A\u{e9}"),
+            (r#""\u{41}\u{1F600}\u{0000041}""#, "A\u{1f600}A"),
+            (r#""\uD83D\uDE00""#, "\u{1f600}"),
+            (r#"'\'\"\\\/\a'"#, "'\"\\/a"),
+            (
+                "\"line\\\ncontinued\\\r\nagain\\\rand\\\u{2028}more\"",
+                "linecontinuedagainandmore",
+            ),
+        ] {
+            assert_eq!(string(source), Ok(value.to_owned()), "{source}");
+        }
+
+        for (source, error) in [
+            (r#""\x4""#, "invalid hexadecimal escape"),
+            (r#""\xg0""#, "invalid hexadecimal escape"),
+            (r#""\u004""#, "invalid unicode escape"),
+            (r#""\u{}""#, "invalid unicode escape"),
+            (r#""\u{110000}""#, "invalid unicode escape"),
+            (r#""\u{41""#, "invalid unicode escape"),
+            (r#""\uD83D""#, "invalid unicode escape"),
+            (r#""\uDE00\uD83D""#, "invalid unicode escape"),
+            (r#""\01""#, "unsupported octal escape"),
+            (r#""\7""#, "unsupported octal escape"),
+            (r#""\"#, "unterminated string literal"),
+        ] {
+            assert_eq!(
+                string(source),
+                Err(format!("invalid TypeScript: {error}")),
+                "{source}"
+            );
+        }
+    }
+
+    #[cfg(feature = "inventory")]
+    #[test]
+    fn typescript_all() {
+        {
+            constant_string!(Exported, EXPORTED, "exported", description = "Exported.");
+        }
+        {
+            constant_string!(Exported, EXPORTED, "exported", description = "Exported.");
+        }
+
+        assert_eq!(
+            super::TypeScriptConstant::all()
+                .into_iter()
+                .filter(|constant| constant.name == "Exported")
+                .collect::<Vec<_>>(),
+            [super::TypeScriptConstant {
+                name: "Exported",
+                value: "exported",
+                description: Some("Exported."),
+                deprecated: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn typescript() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+        constant_string!(
+            #[deprecated]
+            Conflict,
+            CONFLICT,
+            "conflict",
+            deprecated,
+            description = "Conflict.\n\nSee */docs.",
+        );
+
+        #[allow(deprecated)]
+        let constants = constant_typescript!(NotFound, Conflict);
+        assert_eq!(
+            super::typescript(&constants),
+            r#"// @generated by constant-string. Do not edit.
+
+export type NotFound = "notFound";
+export const NotFound: NotFound = "notFound";
+
+/**
+ * Conflict.
+ *
+ * See *\/docs.
+ * @deprecated
+ */
+export type Conflict = "conflict";
+/**
+ * Conflict.
+ *
+ * See *\/docs.
+ * @deprecated
+ */
+export const Conflict: Conflict = "conflict";
+"#
+        );
+        assert_eq!(
+            typescript_declarations(&constants[..1]),
+            r#"// @generated by constant-string. Do not edit.
+
+export type NotFound = "notFound";
+export declare const NotFound: NotFound;
+"#
+        );
+    }
+
+    #[test]
+    fn zod() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+        constant_string!(Quoted, QUOTED, r#"say "hi""#, deprecated);
+
+        let groups = [
+            TypeScriptGroup {
+                description: Some("Error code."),
+                ..TypeScriptGroup::new("ErrorCode", &[NOT_FOUND, QUOTED])
+            },
+            TypeScriptGroup::new("Empty", &[]),
+        ];
+        assert_eq!(
+            super::zod(&constant_typescript!(NotFound, Quoted), &groups),
+            r#"// @generated by constant-string. Do not edit.
+
+import { z } from "zod";
+
+export const NotFound = z.literal("notFound");
+export type NotFound = z.infer<typeof NotFound>;
+
+/** @deprecated */
+export const Quoted = z.literal("say \"hi\"");
+/** @deprecated */
+export type Quoted = z.infer<typeof Quoted>;
+
+/** Error code. */
+export const ErrorCode = z.enum(["notFound", "say \"hi\""]);
+/** Error code. */
+export type ErrorCode = z.infer<typeof ErrorCode>;
+
+export const Empty = z.never();
+export type Empty = z.infer<typeof Empty>;
+"#
+        );
+    }
+
+    #[test]
+    fn generate_phf() {
+        let yaml = r#"
+constants:
+  - name: NotFoundErrorCode
+    value: notFound
+  - name: ConflictErrorCode
+    value: conflict
+groups:
+  - name: ERROR_CODES
+    enum_name: ErrorCode
+    phf: true
+    constants: [NotFoundErrorCode, ConflictErrorCode]
+"#;
+
+        let code = Catalog::parse(yaml, Format::Yaml)
+            .and_then(|catalog| catalog.generate())
+            .unwrap_or_default();
+        assert!(code.contains(
+            "/// Values of [`ERROR_CODES`] as a perfect hash set.\npub static ERROR_CODES_SET: ::phf::Set<&'static str> = ::phf::Set {"
+        ));
+        assert!(code.contains(
+            "/// Variants of [`ErrorCode`] by value as a perfect hash map.\npub static ERROR_CODES_MAP: ::phf::Map<&'static str, ErrorCode> = ::phf::Map {"
+        ));
+        assert!(code.contains("(\"notFound\", ErrorCode::NotFound)"));
+
+        assert_eq!(
+            Catalog::parse(
+                &yaml.replace("value: conflict", "value: notFound"),
+                Format::Yaml
+            )
+            .and_then(|catalog| catalog.generate())
+            .map_err(|error| error.to_string())
+            .err()
+            .as_deref(),
+            Some(r#"duplicate name "notFound""#)
+        );
+    }
+
+    #[test]
+    fn code_name() {
+        assert_eq!(screaming_snake_case("NotFound"), "NOT_FOUND");
+        assert_eq!(screaming_snake_case("HTTPError2Code"), "HTTP_ERROR2_CODE");
+        assert_eq!(screaming_snake_case("Conflict"), "CONFLICT");
+        assert_eq!(pascal_case("conflict_error-code"), "ConflictErrorCode");
+        assert_eq!(variant_name("NotFoundErrorCode", "ErrorCode"), "NotFound");
+        assert_eq!(variant_name("SelfKind", "Kind"), "SelfKind");
+        assert_eq!(variant_name("KindSelf", "Kind"), "KindSelf");
+    }
+}
@@ -0,0 +1,150 @@
+use core::{fmt, ops::Deref};
+
+use crate::{ConstantString, Options};
+
+/// Constant string with the value `VALUE` as a const parameter.
+///
+/// Behaves like a type defined by [`constant_string`](crate::constant_string) with default [`Options`], named
+/// `ConstStr`, so one-off constants don't need a macro invocation and generic code can abstract over the value.
+/// Requires a nightly compiler for `adt_const_params`.
+///
+/// # Example
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// # extern crate serde;
+/// #
+/// use constant_string::ConstStr;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Deserialize, Serialize)]
+/// struct NotFoundError {
+///     code: ConstStr<"notFound">,
+/// }
+///
+/// assert_eq!(
+///     serde_json::to_string(&NotFoundError { code: ConstStr }).expect("serializable value"),
+///     r#"{"code":"notFound"}"#
+/// );
+/// assert!(serde_json::from_str::<NotFoundError>(r#"{"code":"conflict"}"#).is_err());
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ConstStr<const VALUE: &'static str>;
+
+impl<const VALUE: &'static str> Deref for ConstStr<VALUE> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        VALUE
+    }
+}
+
+impl<const VALUE: &'static str> fmt::Debug for ConstStr<VALUE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(VALUE, f)
+    }
+}
+
+impl<const VALUE: &'static str> ConstantString for ConstStr<VALUE> {
+    const NAME: &'static str = "ConstStr";
+
+    const VALUE: &'static str = VALUE;
+
+    const OPTIONS: Options = Options::DEFAULT;
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const VALUE: &'static str> serde::Deserialize<'de> for ConstStr<VALUE> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_str(crate::serde::ConstantStringVisitor::<Self>::new())
+            .map(|()| Self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const VALUE: &'static str> serde::Serialize for ConstStr<VALUE> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(VALUE)
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<const VALUE: &'static str> utoipa::PartialSchema for ConstStr<VALUE> {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::utoipa::schema::<Self>()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<const VALUE: &'static str> utoipa::ToSchema for ConstStr<VALUE> {
+    fn name() -> std::borrow::Cow<'static, str> {
+        crate::utoipa::name::<Self>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstStr;
+    use crate::{ConstantOr, ConstantString};
+
+    fn value<C: ConstantString>() -> &'static str {
+        C::VALUE
+    }
+
+    #[test]
+    fn const_str() {
+        assert_eq!(&*ConstStr::<"notFound">, "notFound");
+        assert_eq!(format!("{:?}", ConstStr::<"notFound">), r#""notFound""#);
+        assert_eq!(value::<ConstStr<"notFound">>(), "notFound");
+        assert_eq!(
+            ConstantOr::<ConstStr<"notFound">>::from("notFound".to_owned()),
+            ConstantOr::Constant(ConstStr)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        assert_eq!(
+            to_string(&ConstStr::<"notFound">).ok().as_deref(),
+            Some(r#""notFound""#)
+        );
+        assert_eq!(
+            from_str::<ConstStr<"notFound">>(r#""notFound""#).ok(),
+            Some(ConstStr)
+        );
+        assert_eq!(
+            from_str::<ConstStr<"notFound">>(r#""other""#)
+                .map_err(|error| error.to_string())
+                .err()
+                .as_deref(),
+            Some(
+                r#"invalid value: string "other", expected constant "notFound" for ConstStr at line 1 column 7"#
+            )
+        );
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(ConstStr::<"notFound">::name(), "ConstStr");
+        assert_eq!(
+            serde_json::to_value(ConstStr::<"notFound">::schema())
+                .ok()
+                .and_then(|schema| schema.get("enum").cloned()),
+            Some(serde_json::json!(["notFound"]))
+        );
+    }
+}
@@ -0,0 +1,1207 @@
+use alloc::string::String;
+use core::{fmt, ops::Deref};
+
+use crate::ConstantString;
+
+/// Constant string `C` or any other string.
+///
+/// A string matching the constant or one of its aliases under its options is the constant. With the `serde` feature,
+/// both are serialized as strings, also for a `compact` constant, so binary formats can tell them apart. A unit is
+/// deserialized as a `compact` constant and `null` as a `null` constant.
+///
+/// # Example
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use constant_string::{ConstantOr, constant_string};
+///
+/// constant_string!(FullResponseFormat, FULL_RESPONSE_FORMAT, "full");
+///
+/// assert_eq!(
+///     ConstantOr::Constant(FullResponseFormat),
+///     serde_json::from_str::<ConstantOr<FullResponseFormat>>("\"full\"").expect("deserializable value")
+/// );
+/// assert_eq!(
+///     ConstantOr::Other("summary".to_owned()),
+///     serde_json::from_str::<ConstantOr<FullResponseFormat>>("\"summary\"").expect("deserializable value")
+/// );
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub enum ConstantOr<C> {
+    /// The constant string.
+    Constant(C),
+    /// Any other string.
+    Other(String),
+}
+
+impl<C: ConstantString> ConstantOr<C> {
+    /// Whether this is the constant string.
+    pub fn is_constant(&self) -> bool {
+        matches!(self, Self::Constant(_))
+    }
+
+    /// The string value.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Constant(_) => C::VALUE,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl<C: ConstantString> Clone for ConstantOr<C> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Constant(_) => Self::Constant(C::default()),
+            Self::Other(value) => Self::Other(value.clone()),
+        }
+    }
+}
+
+impl<C: ConstantString> Default for ConstantOr<C> {
+    fn default() -> Self {
+        Self::Constant(C::default())
+    }
+}
+
+impl<C: ConstantString> Deref for ConstantOr<C> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<C: ConstantString> fmt::Display for ConstantOr<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<C: ConstantString> From<String> for ConstantOr<C> {
+    fn from(value: String) -> Self {
+        if crate::parse::matches_constant::<C>(&value) {
+            Self::Constant(C::default())
+        } else {
+            Self::Other(value)
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, C: ConstantString> arbitrary::Arbitrary<'a> for ConstantOr<C> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(if u.arbitrary()? {
+            Self::Constant(C::default())
+        } else {
+            String::arbitrary(u)?.into()
+        })
+    }
+}
+
+#[cfg(feature = "arcstr")]
+impl<C> From<ConstantOr<C>> for arcstr::ArcStr
+where
+    C: ConstantString + Into<arcstr::ArcStr>,
+{
+    fn from(value: ConstantOr<C>) -> Self {
+        match value {
+            ConstantOr::Constant(constant) => constant.into(),
+            ConstantOr::Other(value) => Self::from(value),
+        }
+    }
+}
+
+#[cfg(feature = "compact_str")]
+impl<C: ConstantString> From<ConstantOr<C>> for compact_str::CompactString {
+    fn from(value: ConstantOr<C>) -> Self {
+        match value {
+            ConstantOr::Constant(_) => Self::const_new(C::VALUE),
+            ConstantOr::Other(value) => Self::from(value),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<C> defmt::Format for ConstantOr<C>
+where
+    C: ConstantString + defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Constant(constant) => constant.format(f),
+            Self::Other(value) => value.as_str().format(f),
+        }
+    }
+}
+
+#[cfg(feature = "dioxus")]
+impl<C: ConstantString> dioxus_core::IntoAttributeValue for ConstantOr<C> {
+    fn into_value(self) -> dioxus_core::AttributeValue {
+        match self {
+            Self::Constant(_) => C::VALUE.into_value(),
+            Self::Other(value) => value.into_value(),
+        }
+    }
+}
+
+#[cfg(feature = "dioxus")]
+impl<C: ConstantString> dioxus_core::IntoDynNode for ConstantOr<C> {
+    fn into_dyn_node(self) -> dioxus_core::DynamicNode {
+        match self {
+            Self::Constant(_) => C::VALUE.into_dyn_node(),
+            Self::Other(value) => value.into_dyn_node(),
+        }
+    }
+}
+
+#[cfg(feature = "fake")]
+impl<C: ConstantString> fake::Dummy<fake::Faker> for ConstantOr<C> {
+    fn dummy_with_rng<R: fake::RngExt + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        if rng.random_bool(0.5) {
+            Self::default()
+        } else {
+            String::dummy_with_rng(config, rng).into()
+        }
+    }
+}
+
+#[cfg(feature = "garde")]
+impl<C> garde::Validate for ConstantOr<C>
+where
+    C: ConstantString + garde::Validate,
+{
+    type Context = C::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> garde::Path,
+        report: &mut garde::Report,
+    ) {
+        if let Self::Constant(constant) = self {
+            constant.validate_into(ctx, parent, report);
+        }
+    }
+}
+
+#[cfg(feature = "garde")]
+impl<C: ConstantString> garde::rules::AsStr for ConstantOr<C> {
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "garde")]
+impl<C: ConstantString> garde::rules::length::HasSimpleLength for ConstantOr<C> {
+    fn length(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+#[cfg(feature = "http")]
+impl<C> TryFrom<ConstantOr<C>> for http::HeaderValue
+where
+    C: ConstantString + Into<http::HeaderValue>,
+{
+    type Error = http::header::InvalidHeaderValue;
+
+    fn try_from(value: ConstantOr<C>) -> Result<Self, Self::Error> {
+        match value {
+            ConstantOr::Constant(constant) => Ok(constant.into()),
+            ConstantOr::Other(value) => value.try_into(),
+        }
+    }
+}
+
+#[cfg(feature = "implicit-clone")]
+impl<C: ConstantString> From<ConstantOr<C>> for implicit_clone::unsync::IString {
+    fn from(value: ConstantOr<C>) -> Self {
+        match value {
+            ConstantOr::Constant(_) => Self::Static(C::VALUE),
+            ConstantOr::Other(value) => Self::from(value),
+        }
+    }
+}
+
+#[cfg(feature = "leptos")]
+impl<C: ConstantString> tachys::view::IntoRender for ConstantOr<C> {
+    type Output = std::borrow::Cow<'static, str>;
+
+    fn into_render(self) -> Self::Output {
+        match self {
+            Self::Constant(_) => std::borrow::Cow::Borrowed(C::VALUE),
+            Self::Other(value) => std::borrow::Cow::Owned(value),
+        }
+    }
+}
+
+#[cfg(feature = "leptos")]
+impl<C: ConstantString> tachys::html::attribute::IntoAttributeValue for ConstantOr<C> {
+    type Output = std::borrow::Cow<'static, str>;
+
+    fn into_attribute_value(self) -> Self::Output {
+        match self {
+            Self::Constant(_) => std::borrow::Cow::Borrowed(C::VALUE),
+            Self::Other(value) => std::borrow::Cow::Owned(value),
+        }
+    }
+}
+
+#[cfg(feature = "maud")]
+impl<C: ConstantString> maud::Render for ConstantOr<C> {
+    fn render_to(&self, buffer: &mut String) {
+        self.as_str().render_to(buffer);
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl<C: ConstantString> From<ConstantOr<C>> for metrics::SharedString {
+    fn from(value: ConstantOr<C>) -> Self {
+        match value {
+            ConstantOr::Constant(_) => Self::const_str(C::VALUE),
+            ConstantOr::Other(value) => Self::from_owned(value),
+        }
+    }
+}
+
+#[cfg(feature = "napi")]
+impl<C: ConstantString> napi::bindgen_prelude::TypeName for ConstantOr<C> {
+    fn type_name() -> &'static str {
+        "String"
+    }
+
+    fn value_type() -> napi::ValueType {
+        napi::ValueType::String
+    }
+}
+
+#[cfg(feature = "napi")]
+impl<C: ConstantString> napi::bindgen_prelude::ValidateNapiValue for ConstantOr<C> {}
+
+#[cfg(feature = "napi")]
+#[allow(unsafe_code)]
+impl<C: ConstantString> napi::bindgen_prelude::ToNapiValue for ConstantOr<C> {
+    unsafe fn to_napi_value(
+        env: napi::sys::napi_env,
+        val: Self,
+    ) -> napi::Result<napi::sys::napi_value> {
+        // SAFETY: The caller guarantees that `env` is a valid environment.
+        unsafe { <&str as napi::bindgen_prelude::ToNapiValue>::to_napi_value(env, val.as_str()) }
+    }
+}
+
+#[cfg(feature = "napi")]
+#[allow(unsafe_code)]
+impl<C: ConstantString> napi::bindgen_prelude::FromNapiValue for ConstantOr<C> {
+    unsafe fn from_napi_value(
+        env: napi::sys::napi_env,
+        napi_val: napi::sys::napi_value,
+    ) -> napi::Result<Self> {
+        // SAFETY: The caller guarantees that `env` and `napi_val` are valid.
+        unsafe { <String as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, napi_val) }
+            .map(Self::from)
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+impl<C: ConstantString> From<ConstantOr<C>> for opentelemetry::StringValue {
+    fn from(value: ConstantOr<C>) -> Self {
+        match value {
+            ConstantOr::Constant(_) => C::VALUE.into(),
+            ConstantOr::Other(value) => value.into(),
+        }
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+impl<C: ConstantString> From<ConstantOr<C>> for opentelemetry::Value {
+    fn from(value: ConstantOr<C>) -> Self {
+        Self::String(value.into())
+    }
+}
+
+#[cfg(feature = "postcard-schema")]
+impl<C: ConstantString> postcard_schema::Schema for ConstantOr<C> {
+    const SCHEMA: &'static postcard_schema::schema::NamedType =
+        &postcard_schema::schema::NamedType {
+            name: "ConstantOr<C>",
+            ty: &postcard_schema::schema::DataModelType::String,
+        };
+}
+
+#[cfg(feature = "proptest")]
+impl<C> proptest::arbitrary::Arbitrary for ConstantOr<C>
+where
+    C: ConstantString + fmt::Debug + 'static,
+{
+    type Parameters = ();
+
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::{
+            arbitrary::any,
+            prop_oneof,
+            strategy::{LazyJust, Strategy},
+        };
+
+        prop_oneof![
+            LazyJust::new(Self::default),
+            any::<String>().prop_map(Self::from),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(feature = "pyo3")]
+impl<'py, C: ConstantString> pyo3::IntoPyObject<'py> for ConstantOr<C> {
+    type Target = pyo3::types::PyString;
+
+    type Output = pyo3::Bound<'py, Self::Target>;
+
+    type Error = std::convert::Infallible;
+
+    fn into_pyobject(self, py: pyo3::Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(pyo3::types::PyString::new(py, self.as_str()))
+    }
+}
+
+#[cfg(feature = "pyo3")]
+impl<'a, 'py, C: ConstantString> pyo3::FromPyObject<'a, 'py> for ConstantOr<C> {
+    type Error = pyo3::PyErr;
+
+    fn extract(obj: pyo3::Borrowed<'a, 'py, pyo3::PyAny>) -> Result<Self, Self::Error> {
+        String::extract(obj).map(Self::from)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<C: ConstantString + 'static> quickcheck::Arbitrary for ConstantOr<C> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        if bool::arbitrary(g) {
+            Self::default()
+        } else {
+            String::arbitrary(g).into()
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Self::Constant(_) => quickcheck::empty_shrinker(),
+            Self::Other(value) => {
+                Box::new(std::iter::once(Self::default()).chain(value.shrink().map(Self::from)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<C: ConstantString> rand::distr::Distribution<ConstantOr<C>> for rand::distr::StandardUniform {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ConstantOr<C> {
+        use rand::{
+            RngExt,
+            distr::{Alphanumeric, SampleString},
+        };
+
+        if rng.random_bool(0.5) {
+            ConstantOr::default()
+        } else {
+            let len = rng.random_range(0..16);
+            Alphanumeric.sample_string(rng, len).into()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C: ConstantString> serde::Serialize for ConstantOr<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C: ConstantString> serde::Deserialize<'de> for ConstantOr<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let visitor = ConstantOrVisitor(core::marker::PhantomData);
+
+        if C::OPTIONS.null && deserializer.is_human_readable() {
+            deserializer.deserialize_option(visitor)
+        } else {
+            deserializer.deserialize_str(visitor)
+        }
+    }
+}
+
+/// Serde visitor for a [`ConstantOr`], matching strings against the constant under its options.
+#[cfg(feature = "serde")]
+struct ConstantOrVisitor<C>(core::marker::PhantomData<C>);
+
+#[cfg(feature = "serde")]
+impl<'de, C: ConstantString> serde::de::Visitor<'de> for ConstantOrVisitor<C> {
+    type Value = ConstantOr<C>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(if crate::parse::matches_constant::<C>(v) {
+            ConstantOr::Constant(C::default())
+        } else {
+            ConstantOr::Other(String::from(v))
+        })
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(v.into())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_byte_buf<E>(self, v: alloc::vec::Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match String::from_utf8(v) {
+            Ok(v) => self.visit_string(v),
+            Err(error) => Err(E::invalid_value(
+                serde::de::Unexpected::Bytes(error.as_bytes()),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        if C::OPTIONS.null {
+            Ok(ConstantOr::Constant(C::default()))
+        } else {
+            Err(E::invalid_type(serde::de::Unexpected::Option, &self))
+        }
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        if C::OPTIONS.compact {
+            Ok(ConstantOr::Constant(C::default()))
+        } else {
+            Err(E::invalid_type(serde::de::Unexpected::Unit, &self))
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+impl<C: ConstantString> From<ConstantOr<C>> for serde_yaml::Value {
+    fn from(value: ConstantOr<C>) -> Self {
+        Self::String(value.as_str().to_owned())
+    }
+}
+
+#[cfg(feature = "smol_str")]
+impl<C: ConstantString> From<ConstantOr<C>> for smol_str::SmolStr {
+    fn from(value: ConstantOr<C>) -> Self {
+        match value {
+            ConstantOr::Constant(_) => Self::new_static(C::VALUE),
+            ConstantOr::Other(value) => Self::from(value),
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<C: ConstantString> From<ConstantOr<C>> for toml::Value {
+    fn from(value: ConstantOr<C>) -> Self {
+        Self::String(value.as_str().to_owned())
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<C: ConstantString> ufmt::uDisplay for ConstantOr<C> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "ustr")]
+impl<C> From<ConstantOr<C>> for ustr::Ustr
+where
+    C: ConstantString + Into<ustr::Ustr>,
+{
+    fn from(value: ConstantOr<C>) -> Self {
+        match value {
+            ConstantOr::Constant(constant) => constant.into(),
+            ConstantOr::Other(value) => ustr::ustr(&value),
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<C> utoipa::PartialSchema for ConstantOr<C>
+where
+    C: ConstantString + utoipa::PartialSchema,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        use utoipa::openapi::{
+            RefOr,
+            schema::{AnyOfBuilder, ObjectBuilder, Schema, Type},
+        };
+
+        RefOr::T(Schema::AnyOf(
+            AnyOfBuilder::new()
+                .item(C::schema())
+                .item(ObjectBuilder::new().schema_type(Type::String))
+                .build(),
+        ))
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<C> utoipa::ToSchema for ConstantOr<C>
+where
+    C: ConstantString + utoipa::ToSchema,
+{
+    fn name() -> std::borrow::Cow<'static, str> {
+        format!("{}OrOther", C::name()).into()
+    }
+}
+
+#[cfg(feature = "validator")]
+impl<C> validator::Validate for ConstantOr<C>
+where
+    C: ConstantString + validator::Validate,
+{
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            Self::Constant(constant) => constant.validate(),
+            Self::Other(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "validator")]
+impl<C: ConstantString> validator::ValidateLength<u64> for ConstantOr<C> {
+    fn length(&self) -> Option<u64> {
+        validator::ValidateLength::length(self.as_str())
+    }
+}
+
+#[cfg(feature = "validator")]
+impl<C: ConstantString> validator::ValidateRegex for ConstantOr<C> {
+    fn validate_regex(&self, regex: impl validator::AsRegex) -> bool {
+        validator::ValidateRegex::validate_regex(&self.as_str(), regex)
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<C: ConstantString> valuable::Valuable for ConstantOr<C> {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::String(self.as_str())
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        visit.visit_value(self.as_value());
+    }
+}
+
+#[cfg(feature = "yew")]
+impl<C: ConstantString> yew::html::IntoPropValue<yew::virtual_dom::AttrValue> for ConstantOr<C> {
+    fn into_prop_value(self) -> yew::virtual_dom::AttrValue {
+        match self {
+            Self::Constant(_) => yew::virtual_dom::AttrValue::Static(C::VALUE),
+            Self::Other(value) => value.into(),
+        }
+    }
+}
+
+#[cfg(feature = "yew")]
+impl<C: ConstantString> yew::html::IntoPropValue<Option<yew::virtual_dom::AttrValue>>
+    for ConstantOr<C>
+{
+    fn into_prop_value(self) -> Option<yew::virtual_dom::AttrValue> {
+        Some(self.into_prop_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstantOr;
+    use crate::constant_string;
+
+    constant_string!(Full, FULL, "full", aliases = ["complete"]);
+
+    #[test]
+    fn constant_or() {
+        assert_eq!(ConstantOr::Constant(Full), ConstantOr::<Full>::default());
+        assert_eq!(
+            ConstantOr::Constant(Full),
+            ConstantOr::<Full>::from("full".to_owned())
+        );
+        assert_eq!(
+            ConstantOr::Other("summary".to_owned()),
+            ConstantOr::<Full>::from("summary".to_owned())
+        );
+        assert_eq!(
+            ConstantOr::Constant(Full),
+            ConstantOr::<Full>::from("complete".to_owned())
+        );
+        assert_eq!(ConstantOr::<Full>::Constant(Full).as_str(), "full");
+        assert_eq!(
+            ConstantOr::<Full>::Other("summary".to_owned()).to_string(),
+            "summary"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        for (value, json) in [
+            (ConstantOr::Constant(Full), "\"full\""),
+            (ConstantOr::Other("summary".to_owned()), "\"summary\""),
+        ] {
+            assert_eq!(
+                json,
+                serde_json::to_string(&value).expect("serializable value")
+            );
+            assert_eq!(
+                value,
+                serde_json::from_str(json).expect("deserializable value")
+            );
+        }
+        assert_eq!(
+            ConstantOr::Constant(Full),
+            serde_json::from_str("\"complete\"").expect("deserializable value")
+        );
+        assert!(serde_json::from_str::<ConstantOr<Full>>("1").is_err());
+        assert!(serde_json::from_str::<ConstantOr<Full>>("null").is_err());
+
+        constant_string!(Lenient, LENIENT, "lenient", lenient);
+        constant_string!(Nullable, NULLABLE, "nullable", null);
+
+        assert_eq!(
+            ConstantOr::Other("other".to_owned()),
+            serde_json::from_str::<ConstantOr<Lenient>>("\"other\"").expect("deserializable value")
+        );
+        assert_eq!(
+            ConstantOr::Constant(Nullable),
+            serde_json::from_str("null").expect("deserializable value")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_bytes() {
+        use serde::de::{
+            Deserializer, Visitor,
+            value::{BorrowedBytesDeserializer, BytesDeserializer, Error},
+        };
+
+        use super::ConstantOrVisitor;
+
+        assert_eq!(
+            ConstantOr::Constant(Full),
+            BytesDeserializer::<Error>::new(b"complete")
+                .deserialize_any(ConstantOrVisitor(core::marker::PhantomData))
+                .expect("deserializable value")
+        );
+        assert_eq!(
+            ConstantOr::<Full>::Other("summary".to_owned()),
+            BorrowedBytesDeserializer::<Error>::new(b"summary")
+                .deserialize_any(ConstantOrVisitor(core::marker::PhantomData))
+                .expect("deserializable value")
+        );
+        assert_eq!(
+            ConstantOr::<Full>::Other("summary".to_owned()),
+            ConstantOrVisitor(core::marker::PhantomData)
+                .visit_byte_buf::<Error>(b"summary".to_vec())
+                .expect("deserializable value")
+        );
+        for result in [
+            BytesDeserializer::<Error>::new(b"\xff")
+                .deserialize_any(ConstantOrVisitor::<Full>(core::marker::PhantomData)),
+            ConstantOrVisitor(core::marker::PhantomData).visit_byte_buf::<Error>(vec![0xff]),
+        ] {
+            assert_eq!(
+                result.map_err(|error| error.to_string()),
+                Err("invalid value: byte array, expected a string".to_owned())
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn binary() {
+        use serde::{Deserialize, de::value::UnitDeserializer};
+
+        constant_string!(Compact, COMPACT, "compact", compact);
+        constant_string!(Nullable, NULLABLE, "nullable", null);
+
+        for value in [
+            ConstantOr::Constant(Compact),
+            ConstantOr::Other("other".to_owned()),
+        ] {
+            let bytes = postcard::to_allocvec(&value).expect("serializable value");
+            assert_eq!(
+                postcard::from_bytes::<ConstantOr<Compact>>(&bytes).ok(),
+                Some(value)
+            );
+        }
+
+        let bytes =
+            postcard::to_allocvec(&ConstantOr::Constant(Nullable)).expect("serializable value");
+        assert_eq!(
+            postcard::from_bytes::<ConstantOr<Nullable>>(&bytes).ok(),
+            Some(ConstantOr::Constant(Nullable))
+        );
+
+        assert_eq!(
+            ConstantOr::<Compact>::deserialize(UnitDeserializer::<serde::de::value::Error>::new())
+                .ok(),
+            Some(ConstantOr::Constant(Compact))
+        );
+        assert!(
+            ConstantOr::<Full>::deserialize(UnitDeserializer::<serde::de::value::Error>::new())
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        use crate::MaybeConstant;
+
+        #[derive(Arbitrary, Debug)]
+        struct Request {
+            maybe_format: MaybeConstant<Full>,
+            other_format: ConstantOr<Full>,
+        }
+
+        let mut constant = 0;
+        let mut other = 0;
+        for seed in 0..=u8::MAX {
+            let bytes = [seed, 3, b'a', b'b', b'c', 0];
+            let request =
+                Request::arbitrary(&mut Unstructured::new(&bytes)).expect("arbitrary value");
+
+            assert_eq!(*request.maybe_format, Full);
+            match request.other_format {
+                ConstantOr::Constant(_) => constant += 1,
+                ConstantOr::Other(value) => {
+                    assert_ne!(value, "full");
+                    other += 1;
+                }
+            }
+        }
+        assert!(constant > 0 && other > 0);
+    }
+
+    #[cfg(feature = "arcstr")]
+    #[test]
+    fn arcstr() {
+        use arcstr::ArcStr;
+
+        use crate::MaybeConstant;
+
+        assert!(ArcStr::is_static(&ArcStr::from(MaybeConstant(Full))));
+        assert!(ArcStr::is_static(&ArcStr::from(
+            ConstantOr::<Full>::default()
+        )));
+        assert_eq!(
+            ArcStr::from(ConstantOr::<Full>::Other("other".to_owned())),
+            "other"
+        );
+    }
+
+    #[cfg(feature = "askama")]
+    #[test]
+    fn askama() {
+        use askama::Template;
+
+        use crate::MaybeConstant;
+
+        #[derive(Template)]
+        #[template(source = r#"<p title="{{ maybe }}">{{ other }}</p>"#, ext = "html")]
+        struct Page {
+            maybe: MaybeConstant<Full>,
+            other: ConstantOr<Full>,
+        }
+
+        let page = Page {
+            maybe: MaybeConstant(Full),
+            other: ConstantOr::Other("<other>".to_owned()),
+        };
+        assert_eq!(
+            page.render().expect("renderable template"),
+            r#"<p title="full">&#60;other&#62;</p>"#
+        );
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn compact_str() {
+        use compact_str::CompactString;
+
+        use crate::MaybeConstant;
+
+        assert_eq!(CompactString::from(MaybeConstant(Full)), "full");
+        assert_eq!(
+            CompactString::from(ConstantOr::<Full>::Other("other".to_owned())),
+            "other"
+        );
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn defmt() {
+        use defmt::{
+            Format,
+            export::{fetch_bytes, fetch_string_index, make_formatter},
+        };
+
+        use crate::MaybeConstant;
+
+        let index = fetch_string_index();
+        MaybeConstant(Full).format(make_formatter());
+        ConstantOr::<Full>::Constant(Full).format(make_formatter());
+
+        // Each write logs the indices of its format string and the interned constant, but not the constant itself.
+        let mut indices = fetch_bytes()
+            .chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        assert_eq!(indices, (index..index + 4).collect::<Vec<_>>());
+
+        ConstantOr::<Full>::Other("summary".to_owned()).format(make_formatter());
+        assert!(fetch_bytes().ends_with(b"summary"));
+    }
+
+    #[cfg(feature = "dioxus")]
+    #[test]
+    fn dioxus() {
+        use dioxus::prelude::*;
+
+        use crate::MaybeConstant;
+
+        let other = ConstantOr::<Full>::Other("other".to_owned());
+        let html = dioxus_ssr::render_element(rsx! {
+            p {
+                "data-constant": MaybeConstant(Full),
+                aria_label: other,
+                {ConstantOr::<Full>::default()}
+            }
+        });
+        assert_eq!(
+            html,
+            r#"<p data-constant="full" aria-label="other">full</p>"#
+        );
+    }
+
+    #[cfg(feature = "leptos")]
+    #[test]
+    fn leptos() {
+        use leptos::prelude::*;
+
+        use crate::MaybeConstant;
+
+        let other = ConstantOr::<Full>::Other("other".to_owned());
+        let html = view! {
+            <p data-constant=MaybeConstant(Full) aria-label=other>
+                {ConstantOr::<Full>::default()}
+            </p>
+        }
+        .to_html();
+        assert_eq!(
+            html,
+            r#"<p data-constant="full" aria-label="other">full</p>"#
+        );
+    }
+
+    #[cfg(feature = "yew")]
+    #[test]
+    fn yew() {
+        use yew::{AttrValue, Html, Properties, html, props};
+
+        use crate::MaybeConstant;
+
+        #[derive(PartialEq, Properties)]
+        struct Props {
+            test_id: Option<AttrValue>,
+            label: AttrValue,
+        }
+
+        let props = props!(Props {
+            test_id: MaybeConstant(Full),
+            label: ConstantOr::<Full>::Other("other".to_owned()),
+        });
+        assert_eq!(props.test_id.as_deref(), Some("full"));
+        assert_eq!(props.label, "other");
+
+        assert_eq!(Html::from(MaybeConstant(Full)), html! { "full" });
+    }
+
+    #[cfg(feature = "implicit-clone")]
+    #[test]
+    fn implicit_clone() {
+        use implicit_clone::unsync::IString;
+
+        use crate::MaybeConstant;
+
+        assert_eq!(IString::from(MaybeConstant(Full)), IString::Static("full"));
+        assert_eq!(
+            IString::from(ConstantOr::<Full>::default()),
+            IString::Static("full")
+        );
+        assert_eq!(
+            IString::from(ConstantOr::<Full>::Other("other".to_owned())),
+            "other"
+        );
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn maud() {
+        use maud::html;
+
+        use crate::MaybeConstant;
+
+        let other = ConstantOr::<Full>::Other("<other>".to_owned());
+        let markup = html! {
+            p title=(MaybeConstant(Full)) { (other) }
+        };
+        assert_eq!(markup.into_string(), r#"<p title="full">&lt;other&gt;</p>"#);
+    }
+
+    #[cfg(feature = "opentelemetry")]
+    #[test]
+    fn opentelemetry() {
+        use opentelemetry::{KeyValue, StringValue, Value};
+
+        use crate::MaybeConstant;
+
+        assert_eq!(
+            KeyValue::new("code", MaybeConstant(Full)).value,
+            Value::from("full")
+        );
+        assert_eq!(
+            StringValue::from(ConstantOr::<Full>::Other("other".to_owned())).as_str(),
+            "other"
+        );
+        assert_eq!(
+            Value::from(ConstantOr::<Full>::default()),
+            Value::from("full")
+        );
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    #[test]
+    fn serde_yaml() {
+        use serde_yaml::Value;
+
+        use crate::MaybeConstant;
+
+        assert_eq!(Value::from(MaybeConstant(Full)), Value::from("full"));
+        assert_eq!(
+            Value::from(ConstantOr::<Full>::Other("other".to_owned())),
+            Value::from("other")
+        );
+    }
+
+    #[cfg(feature = "smol_str")]
+    #[test]
+    fn smol_str() {
+        use smol_str::SmolStr;
+
+        use crate::MaybeConstant;
+
+        assert_eq!(SmolStr::from(MaybeConstant(Full)), "full");
+        assert_eq!(SmolStr::from(ConstantOr::<Full>::default()), "full");
+        assert_eq!(
+            SmolStr::from(ConstantOr::<Full>::Other("other".to_owned())),
+            "other"
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml() {
+        use toml::Value;
+
+        use crate::MaybeConstant;
+
+        assert_eq!(Value::from(MaybeConstant(Full)), Value::from("full"));
+        assert_eq!(
+            Value::from(ConstantOr::<Full>::default()),
+            Value::from("full")
+        );
+    }
+
+    #[cfg(feature = "valuable")]
+    #[test]
+    fn valuable() {
+        use valuable::{NamedValues, Valuable, Value, Visit};
+
+        use crate::MaybeConstant;
+
+        #[derive(Valuable)]
+        struct Request {
+            maybe_format: MaybeConstant<Full>,
+            other_format: ConstantOr<Full>,
+        }
+
+        #[derive(Default)]
+        struct Fields(Vec<String>);
+
+        impl Visit for Fields {
+            fn visit_value(&mut self, value: Value<'_>) {
+                if let Value::Structable(structable) = value {
+                    structable.visit(self);
+                }
+            }
+
+            fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+                for (field, value) in named_values {
+                    if let Value::String(value) = value {
+                        self.0.push(format!("{}={value}", field.name()));
+                    }
+                }
+            }
+        }
+
+        let request = Request {
+            maybe_format: MaybeConstant(Full),
+            other_format: ConstantOr::Other("summary".to_owned()),
+        };
+        let mut fields = Fields::default();
+        valuable::visit(&request, &mut fields);
+
+        assert_eq!(fields.0, ["maybe_format=full", "other_format=summary"]);
+    }
+
+    #[cfg(feature = "validator")]
+    #[test]
+    fn validator() {
+        use std::sync::LazyLock;
+
+        use regex::Regex;
+        use validator::Validate;
+
+        static LOWERCASE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("^[a-z]+$").expect("valid regular expression"));
+
+        #[derive(Validate)]
+        struct Request {
+            #[validate(nested, length(max = 8), regex(path = *LOWERCASE))]
+            other_format: ConstantOr<Full>,
+        }
+
+        for other_format in [
+            ConstantOr::Constant(Full),
+            ConstantOr::Other("summary".to_owned()),
+        ] {
+            assert!(Request { other_format }.validate().is_ok());
+        }
+        for other_format in ["Summary", "paragraphs"] {
+            assert!(
+                Request {
+                    other_format: ConstantOr::Other(other_format.to_owned()),
+                }
+                .validate()
+                .is_err()
+            );
+        }
+    }
+
+    #[cfg(feature = "garde")]
+    #[test]
+    fn garde() {
+        use garde::Validate;
+
+        #[derive(Validate)]
+        struct Request {
+            #[garde(dive, ascii, length(max = 8))]
+            other_format: ConstantOr<Full>,
+        }
+
+        for other_format in [
+            ConstantOr::Constant(Full),
+            ConstantOr::Other("summary".to_owned()),
+        ] {
+            assert!(Request { other_format }.validate().is_ok());
+        }
+        for other_format in ["résumé", "paragraphs"] {
+            assert!(
+                Request {
+                    other_format: ConstantOr::Other(other_format.to_owned()),
+                }
+                .validate()
+                .is_err()
+            );
+        }
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{
+            PartialSchema, ToSchema,
+            openapi::{
+                RefOr, Type,
+                schema::{AnyOf, Object, Schema},
+            },
+        };
+
+        assert_eq!(ConstantOr::<Full>::name(), "FullOrOther");
+        assert_eq!(
+            RefOr::T(Schema::AnyOf(
+                AnyOf::builder()
+                    .item(
+                        Object::builder()
+                            .schema_type(Type::String)
+                            .default(Some("full".into()))
+                            .examples(["full"])
+                            .enum_values(Some(["full"]))
+                    )
+                    .item(Object::builder().schema_type(Type::String))
+                    .build()
+            )),
+            ConstantOr::<Full>::schema()
+        );
+    }
+}
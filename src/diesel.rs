@@ -0,0 +1,78 @@
+//! Diesel integration.
+//!
+//! Constant strings map to [`Text`] columns of any backend: the constant is written on serialization and the stored
+//! value is validated against its [`Options`](crate::Options) on deserialization.
+
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    sql_types::Text,
+};
+
+use crate::ConstantString;
+
+/// Deserialize the constant string `C` from a [`Text`] value, failing with a [`ParseError`](crate::ParseError) on a
+/// mismatch.
+///
+/// Used by the [`FromSql`] implementation of [`constant_string`](crate::constant_string).
+pub fn from_sql<C, DB>(bytes: DB::RawValue<'_>) -> deserialize::Result<C>
+where
+    C: ConstantString,
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    let value = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+
+    Ok(crate::parse(&value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use diesel::{
+        Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection, dsl::sql, select,
+        sql_types::Text,
+    };
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound");
+
+    diesel::table! {
+        errors (id) {
+            id -> Integer,
+            code -> Text,
+        }
+    }
+
+    #[test]
+    fn sqlite() {
+        let mut connection = SqliteConnection::establish(":memory:").expect("in-memory database");
+
+        diesel::sql_query("CREATE TABLE errors (id INTEGER PRIMARY KEY, code TEXT NOT NULL)")
+            .execute(&mut connection)
+            .expect("created table");
+        diesel::insert_into(errors::table)
+            .values((errors::id.eq(1), errors::code.eq(NotFound)))
+            .execute(&mut connection)
+            .expect("inserted row");
+
+        let code: NotFound = errors::table
+            .select(errors::code)
+            .filter(errors::code.eq(&NotFound))
+            .first(&mut connection)
+            .expect("decodable row");
+        assert_eq!(code, NotFound);
+
+        let error = select(sql::<Text>("'missing'"))
+            .get_result::<NotFound>(&mut connection)
+            .expect_err("mismatched value");
+        assert_eq!(
+            error.source().map(ToString::to_string),
+            Some(
+                r#"invalid value "missing", expected constant "notFound" for NotFound"#.to_owned()
+            )
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::ConstantString;
+
+/// Constant string used as the code of a [`ConstantError`], with a fixed HTTP status and default message.
+///
+/// Implemented by [`constant_error`](crate::constant_error).
+pub trait ConstantErrorCode: ConstantString {
+    /// Name of the error type.
+    const ERROR_NAME: &'static str;
+
+    /// HTTP status code of the error.
+    const STATUS: u16;
+
+    /// Default message of the error.
+    const MESSAGE: &'static str;
+}
+
+/// Error with the constant string code `C` and a message, defaulting to the message of the code.
+///
+/// Defined for a code by [`constant_error`](crate::constant_error). Serialized as an object with `code` and
+/// `message` properties.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ConstantError<C> {
+    /// Code of the error.
+    pub code: C,
+
+    /// Human-readable message of the error.
+    pub message: Cow<'static, str>,
+}
+
+impl<C: ConstantErrorCode> ConstantError<C> {
+    /// Error with a message replacing the default message of the code.
+    pub fn with_message(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            code: C::default(),
+            message: message.into(),
+        }
+    }
+
+    /// HTTP status code of the error.
+    pub fn status(&self) -> u16 {
+        C::STATUS
+    }
+}
+
+impl<C: ConstantErrorCode> Clone for ConstantError<C> {
+    fn clone(&self) -> Self {
+        Self {
+            code: C::default(),
+            message: self.message.clone(),
+        }
+    }
+}
+
+impl<C: ConstantErrorCode> Default for ConstantError<C> {
+    fn default() -> Self {
+        Self {
+            code: C::default(),
+            message: Cow::Borrowed(C::MESSAGE),
+        }
+    }
+}
+
+impl<C: ConstantErrorCode> fmt::Display for ConstantError<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl<C: ConstantErrorCode + fmt::Debug> core::error::Error for ConstantError<C> {}
+
+#[cfg(feature = "utoipa")]
+impl<C> utoipa::PartialSchema for ConstantError<C>
+where
+    C: ConstantErrorCode + utoipa::PartialSchema,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::utoipa::error_schema::<C>()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<C> utoipa::ToSchema for ConstantError<C>
+where
+    C: ConstantErrorCode + utoipa::PartialSchema,
+{
+    fn name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(C::ERROR_NAME)
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<C> utoipa::IntoResponses for ConstantError<C>
+where
+    C: ConstantErrorCode + utoipa::PartialSchema,
+{
+    fn responses() -> std::collections::BTreeMap<
+        String,
+        utoipa::openapi::RefOr<utoipa::openapi::response::Response>,
+    > {
+        [(
+            C::STATUS.to_string(),
+            crate::utoipa::response::<Self>(C::MESSAGE).into(),
+        )]
+        .into()
+    }
+}
+
+#[cfg(feature = "actix-web")]
+impl<C> actix_web::ResponseError for ConstantError<C>
+where
+    C: ConstantErrorCode + fmt::Debug + serde::Serialize,
+{
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(C::STATUS)
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        crate::actix_web::json_response(C::STATUS, "application/json", self)
+    }
+}
+
+#[cfg(feature = "actix-web")]
+impl<C> actix_web::Responder for ConstantError<C>
+where
+    C: ConstantErrorCode + serde::Serialize,
+{
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _: &actix_web::HttpRequest) -> actix_web::HttpResponse {
+        crate::actix_web::json_response(C::STATUS, "application/json", &self)
+    }
+}
+
+/// Define an error type with a constant string code, a fixed HTTP status and a default message.
+///
+/// Takes the name of the error type, the arguments of [`constant_string`](crate::constant_string) for the code, and
+/// the `status` and `message`, followed by options of the code. The error type is a [`ConstantError`] of the code,
+/// which implements [`ConstantErrorCode`].
+///
+/// # Example
+#[cfg_attr(all(feature = "serde", feature = "utoipa"), doc = "```")]
+#[cfg_attr(not(all(feature = "serde", feature = "utoipa")), doc = "```ignore")]
+/// # extern crate utoipa;
+/// #
+/// use constant_string::constant_error;
+/// use utoipa::OpenApi;
+///
+/// constant_error!(
+///     /// Entity not found.
+///     NotFoundError,
+///     NotFoundErrorCode,
+///     NOT_FOUND_ERROR_CODE,
+///     "notFound",
+///     status = 404,
+///     message = "Entity not found",
+/// );
+///
+/// let error = NotFoundError::default();
+/// assert_eq!(error.status(), 404);
+/// assert_eq!(
+///     serde_json::to_string(&error).expect("serializable value"),
+///     r#"{"code":"notFound","message":"Entity not found"}"#
+/// );
+///
+/// #[utoipa::path(get, path = "/entity", responses(NotFoundError))]
+/// fn entity() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(paths(entity), components(schemas(NotFoundError)))]
+/// struct ApiDoc;
+/// ```
+#[macro_export]
+macro_rules! constant_error {
+    (
+        $(#[$meta:meta])* $name:ident,
+        $code:ident,
+        $code_name:ident,
+        $value:literal,
+        status = $status:literal,
+        message = $message:literal
+        $(, $($options:tt)*)?
+    ) => {
+        $crate::constant_string!($code, $code_name, $value $(, $($options)*)?);
+
+        #[allow(deprecated)]
+        impl $crate::ConstantErrorCode for $code {
+            const ERROR_NAME: &'static str = ::core::stringify!($name);
+
+            const STATUS: u16 = $status;
+
+            const MESSAGE: &'static str = $message;
+        }
+
+        $(#[$meta])*
+        pub type $name = $crate::ConstantError<$code>;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::Cow;
+
+    use crate::ConstantError;
+
+    constant_error!(
+        NotFoundError,
+        NotFoundErrorCode,
+        NOT_FOUND_ERROR_CODE,
+        "notFound",
+        status = 404,
+        message = "Entity not found",
+        aliases = ["not_found"],
+    );
+
+    #[test]
+    fn constant_error() {
+        let error = NotFoundError::default();
+        assert_eq!(error.status(), 404);
+        assert_eq!(error.to_string(), "Entity not found");
+        assert_eq!(
+            NotFoundError::with_message("Entity 42 not found"),
+            ConstantError {
+                code: NotFoundErrorCode,
+                message: Cow::Borrowed("Entity 42 not found"),
+            }
+        );
+        assert_eq!(error.clone(), error);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        assert_eq!(
+            to_string(&NotFoundError::default()).ok().as_deref(),
+            Some(r#"{"code":"notFound","message":"Entity not found"}"#)
+        );
+        assert_eq!(
+            from_str::<NotFoundError>(r#"{"code":"not_found","message":"Missing"}"#).ok(),
+            Some(NotFoundError::with_message("Missing"))
+        );
+        assert!(from_str::<NotFoundError>(r#"{"code":"conflict","message":"Missing"}"#).is_err());
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+        assert_eq!(NotFoundError::name(), "NotFoundError");
+        assert_eq!(
+            serde_json::to_value(NotFoundError::schema()).ok(),
+            Some(serde_json::json!({
+                "type": "object",
+                "required": ["code", "message"],
+                "properties": {
+                    "code": {
+                        "type": "string",
+                        "enum": ["notFound"],
+                        "default": "notFound",
+                        "examples": ["notFound"],
+                    },
+                    "message": {
+                        "type": "string",
+                        "examples": ["Entity not found"],
+                    },
+                },
+            }))
+        );
+        assert_eq!(
+            NotFoundError::responses().into_keys().collect::<Vec<_>>(),
+            ["404"]
+        );
+    }
+}
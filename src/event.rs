@@ -0,0 +1,191 @@
+use crate::ConstantString;
+
+/// Event envelope with the constant string `T` as the `type` tag and a payload `P` as the `data`.
+///
+/// Serialized as an object with `type` and `data` properties, for webhooks and event sourcing. Deserialization fails
+/// unless the `type` matches `T`, so an untagged enum of events is discriminated by the constants. See
+/// [`constant_event_one_of`](crate::constant_event_one_of) for the `oneOf` schema of a set of event types.
+///
+/// # Example
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// # extern crate serde;
+/// #
+/// use constant_string::{Event, constant_string};
+/// use serde::Serialize;
+///
+/// constant_string!(UserCreatedEventType, USER_CREATED_EVENT_TYPE, "user.created");
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: u32,
+/// }
+///
+/// type UserCreatedEvent = Event<UserCreatedEventType, User>;
+///
+/// assert_eq!(
+///     serde_json::to_string(&UserCreatedEvent::new(User { id: 42 })).expect("serializable value"),
+///     r#"{"type":"user.created","data":{"id":42}}"#
+/// );
+/// ```
+#[derive(Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Event<T, P> {
+    /// Type of the event.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub r#type: T,
+
+    /// Payload of the event.
+    pub data: P,
+}
+
+impl<T: ConstantString, P> Event<T, P> {
+    /// Event with the payload `data`.
+    pub fn new(data: P) -> Self {
+        Self {
+            r#type: T::default(),
+            data,
+        }
+    }
+
+    /// Value of the type of the event.
+    pub fn type_str(&self) -> &'static str {
+        T::VALUE
+    }
+
+    /// Take the payload of the event.
+    pub fn into_data(self) -> P {
+        self.data
+    }
+}
+
+impl<T: ConstantString, P: Clone> Clone for Event<T, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.data.clone())
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<T, P> utoipa::PartialSchema for Event<T, P>
+where
+    T: ConstantString + utoipa::PartialSchema,
+    P: utoipa::PartialSchema,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::utoipa::event_schema::<T, P>()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<T, P> utoipa::ToSchema for Event<T, P>
+where
+    T: ConstantString + utoipa::ToSchema,
+    P: utoipa::ToSchema,
+{
+    fn name() -> std::borrow::Cow<'static, str> {
+        format!("Event_{}", T::name()).into()
+    }
+
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        P::schemas(schemas);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Event;
+    use crate::constant_string;
+
+    constant_string!(
+        UserCreatedEventType,
+        USER_CREATED_EVENT_TYPE,
+        "user.created"
+    );
+    constant_string!(
+        UserDeletedEventType,
+        USER_DELETED_EVENT_TYPE,
+        "user.deleted"
+    );
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+    #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+    struct User {
+        id: u32,
+    }
+
+    type UserCreatedEvent = Event<UserCreatedEventType, User>;
+    type UserDeletedEvent = Event<UserDeletedEventType, User>;
+
+    #[test]
+    fn event() {
+        let event = UserCreatedEvent::new(User { id: 42 });
+        assert_eq!(event.type_str(), "user.created");
+        assert_eq!(event.data.id, 42);
+        assert_eq!(event.clone(), event);
+        assert_eq!(event.into_data(), User { id: 42 });
+        assert_eq!(
+            UserDeletedEvent::new(User { id: 42 }).type_str(),
+            "user.deleted"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        #[serde(untagged)]
+        enum UserEvent {
+            Created(UserCreatedEvent),
+            Deleted(UserDeletedEvent),
+        }
+
+        assert_eq!(
+            to_string(&UserDeletedEvent::new(User { id: 42 }))
+                .ok()
+                .as_deref(),
+            Some(r#"{"type":"user.deleted","data":{"id":42}}"#)
+        );
+        assert_eq!(
+            from_str::<UserEvent>(r#"{"type":"user.deleted","data":{"id":42}}"#).ok(),
+            Some(UserEvent::Deleted(Event::new(User { id: 42 })))
+        );
+        assert!(from_str::<UserEvent>(r#"{"type":"user.updated","data":{"id":42}}"#).is_err());
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(UserCreatedEvent::name(), "Event_UserCreatedEventType");
+        assert_eq!(
+            serde_json::to_value(UserCreatedEvent::schema())
+                .ok()
+                .and_then(|schema| schema.get("required").cloned()),
+            Some(serde_json::json!(["type", "data"]))
+        );
+        assert_eq!(
+            serde_json::to_value(crate::constant_event_one_of!(
+                UserCreatedEventType => User,
+                UserDeletedEventType => User,
+            ))
+            .ok()
+            .and_then(|schema| schema.get("discriminator").cloned()),
+            Some(serde_json::json!({
+                "propertyName": "type",
+                "mapping": {
+                    "user.created": "#/components/schemas/Event_UserCreatedEventType",
+                    "user.deleted": "#/components/schemas/Event_UserDeletedEventType",
+                },
+            }))
+        );
+    }
+}
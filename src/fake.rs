@@ -0,0 +1,125 @@
+//! Fake integration.
+//!
+//! Constant strings implement [`Dummy<Faker>`](fake::Dummy), always generating the constant. Use [`Weighted`] and
+//! [`constant_weighted`](crate::constant_weighted) for fields accepting a group of constants.
+
+use fake::{Dummy, RngExt};
+
+/// Faker choosing one of a group of constant values, in proportion to their weights.
+///
+/// Generates [`String`] and `&'static str` values. Construct with [`constant_weighted`](crate::constant_weighted).
+#[derive(Clone, Copy, Debug)]
+pub struct Weighted(pub &'static [(&'static str, u32)]);
+
+impl Weighted {
+    /// Choose a value in proportion to its weight.
+    ///
+    /// # Panics
+    /// Panics if there are no values or all weights are zero.
+    pub fn choose<R: RngExt + ?Sized>(&self, rng: &mut R) -> &'static str {
+        let total = self.0.iter().map(|(_, weight)| weight).sum::<u32>();
+        let mut index = rng.random_range(0..total);
+
+        self.0
+            .iter()
+            .find(|(_, weight)| {
+                if index < *weight {
+                    true
+                } else {
+                    index -= weight;
+                    false
+                }
+            })
+            .map(|(value, _)| *value)
+            .expect("value within total weight")
+    }
+}
+
+impl Dummy<Weighted> for &'static str {
+    fn dummy_with_rng<R: RngExt + ?Sized>(config: &Weighted, rng: &mut R) -> Self {
+        config.choose(rng)
+    }
+}
+
+impl Dummy<Weighted> for String {
+    fn dummy_with_rng<R: RngExt + ?Sized>(config: &Weighted, rng: &mut R) -> Self {
+        config.choose(rng).to_owned()
+    }
+}
+
+/// Define a [`Weighted`] faker choosing the value of one of a group of constant string types.
+///
+/// Each type is followed by an optional `=> weight`, defaulting to 1.
+///
+/// # Example
+/// ```
+/// # extern crate fake;
+/// #
+/// use constant_string::{constant_string, constant_weighted};
+/// use fake::{Dummy, Fake, Faker};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// #[derive(Debug, Dummy)]
+/// struct Error {
+///     #[dummy(faker = "constant_weighted!(NotFoundErrorCode => 3, ConflictErrorCode)")]
+///     code: String,
+///     not_found: NotFoundErrorCode,
+/// }
+///
+/// let error: Error = Faker.fake();
+/// assert!(["notFound", "conflict"].contains(&error.code.as_str()));
+/// ```
+#[macro_export]
+macro_rules! constant_weighted {
+    ($($constant:ty $(=> $weight:expr)?),+ $(,)?) => {
+        $crate::fake::Weighted(&[$((
+            <$constant as $crate::ConstantString>::VALUE,
+            $crate::constant_weighted!(@weight $($weight)?),
+        )),+])
+    };
+    (@weight) => {
+        1
+    };
+    (@weight $weight:expr) => {
+        $weight
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, Faker};
+
+    use super::Weighted;
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Conflict, CONFLICT, "conflict");
+
+    #[test]
+    fn dummy() {
+        assert_eq!(Faker.fake::<NotFound>(), NotFound);
+        assert_eq!(Faker.fake::<MaybeConstant<NotFound>>().0, NotFound);
+
+        let values = (0..100)
+            .map(|_| Faker.fake::<ConstantOr<NotFound>>())
+            .collect::<Vec<_>>();
+        assert!(values.iter().any(ConstantOr::is_constant));
+        assert!(values.iter().any(|value| !value.is_constant()));
+    }
+
+    #[test]
+    fn weighted() {
+        let faker = constant_weighted!(NotFound => 3, Conflict);
+        assert_eq!(faker.0, [("notFound", 3), ("conflict", 1)]);
+
+        for _ in 0..10 {
+            assert!(["notFound", "conflict"].contains(&faker.fake::<&str>()));
+        }
+        assert_eq!(
+            Weighted(&[("notFound", 0), ("conflict", 1)]).fake::<String>(),
+            "conflict"
+        );
+    }
+}
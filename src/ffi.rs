@@ -0,0 +1,59 @@
+//! FFI integration.
+
+/// Implement a constant string that is also available as a C string.
+///
+/// The literal is validated to contain no interior NUL bytes at compile time. The NUL-terminated value is available as
+/// a [`CStr`](std::ffi::CStr) in the `C_STR` associated constant. All other behavior is identical to
+/// [`constant_string`](crate::constant_string).
+///
+/// # Example
+/// ```
+/// # use constant_string::constant_cstring;
+/// #
+/// constant_cstring!(LibraryName, LIBRARY_NAME, "constant-string");
+///
+/// assert_eq!(LibraryName::C_STR.to_bytes_with_nul(), b"constant-string\0");
+/// ```
+///
+/// Interior NUL bytes fail to compile:
+/// ```compile_fail
+/// # use constant_string::constant_cstring;
+/// #
+/// constant_cstring!(LibraryName, LIBRARY_NAME, "constant\0string");
+/// ```
+#[macro_export]
+macro_rules! constant_cstring {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        $crate::constant_string!($name, $code_name, $code);
+
+        impl $name {
+            #[doc = ::core::concat!("C string `", $code, "`.")]
+            pub const C_STR: &'static ::core::ffi::CStr =
+                match ::core::ffi::CStr::from_bytes_with_nul(
+                    ::core::concat!($code, "\0").as_bytes(),
+                ) {
+                    ::core::result::Result::Ok(c_str) => c_str,
+                    ::core::result::Result::Err(_) => ::core::panic!(::core::concat!(
+                        "constant string contains an interior NUL byte ",
+                        ::core::stringify!($code)
+                    )),
+                };
+        }
+
+        const _: &::core::ffi::CStr = $name::C_STR;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    constant_cstring!(LibraryName, LIBRARY_NAME, "constant-string");
+
+    #[test]
+    fn constant_cstring() {
+        assert_eq!(LibraryName.deref(), "constant-string");
+        assert_eq!(LibraryName::C_STR, c"constant-string");
+        assert_eq!(LibraryName::C_STR.to_str(), Ok("constant-string"));
+    }
+}
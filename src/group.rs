@@ -0,0 +1,115 @@
+/// Define a named group of constant string types.
+///
+/// Defines a unit struct with `ALL` holding the values of the members, `contains` matching a value or alias of a
+/// member under its [`Options`](crate::Options), and `iter` over the values, and a marker trait implemented by the
+/// members, for bounds accepting only members of the group.
+///
+/// # Example
+/// ```
+/// use constant_string::{ConstantString, constant_string, constant_string_group};
+///
+/// constant_string!(TimeoutErrorCode, TIMEOUT_ERROR_CODE, "timeout");
+/// constant_string!(UnavailableErrorCode, UNAVAILABLE_ERROR_CODE, "unavailable", aliases = ["busy"]);
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+///
+/// constant_string_group! {
+///     /// Codes of errors worth retrying.
+///     pub struct Retryable;
+///     pub trait RetryableCode;
+///     TimeoutErrorCode, UnavailableErrorCode
+/// }
+///
+/// fn retry<C: RetryableCode>(code: C) -> &'static str {
+///     C::VALUE
+/// }
+///
+/// assert_eq!(Retryable::ALL, ["timeout", "unavailable"]);
+/// assert!(Retryable::contains("busy"));
+/// assert!(!Retryable::contains("notFound"));
+/// assert_eq!(retry(TimeoutErrorCode), "timeout");
+/// ```
+#[macro_export]
+macro_rules! constant_string_group {
+    (
+        $(#[$meta:meta])* $vis:vis struct $name:ident;
+        $(#[$trait_meta:meta])* $trait_vis:vis trait $trait:ident;
+        $($constant:ty),+ $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::default::Default,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+            ::core::cmp::PartialEq,
+        )]
+        $vis struct $name;
+
+        #[allow(dead_code, deprecated)]
+        impl $name {
+            /// Values of the members.
+            pub const ALL: &'static [&'static str] =
+                &[$(<$constant as $crate::ConstantString>::VALUE),+];
+
+            /// Whether `value` matches the value or one of the aliases of a member, under its options.
+            pub fn contains(value: &str) -> bool {
+                $($crate::__private::matches_constant::<$constant>(value))||+
+            }
+
+            /// Iterate over the values of the members.
+            pub fn iter() -> impl ::core::iter::Iterator<Item = &'static str> {
+                ::core::iter::Iterator::copied(Self::ALL.iter())
+            }
+        }
+
+        $(#[$trait_meta])*
+        #[doc = ::core::concat!("Constant string in [`", ::core::stringify!($name), "`].")]
+        $trait_vis trait $trait: $crate::ConstantString {}
+
+        $(
+            #[allow(deprecated)]
+            impl $trait for $constant {}
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constant_string;
+
+    constant_string!(TimeoutErrorCode, TIMEOUT_ERROR_CODE, "timeout");
+    constant_string!(
+        UnavailableErrorCode,
+        UNAVAILABLE_ERROR_CODE,
+        "unavailable",
+        aliases = ["busy"],
+        case_insensitive,
+    );
+    constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+
+    constant_string_group! {
+        struct Retryable;
+        trait RetryableCode;
+        TimeoutErrorCode, UnavailableErrorCode,
+    }
+
+    fn value<C: RetryableCode>() -> &'static str {
+        C::VALUE
+    }
+
+    #[test]
+    fn constant_string_group() {
+        assert_eq!(Retryable::ALL, ["timeout", "unavailable"]);
+        assert!(Retryable::contains("timeout"));
+        assert!(Retryable::contains("BUSY"));
+        assert!(!Retryable::contains("Timeout"));
+        assert!(!Retryable::contains(NOT_FOUND_ERROR_CODE));
+        assert_eq!(
+            Retryable::iter().collect::<Vec<_>>(),
+            ["timeout", "unavailable"]
+        );
+        assert_eq!(value::<UnavailableErrorCode>(), "unavailable");
+    }
+}
@@ -0,0 +1,146 @@
+//! HTTP integration.
+
+/// Implement a constant HTTP header with a constant string value.
+///
+/// The header name and value are validated at compile time and available as `HEADER_NAME` and `HEADER_VALUE`
+/// associated constants. The header name can be omitted for a header value that isn't tied to one header. Converting
+/// the constant into a [`HeaderValue`](https://docs.rs/http/latest/http/header/struct.HeaderValue.html) is
+/// infallible, and `insert_into` inserts it into a [`HeaderMap`](https://docs.rs/http/latest/http/header/struct.HeaderMap.html)
+/// without an `unwrap`. All other behavior is identical to [`constant_string`](crate::constant_string), using the
+/// header value as the constant string.
+///
+/// # Example
+/// ```
+/// # extern crate http;
+/// #
+/// use constant_string::constant_header;
+/// use http::{HeaderMap, HeaderValue};
+///
+/// constant_header!(RequestSource, REQUEST_SOURCE, "x-request-source", "service-a");
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(RequestSource::HEADER_NAME, RequestSource.into());
+///
+/// assert_eq!(headers["x-request-source"], "service-a");
+/// ```
+///
+/// Without a header name:
+/// ```
+/// # extern crate http;
+/// #
+/// use constant_string::constant_header;
+/// use http::{HeaderMap, header::CACHE_CONTROL};
+///
+/// constant_header!(NoStore, NO_STORE, "no-store");
+///
+/// let mut headers = HeaderMap::new();
+/// NoStore::insert_into(&mut headers, CACHE_CONTROL);
+///
+/// assert_eq!(headers[CACHE_CONTROL], "no-store");
+/// ```
+///
+/// Invalid header names fail to compile:
+/// ```compile_fail
+/// # use constant_string::constant_header;
+/// #
+/// constant_header!(RequestSource, REQUEST_SOURCE, "X-Request-Source", "service-a");
+/// ```
+///
+/// Invalid header values fail to compile:
+/// ```compile_fail
+/// # use constant_string::constant_header;
+/// #
+/// constant_header!(RequestSource, REQUEST_SOURCE, "x-request-source", "service\na");
+/// ```
+#[macro_export]
+macro_rules! constant_header {
+    ($name:ident, $code_name:ident, $header:literal, $code:literal) => {
+        $crate::constant_header!($name, $code_name, $code);
+
+        impl $name {
+            #[doc = ::core::concat!("Header name `", $header, "`.")]
+            pub const HEADER_NAME: $crate::__private::http::HeaderName =
+                $crate::__private::http::HeaderName::from_static($header);
+        }
+
+        const _: () = ::core::mem::forget($name::HEADER_NAME);
+    };
+    ($name:ident, $code_name:ident, $code:literal) => {
+        $crate::constant_string!($name, $code_name, $code);
+
+        impl $name {
+            #[doc = ::core::concat!("Header value `", $code, "`.")]
+            pub const HEADER_VALUE: $crate::__private::http::HeaderValue =
+                $crate::__private::http::HeaderValue::from_static($code_name);
+
+            /// Insert the header value into a header map, returning the previous value of the header.
+            pub fn insert_into(
+                map: &mut $crate::__private::http::HeaderMap,
+                name: $crate::__private::http::HeaderName,
+            ) -> ::core::option::Option<$crate::__private::http::HeaderValue> {
+                map.insert(name, Self::HEADER_VALUE)
+            }
+        }
+
+        const _: () = ::core::mem::forget($name::HEADER_VALUE);
+
+        impl ::core::convert::From<$name> for $crate::__private::http::HeaderValue {
+            fn from(_: $name) -> Self {
+                $name::HEADER_VALUE
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderMap, HeaderValue, header::ETAG};
+
+    use crate::{ConstantOr, MaybeConstant};
+
+    constant_header!(
+        RequestSource,
+        REQUEST_SOURCE,
+        "x-request-source",
+        "service-a"
+    );
+    constant_header!(Etag, ETAG_VALUE, "W/v1");
+
+    #[test]
+    fn constant_header() {
+        assert_eq!(RequestSource::HEADER_NAME, "x-request-source");
+        assert_eq!(RequestSource::HEADER_VALUE, "service-a");
+        assert_eq!(HeaderValue::from(RequestSource), "service-a");
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RequestSource::HEADER_NAME, RequestSource.into());
+        assert_eq!(headers["x-request-source"], "service-a");
+    }
+
+    #[test]
+    fn header_value() {
+        assert_eq!(Etag::HEADER_VALUE, "W/v1");
+        assert_eq!(HeaderValue::from(MaybeConstant(Etag)), "W/v1");
+        assert_eq!(
+            HeaderValue::try_from(ConstantOr::<Etag>::Constant(Etag)).ok(),
+            Some(Etag::HEADER_VALUE)
+        );
+        assert_eq!(
+            HeaderValue::try_from(ConstantOr::<Etag>::Other("W/v2".to_owned())).ok(),
+            Some(HeaderValue::from_static("W/v2"))
+        );
+        assert!(HeaderValue::try_from(ConstantOr::<Etag>::Other("\n".to_owned())).is_err());
+    }
+
+    #[test]
+    fn insert_into() {
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(Etag::insert_into(&mut headers, ETAG), None);
+        assert_eq!(
+            RequestSource::insert_into(&mut headers, ETAG),
+            Some(Etag::HEADER_VALUE)
+        );
+        assert_eq!(headers[ETAG], "service-a");
+    }
+}
@@ -0,0 +1,140 @@
+//! ICU4X locale integration.
+
+use icu_locale_core::LanguageIdentifier;
+
+use crate::ConstantString;
+
+/// Message of the constant string `C` for `locale`, from its [`messages`](crate::Options::messages) option.
+///
+/// Matches the language tag exactly, then by language only, falling back to the first message and then to the
+/// constant. Used by the `message` accessor of [`constant_string`](crate::constant_string).
+///
+/// # Example
+/// ```
+/// # extern crate icu_locale_core;
+/// #
+/// use constant_string::constant_string;
+/// use icu_locale_core::langid;
+///
+/// constant_string!(
+///     NotFoundErrorCode,
+///     NOT_FOUND_ERROR_CODE,
+///     "notFound",
+///     messages = { "en": "Entity not found", "de": "Entität nicht gefunden" },
+/// );
+///
+/// assert_eq!(NotFoundErrorCode.message(&langid!("de-AT")), "Entität nicht gefunden");
+/// assert_eq!(NotFoundErrorCode.message(&langid!("fr")), "Entity not found");
+/// ```
+pub fn message<C: ConstantString>(locale: &LanguageIdentifier) -> &'static str {
+    let messages = C::OPTIONS.messages;
+
+    messages
+        .iter()
+        .find(|(tag, _)| locale.normalizing_eq(tag))
+        .or_else(|| {
+            messages.iter().find(|(tag, _)| {
+                tag.split(['-', '_'])
+                    .next()
+                    .is_some_and(|language| language.eq_ignore_ascii_case(locale.language.as_str()))
+            })
+        })
+        .or(messages.first())
+        .map_or(C::VALUE, |(_, message)| message)
+}
+
+/// Implement a constant string containing a [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag.
+///
+/// The language tag is validated at compile time and available as a
+/// [`LanguageIdentifier`](https://docs.rs/icu_locale_core/latest/icu_locale_core/struct.LanguageIdentifier.html) in the
+/// `LANGUAGE_IDENTIFIER` associated constant. All other behavior, including exact string matching for Serde, is
+/// identical to [`constant_string`](crate::constant_string).
+///
+/// # Example
+/// ```
+/// # extern crate icu_locale_core;
+/// #
+/// use constant_string::constant_language_tag;
+/// use icu_locale_core::subtags::{language, region};
+///
+/// constant_language_tag!(DefaultLocale, DEFAULT_LOCALE, "en-US");
+///
+/// assert_eq!(DefaultLocale::LANGUAGE_IDENTIFIER.language, language!("en"));
+/// assert_eq!(DefaultLocale::LANGUAGE_IDENTIFIER.region, Some(region!("US")));
+/// ```
+///
+/// Invalid language tags fail to compile:
+/// ```compile_fail
+/// # use constant_string::constant_language_tag;
+/// #
+/// constant_language_tag!(DefaultLocale, DEFAULT_LOCALE, "en_US!");
+/// ```
+#[macro_export]
+macro_rules! constant_language_tag {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        $crate::constant_string!($name, $code_name, $code);
+
+        impl $name {
+            #[doc = ::core::concat!("Language identifier `", $code, "`.")]
+            pub const LANGUAGE_IDENTIFIER: $crate::__private::icu_locale_core::LanguageIdentifier = {
+                // `langid!` refers to `Ok`, `Some` and `None` by name.
+                use ::core::{
+                    option::Option::{None, Some},
+                    result::Result::Ok,
+                };
+
+                $crate::__private::icu_locale_core::langid!($code)
+            };
+        }
+
+        const _: $crate::__private::icu_locale_core::LanguageIdentifier =
+            $name::LANGUAGE_IDENTIFIER;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use crate::constant_string;
+
+    use icu_locale_core::{
+        langid,
+        subtags::{language, region},
+    };
+
+    constant_language_tag!(DefaultLocale, DEFAULT_LOCALE, "en-US");
+    constant_string!(
+        NotFound,
+        NOT_FOUND,
+        "notFound",
+        messages = { "en-US": "Entity not found", "en-GB": "Entity not found, sorry", "de": "Entität nicht gefunden" },
+    );
+    constant_string!(Conflict, CONFLICT, "conflict");
+
+    #[test]
+    fn constant_language_tag() {
+        assert_eq!(DefaultLocale.deref(), "en-US");
+        assert_eq!(DefaultLocale::LANGUAGE_IDENTIFIER, langid!("en-US"));
+        assert_eq!(DefaultLocale::LANGUAGE_IDENTIFIER.language, language!("en"));
+        assert_eq!(
+            DefaultLocale::LANGUAGE_IDENTIFIER.region,
+            Some(region!("US"))
+        );
+    }
+
+    #[test]
+    fn message() {
+        assert_eq!(
+            NotFound.message(&langid!("en-GB")),
+            "Entity not found, sorry"
+        );
+        assert_eq!(
+            NotFound.message(&langid!("de-CH")),
+            "Entität nicht gefunden"
+        );
+        assert_eq!(NotFound.message(&langid!("en")), "Entity not found");
+        assert_eq!(NotFound.message(&langid!("fr")), "Entity not found");
+        assert_eq!(Conflict.message(&langid!("en")), "conflict");
+    }
+}
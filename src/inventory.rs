@@ -0,0 +1,71 @@
+//! Inventory integration.
+//!
+//! Every constant string defined with [`constant_string`](crate::constant_string) is registered with [`inventory`],
+//! so [`all`] iterates over the constant strings linked into the binary, e.g. to export them all with
+//! [`TypeScriptConstant::all`](crate::codegen::TypeScriptConstant::all) instead of listing them by hand.
+//!
+//! # Example
+//! ```
+//! use constant_string::{constant_string, inventory::all};
+//!
+//! constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+//!
+//! assert!(all().any(|constant| constant.name == "NotFoundErrorCode" && constant.value == "notFound"));
+//! ```
+
+use crate::{ConstantString, Options};
+
+/// Constant string registered by [`constant_string`](crate::constant_string).
+#[derive(Clone, Copy, Debug)]
+pub struct Constant {
+    /// Name of the type.
+    pub name: &'static str,
+
+    /// Value of the constant.
+    pub value: &'static str,
+
+    /// Options of the constant.
+    pub options: Options,
+}
+
+impl Constant {
+    /// Registration of the constant string `C`.
+    pub const fn of<C: ConstantString>() -> Self {
+        Self {
+            name: C::NAME,
+            value: C::VALUE,
+            options: C::OPTIONS,
+        }
+    }
+}
+
+inventory::collect!(Constant);
+
+/// Iterate over the registered constant strings, in unspecified order.
+pub fn all() -> impl Iterator<Item = &'static Constant> {
+    inventory::iter::<Constant>.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::all;
+    use crate::constant_string;
+
+    constant_string!(
+        RegisteredErrorCode,
+        REGISTERED_ERROR_CODE,
+        "registered",
+        description = "Registered."
+    );
+
+    #[test]
+    fn registered() {
+        let constants = all()
+            .filter(|constant| constant.name == "RegisteredErrorCode")
+            .collect::<Vec<_>>();
+
+        assert_eq!(constants.len(), 1);
+        assert_eq!(constants[0].value, "registered");
+        assert_eq!(constants[0].options.description, Some("Registered."));
+    }
+}
@@ -0,0 +1,85 @@
+//! Juniper integration.
+//!
+//! Constant strings are exposed as custom scalars, named by the type, that only accept the constant. The
+//! `#[graphql_scalar]` attribute refers to `juniper` by name, so it must be a direct dependency.
+
+use crate::{ConstantString, parse};
+
+/// GraphQL output of the constant string `C`.
+///
+/// Used by the [`GraphQLScalar`](juniper::GraphQLScalar) implementation of [`constant_string`](crate::constant_string).
+pub fn to_output<C: ConstantString>(_: &C) -> &'static str {
+    C::VALUE
+}
+
+/// Parse the constant string `C` from a GraphQL string input, applying its [`Options`](crate::Options).
+///
+/// Used by the [`GraphQLScalar`](juniper::GraphQLScalar) implementation of [`constant_string`](crate::constant_string).
+pub fn from_input<C: ConstantString>(input: &str) -> Result<C, Box<str>> {
+    parse(input).map_err(|error| error.to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use juniper::{
+        EmptyMutation, EmptySubscription, RootNode, Variables, execute_sync, graphql_object,
+        graphql_value,
+    };
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn code(code: NotFound) -> NotFound {
+            code
+        }
+    }
+
+    type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+
+    fn schema() -> Schema {
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[test]
+    fn schema_language() {
+        let sdl = schema().as_sdl();
+
+        assert!(sdl.contains("scalar NotFound\n"), "{sdl}");
+        assert!(sdl.contains("code(code: NotFound!): NotFound!"), "{sdl}");
+    }
+
+    #[test]
+    fn execute() {
+        let schema = schema();
+
+        assert_eq!(
+            execute_sync(
+                r#"{ code(code: "not_found") }"#,
+                None,
+                &schema,
+                &Variables::new(),
+                &()
+            )
+            .expect("valid query"),
+            (graphql_value!({ "code": "notFound" }), Vec::new())
+        );
+
+        let errors = execute_sync(
+            r#"{ code(code: "other") }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .expect_err("mismatched value");
+        assert_eq!(
+            errors.to_string(),
+            r#"Invalid value for argument "code", reason: Invalid value ""other"" for type "NotFound". At 0:13"#
+        );
+    }
+}
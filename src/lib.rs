@@ -1,9 +1,13 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(adt_const_params, unsized_const_params))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 #![warn(missing_docs)]
 
 //! Constant string with support for [Serde](https://crates.io/crates/serde) and [Utoipa](https://crates.io/crates/utoipa).
 //!
 //! # Example
-//! ```
+#![cfg_attr(all(feature = "serde", feature = "utoipa"), doc = "```")]
+#![cfg_attr(not(all(feature = "serde", feature = "utoipa")), doc = "```ignore")]
 //! # extern crate serde;
 //! # extern crate utoipa;
 //! #
@@ -22,31 +26,413 @@
 //! ```
 //!
 //! # Features
+//! - `actix-web` - Provide [`ConstantPath`](crate::actix_web::ConstantPath) and [`ConstantQuery`](crate::actix_web::ConstantQuery) extractors for [`actix-web`](https://docs.rs/actix-web/latest/actix_web/), rejecting mismatched parameters with the statuses of a [`ConstantConfig`](crate::actix_web::ConstantConfig), and implement [`Responder`](https://docs.rs/actix-web/latest/actix_web/trait.Responder.html) for [`ConstantError`] and [`ProblemDetails`] and [`ResponseError`](https://docs.rs/actix-web/latest/actix_web/trait.ResponseError.html) for [`ConstantError`].
+//! - `aide` - Implement [`JsonSchema`](https://docs.rs/schemars/0.9/schemars/trait.JsonSchema.html) trait from [`schemars` 0.9](https://docs.rs/schemars/0.9/schemars/), as used by [`aide`](https://docs.rs/aide/latest/aide/).
+//! - `alloc` - Enable [`ConstantOr`], [`CodedError`], [`ConstantError`], [`ProblemDetails`], [`TypedMap`], [`constant_tagged_enum`], [`parse`], [`ParseError`] and [`TagError`], which allocate. Enabled by `std`.
+//! - `apistos` - Implement `JsonSchema` and [`ApiComponent`](https://docs.rs/apistos/latest/apistos/trait.ApiComponent.html) traits for [`apistos`](https://docs.rs/apistos/latest/apistos/).
+//! - `arbitrary` - Implement [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) trait from [`arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/), always yielding the constant.
+//! - `arcstr` - Implement [`From`] for [`ArcStr`](https://docs.rs/arcstr/latest/arcstr/struct.ArcStr.html) from [`arcstr`](https://docs.rs/arcstr/latest/arcstr/) as a static literal, without allocating.
+//! - `askama` - Implement [`Display`](std::fmt::Display), as used by [`askama`](https://docs.rs/askama/latest/askama/) templates and filters, so constant strings can be written with `{{ code }}`.
+//! - `async-graphql` - Implement [`ScalarType`](https://docs.rs/async-graphql/latest/async_graphql/trait.ScalarType.html), [`InputType`](https://docs.rs/async-graphql/latest/async_graphql/trait.InputType.html) and [`OutputType`](https://docs.rs/async-graphql/latest/async_graphql/trait.OutputType.html) traits from [`async-graphql`](https://docs.rs/async-graphql/latest/async_graphql/) as custom scalars and enable [`constant_graphql_enum`].
+//! - `axum` - Provide [`ConstantPath`](crate::axum::ConstantPath) and [`ConstantQuery`](crate::axum::ConstantQuery) extractors for [`axum`](https://docs.rs/axum/latest/axum/), rejecting mismatched path segments with `404 Not Found` and query parameters with `422 Unprocessable Entity`.
+//! - `bincode` - Implement [`Encode`](https://docs.rs/bincode/2/bincode/enc/trait.Encode.html) and [`Decode`](https://docs.rs/bincode/2/bincode/de/trait.Decode.html) traits from [`bincode` 2](https://docs.rs/bincode/2/bincode/).
+//! - `borsh` - Implement [`BorshSerialize`](https://docs.rs/borsh/latest/borsh/ser/trait.BorshSerialize.html) and [`BorshDeserialize`](https://docs.rs/borsh/latest/borsh/de/trait.BorshDeserialize.html) traits from [`borsh`](https://docs.rs/borsh/latest/borsh/).
+//! - `bson` - Implement conversion into [`Bson`](https://docs.rs/bson/latest/bson/enum.Bson.html) from [`bson`](https://docs.rs/bson/latest/bson/), as used by [`mongodb`](https://docs.rs/mongodb/latest/mongodb/). Enables `serde`.
+//! - `clap` - Implement [`ValueParserFactory`](https://docs.rs/clap/latest/clap/builder/trait.ValueParserFactory.html) trait from [`clap`](https://docs.rs/clap/latest/clap/) and [`Clone`], which it requires, and enable [`constant_value_parser`].
+//! - `codegen` - Enable [`codegen`] of [`constant_string`] invocations from JSON, TOML and YAML catalogs, OpenAPI documents, JSON Schemas and TypeScript union types in build scripts, and export of TypeScript definitions and Zod schemas with [`constant_typescript`].
+//! - `compact_str` - Implement [`From`] for [`CompactString`](https://docs.rs/compact_str/latest/compact_str/struct.CompactString.html) from [`compact_str`](https://docs.rs/compact_str/latest/compact_str/), borrowing the constant without allocating.
+//! - `defmt` - Implement [`Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html) trait from [`defmt`](https://docs.rs/defmt/latest/defmt/), logging the interned constant.
+//! - `diesel` - Implement [`ToSql`](https://docs.rs/diesel/latest/diesel/serialize/trait.ToSql.html), [`FromSql`](https://docs.rs/diesel/latest/diesel/deserialize/trait.FromSql.html) and [`AsExpression`](https://docs.rs/diesel/latest/diesel/expression/trait.AsExpression.html) traits from [`diesel`](https://docs.rs/diesel/latest/diesel/) for `Text` columns.
+//! - `dioxus` - Implement [`IntoAttributeValue`](https://docs.rs/dioxus-core/latest/dioxus_core/trait.IntoAttributeValue.html) and [`IntoDynNode`](https://docs.rs/dioxus-core/latest/dioxus_core/trait.IntoDynNode.html) traits from [`dioxus-core`](https://docs.rs/dioxus-core/latest/dioxus_core/), as used by [`dioxus`](https://docs.rs/dioxus/latest/dioxus/), so constant strings can be used as attribute values and text in `rsx!`.
+//! - `fake` - Implement [`Dummy`](https://docs.rs/fake/latest/fake/trait.Dummy.html) trait from [`fake`](https://docs.rs/fake/latest/fake/), always generating the constant, and enable [`constant_weighted`].
+//! - `garde` - Implement [`Validate`](https://docs.rs/garde/latest/garde/trait.Validate.html) trait from [`garde`](https://docs.rs/garde/latest/garde/), so constant strings can be validated with `#[garde(dive)]`, and string rules for [`ConstantOr`].
+//! - `http` - Enable [`constant_header`] for header names and values from [`http`](https://docs.rs/http/latest/http/), and conversion of [`MaybeConstant`] and [`ConstantOr`] of them into header values.
+//! - `icu_locale_core` - Enable [`constant_language_tag`] for language identifiers from [`icu_locale_core`](https://docs.rs/icu_locale_core/latest/icu_locale_core/), and the `messages` option for localized messages with a `message(locale)` accessor.
+//! - `implicit-clone` - Implement [`From`] for [`IString`](https://docs.rs/implicit-clone/latest/implicit_clone/unsync/enum.IString.html) from [`implicit-clone`](https://docs.rs/implicit-clone/latest/implicit_clone/), as used for [`AttrValue`](https://docs.rs/yew/latest/yew/virtual_dom/type.AttrValue.html) by `yew`, borrowing the constant without allocating.
+//! - `inventory` - Register every constant string with [`inventory`](https://docs.rs/inventory/latest/inventory/), so [`inventory::all`] iterates over them, e.g. for [`TypeScriptConstant::all`](crate::codegen::TypeScriptConstant::all) with `codegen`.
+//! - `juniper` - Implement [`GraphQLScalar`](https://docs.rs/juniper/latest/juniper/derive.GraphQLScalar.html) from [`juniper`](https://docs.rs/juniper/latest/juniper/) as custom scalars named by the type. Requires `juniper` as a direct dependency, as its macros refer to it by name.
+//! - `leptos` - Implement [`IntoRender`](https://docs.rs/leptos/latest/leptos/prelude/trait.IntoRender.html), [`IntoAttributeValue`](https://docs.rs/leptos/latest/leptos/prelude/trait.IntoAttributeValue.html) and [`IntoClass`](https://docs.rs/leptos/latest/leptos/prelude/trait.IntoClass.html) traits from [`tachys`](https://docs.rs/tachys/latest/tachys/), as used by [`leptos`](https://docs.rs/leptos/latest/leptos/), so constant strings can be used as text, attribute values and class names in `view!`.
+//! - `maud` - Implement [`Render`](https://docs.rs/maud/latest/maud/trait.Render.html) trait from [`maud`](https://docs.rs/maud/latest/maud/), escaping the constant.
+//! - `metrics` - Implement [`From`] for [`SharedString`](https://docs.rs/metrics/latest/metrics/type.SharedString.html) from [`metrics`](https://docs.rs/metrics/latest/metrics/), borrowing the constant, and provide [`label`](crate::metrics::label) for static labels.
+//! - `minicbor` - Implement [`Encode`](https://docs.rs/minicbor/latest/minicbor/encode/trait.Encode.html) and [`Decode`](https://docs.rs/minicbor/latest/minicbor/decode/trait.Decode.html) traits from [`minicbor`](https://docs.rs/minicbor/latest/minicbor/).
+//! - `miniserde` - Implement [`Serialize`](https://docs.rs/miniserde/latest/miniserde/trait.Serialize.html) and [`Deserialize`](https://docs.rs/miniserde/latest/miniserde/trait.Deserialize.html) traits from [`miniserde`](https://docs.rs/miniserde/latest/miniserde/).
+//! - `musli` - Implement [`Encode`](https://docs.rs/musli/latest/musli/trait.Encode.html) and [`Decode`](https://docs.rs/musli/latest/musli/trait.Decode.html) traits from [`musli`](https://docs.rs/musli/latest/musli/).
+//! - `nanoserde` - Implement [`SerJson`](https://docs.rs/nanoserde/latest/nanoserde/trait.SerJson.html), [`DeJson`](https://docs.rs/nanoserde/latest/nanoserde/trait.DeJson.html), [`SerBin`](https://docs.rs/nanoserde/latest/nanoserde/trait.SerBin.html), [`DeBin`](https://docs.rs/nanoserde/latest/nanoserde/trait.DeBin.html), [`SerRon`](https://docs.rs/nanoserde/latest/nanoserde/trait.SerRon.html) and [`DeRon`](https://docs.rs/nanoserde/latest/nanoserde/trait.DeRon.html) traits from [`nanoserde`](https://docs.rs/nanoserde/latest/nanoserde/).
+//! - `napi` - Implement [`ToNapiValue`](https://docs.rs/napi/latest/napi/bindgen_prelude/trait.ToNapiValue.html) and [`FromNapiValue`](https://docs.rs/napi/latest/napi/bindgen_prelude/trait.FromNapiValue.html) traits from [`napi`](https://docs.rs/napi/latest/napi/) as JavaScript strings.
+//! - `nightly` - Enable [`ConstStr`], a constant string with its value as a const parameter, using the unstable `adt_const_params` feature of a nightly compiler.
+//! - `okapi` - Implement [`JsonSchema`](https://docs.rs/schemars/0.8/schemars/trait.JsonSchema.html) trait from the [`schemars` 0.8](https://docs.rs/schemars/0.8/schemars/) re-exported by [`okapi`](https://docs.rs/okapi/latest/okapi/), as used by [`rocket_okapi`](https://docs.rs/rocket_okapi/latest/rocket_okapi/).
+//! - `opentelemetry` - Implement [`From`] for [`Value`](https://docs.rs/opentelemetry/latest/opentelemetry/enum.Value.html) and [`StringValue`](https://docs.rs/opentelemetry/latest/opentelemetry/struct.StringValue.html) from [`opentelemetry`](https://docs.rs/opentelemetry/latest/opentelemetry/), borrowing the constant, so constant strings can be used as attribute values without allocating.
+//! - `poem-openapi` - Implement [`Type`](https://docs.rs/poem-openapi/latest/poem_openapi/types/trait.Type.html), `ParseFromJSON` and `ToJSON` traits from [`poem-openapi`](https://docs.rs/poem-openapi/latest/poem_openapi/). Enables `serde`.
+//! - `postcard-schema` - Implement [`Schema`](https://docs.rs/postcard-schema/latest/postcard_schema/trait.Schema.html) trait from [`postcard-schema`](https://docs.rs/postcard-schema/latest/postcard_schema/).
+//! - `postgres-types` - Implement [`ToSql`](https://docs.rs/postgres-types/latest/postgres_types/trait.ToSql.html) and [`FromSql`](https://docs.rs/postgres-types/latest/postgres_types/trait.FromSql.html) traits from [`postgres-types`](https://docs.rs/postgres-types/latest/postgres_types/) for text columns.
+//! - `proptest` - Implement [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html) trait from [`proptest`](https://docs.rs/proptest/latest/proptest/), always yielding the constant, and enable [`constant_strategy`].
+//! - `pyo3` - Implement [`IntoPyObject`](https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html) and [`FromPyObject`](https://docs.rs/pyo3/latest/pyo3/conversion/trait.FromPyObject.html) traits from [`pyo3`](https://docs.rs/pyo3/latest/pyo3/) as Python strings.
+//! - `quickcheck` - Implement [`Arbitrary`](https://docs.rs/quickcheck/latest/quickcheck/trait.Arbitrary.html) trait from [`quickcheck`](https://docs.rs/quickcheck/latest/quickcheck/) and [`Clone`], which it requires, always generating the constant, and enable [`constant_choose`].
+//! - `rand` - Implement [`Distribution`](https://docs.rs/rand/latest/rand/distr/trait.Distribution.html) trait for [`StandardUniform`](https://docs.rs/rand/latest/rand/distr/struct.StandardUniform.html) from [`rand`](https://docs.rs/rand/latest/rand/), always sampling the constant, and enable [`constant_distribution`].
+//! - `redis` - Implement [`ToRedisArgs`](https://docs.rs/redis/latest/redis/trait.ToRedisArgs.html) and [`FromRedisValue`](https://docs.rs/redis/latest/redis/trait.FromRedisValue.html) traits from [`redis`](https://docs.rs/redis/latest/redis/).
+//! - `regex` - Enable [`constant_regex`] for patterns compiled to a [`Regex`](https://docs.rs/regex/latest/regex/struct.Regex.html) from [`regex`](https://docs.rs/regex/latest/regex/).
+//! - `rkyv` - Implement [`Archive`](https://docs.rs/rkyv/latest/rkyv/trait.Archive.html), [`Serialize`](https://docs.rs/rkyv/latest/rkyv/trait.Serialize.html) and [`Deserialize`](https://docs.rs/rkyv/latest/rkyv/trait.Deserialize.html) traits from [`rkyv`](https://docs.rs/rkyv/latest/rkyv/), archived as [`ArchivedString`](https://docs.rs/rkyv/latest/rkyv/string/struct.ArchivedString.html).
+//! - `rocket` - Implement [`FromParam`](https://docs.rs/rocket/latest/rocket/request/trait.FromParam.html), [`FromFormField`](https://docs.rs/rocket/latest/rocket/form/trait.FromFormField.html) and [`UriDisplay`](https://docs.rs/rocket/latest/rocket/http/uri/fmt/trait.UriDisplay.html) traits from [`rocket`](https://docs.rs/rocket/latest/rocket/).
+//! - `rusqlite` - Implement [`ToSql`](https://docs.rs/rusqlite/latest/rusqlite/trait.ToSql.html) and [`FromSql`](https://docs.rs/rusqlite/latest/rusqlite/types/trait.FromSql.html) traits from [`rusqlite`](https://docs.rs/rusqlite/latest/rusqlite/) for text columns.
+//! - `salvo` - Implement [`ToSchema`](https://docs.rs/salvo-oapi/latest/salvo_oapi/trait.ToSchema.html) trait from [`salvo-oapi`](https://docs.rs/salvo-oapi/latest/salvo_oapi/).
+//! - `schemars` - Implement [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) trait from [`schemars`](https://docs.rs/schemars/latest/schemars/).
+//! - `scylla` - Implement [`SerializeValue`](https://docs.rs/scylla/latest/scylla/serialize/value/trait.SerializeValue.html) and [`DeserializeValue`](https://docs.rs/scylla/latest/scylla/deserialize/value/trait.DeserializeValue.html) traits from [`scylla`](https://docs.rs/scylla/latest/scylla/) for `ascii` and `text` columns.
+//! - `sea-orm` - Implement [`TryGetable`](https://docs.rs/sea-orm/latest/sea_orm/trait.TryGetable.html) and [`ValueType`](https://docs.rs/sea-query/latest/sea_query/value/trait.ValueType.html) traits and conversion into [`Value`](https://docs.rs/sea-orm/latest/sea_orm/enum.Value.html) from [`sea-orm`](https://docs.rs/sea-orm/latest/sea_orm/) for entity columns.
 //! - `serde` - Implement [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) and [`Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html) traits from [`serde`](https://docs.rs/serde/latest/serde/).
-//! - `utoipa` - Implement [`ToSchema`](https://docs.rs/utoipa/latest/utoipa/trait.ToSchema.html) trait from [`utoipa`](https://docs.rs/utoipa/latest/utoipa/).
+//! - `serde-reflection` - Trace constant strings with their values for [`serde-reflection`](https://docs.rs/serde-reflection/latest/serde_reflection/) with [`serde_reflection::trace`].
+//! - `serde_with` - Enable [`serde_with::MustBe`] adapters for [`serde_with`](https://docs.rs/serde_with/latest/serde_with/).
+//! - `serde_yaml` - Implement [`From`] for [`Value`](https://docs.rs/serde_yaml/latest/serde_yaml/enum.Value.html) from [`serde_yaml`](https://docs.rs/serde_yaml/latest/serde_yaml/), so constant strings can be inserted into YAML documents.
+//! - `smol_str` - Implement [`From`] for [`SmolStr`](https://docs.rs/smol_str/latest/smol_str/struct.SmolStr.html) from [`smol_str`](https://docs.rs/smol_str/latest/smol_str/), borrowing the constant without allocating.
+//! - `specta` - Implement [`Type`](https://docs.rs/specta/latest/specta/trait.Type.html) trait from [`specta`](https://docs.rs/specta/latest/specta/), exporting string literal types.
+//! - `speedy` - Implement [`Readable`](https://docs.rs/speedy/latest/speedy/trait.Readable.html) and [`Writable`](https://docs.rs/speedy/latest/speedy/trait.Writable.html) traits from [`speedy`](https://docs.rs/speedy/latest/speedy/).
+//! - `sqlx` - Implement [`Type`](https://docs.rs/sqlx/latest/sqlx/trait.Type.html), [`Encode`](https://docs.rs/sqlx/latest/sqlx/trait.Encode.html) and [`Decode`](https://docs.rs/sqlx/latest/sqlx/trait.Decode.html) traits from [`sqlx`](https://docs.rs/sqlx/latest/sqlx/) for text columns.
+//! - `std` - Enabled by default. Disable default features for `no_std` targets, keeping `alloc`, `defmt`, `serde` and `ufmt`. All other integrations enable `std`.
+//! - `test-util` - Enable [`test_util`] assertions for constant strings using [`serde_test`](https://docs.rs/serde_test/latest/serde_test/).
+//! - `time` - Enable [`constant_time_format`] for format descriptions from [`time`](https://docs.rs/time/latest/time/).
+//! - `toml` - Implement [`From`] for [`Value`](https://docs.rs/toml/latest/toml/enum.Value.html) from [`toml`](https://docs.rs/toml/latest/toml/), so constant strings can be inserted into TOML documents.
+//! - `ts-rs` - Implement [`TS`](https://docs.rs/ts-rs/latest/ts_rs/trait.TS.html) trait from [`ts-rs`](https://docs.rs/ts-rs/latest/ts_rs/), exporting string literal types.
+//! - `tsify` - Implement [`Tsify`](https://docs.rs/tsify/latest/tsify/trait.Tsify.html) trait from [`tsify`](https://docs.rs/tsify/latest/tsify/), declaring string literal types in the generated TypeScript. Enables `serde` and `wasm-bindgen`.
+//! - `ufmt` - Implement [`uDisplay`](https://docs.rs/ufmt/latest/ufmt/trait.uDisplay.html) and [`uDebug`](https://docs.rs/ufmt/latest/ufmt/trait.uDebug.html) traits from [`ufmt`](https://docs.rs/ufmt/latest/ufmt/), for formatting on embedded targets without [`core::fmt`].
+//! - `unicode-normalization` - Enable the `nfc` option to compare under NFC normalization using [`unicode-normalization`](https://docs.rs/unicode-normalization/latest/unicode_normalization/).
+//! - `uniffi` - Enable [`constant_custom_type`] for custom types of `String` from [`uniffi`](https://docs.rs/uniffi/latest/uniffi/).
+//! - `ustr` - Implement [`From`] for [`Ustr`](https://docs.rs/ustr/latest/ustr/struct.Ustr.html) from [`ustr`](https://docs.rs/ustr/latest/ustr/), interning the constant once, and provide [`pre_intern`](crate::ustr::pre_intern) to intern constants at startup.
+//! - `utoipa` - Implement [`ToSchema`](https://docs.rs/utoipa/latest/utoipa/trait.ToSchema.html) trait from [`utoipa`](https://docs.rs/utoipa/latest/utoipa/) and enable [`constant_enum_schema`], [`constant_event_one_of`], [`constant_one_of`], [`constant_response`] and [`constant_string_components`].
+//! - `utoipa-4` - Implement [`ToSchema`](https://docs.rs/utoipa/4/utoipa/trait.ToSchema.html) trait from [`utoipa` 4](https://docs.rs/utoipa/4/utoipa/).
+//! - `utoipa-5` - Alias for `utoipa`.
+//! - `validator` - Implement [`Validate`](https://docs.rs/validator/latest/validator/trait.Validate.html) trait from [`validator`](https://docs.rs/validator/latest/validator/), so constant strings can be validated with `#[validate(nested)]`, and string validators for [`ConstantOr`]. Enables `serde`.
+//! - `valuable` - Implement [`Valuable`](https://docs.rs/valuable/latest/valuable/trait.Valuable.html) trait from [`valuable`](https://docs.rs/valuable/latest/valuable/) as a string value, for structured [`tracing`](https://docs.rs/tracing/latest/tracing/) fields.
+//! - `wasm-bindgen` - Implement [`From`] for [`JsValue`](https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html) from [`wasm-bindgen`](https://docs.rs/wasm-bindgen/latest/wasm_bindgen/).
+//! - `yew` - Implement [`IntoPropValue`](https://docs.rs/yew/latest/yew/html/trait.IntoPropValue.html) for [`AttrValue`](https://docs.rs/yew/latest/yew/virtual_dom/type.AttrValue.html) and conversion into [`Html`](https://docs.rs/yew/latest/yew/html/type.Html.html) and [`Classes`](https://docs.rs/yew/latest/yew/html/struct.Classes.html) from [`yew`](https://docs.rs/yew/latest/yew/), so constant strings can be used as props, text and class names in `html!`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+#[cfg(feature = "aide")]
+pub mod aide;
+#[cfg(feature = "apistos")]
+pub mod apistos;
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "bincode")]
+pub mod bincode;
+#[cfg(feature = "borsh")]
+pub mod borsh;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "alloc")]
+mod coded_error;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+#[cfg(feature = "nightly")]
+mod const_str;
+#[cfg(feature = "alloc")]
+mod constant_or;
+#[cfg(feature = "diesel")]
+pub mod diesel;
+#[cfg(feature = "alloc")]
+mod error;
+mod event;
+#[cfg(feature = "fake")]
+pub mod fake;
+mod ffi;
+mod group;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "icu_locale_core")]
+pub mod icu_locale_core;
+#[cfg(feature = "inventory")]
+pub mod inventory;
+#[cfg(feature = "juniper")]
+pub mod juniper;
+mod maybe_constant;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "minicbor")]
+pub mod minicbor;
+#[cfg(feature = "miniserde")]
+pub mod miniserde;
+#[cfg(feature = "musli")]
+pub mod musli;
+#[cfg(feature = "nanoserde")]
+pub mod nanoserde;
+#[cfg(feature = "napi")]
+pub mod napi;
+#[cfg(feature = "okapi")]
+pub mod okapi;
+mod one_of;
+mod options;
+mod parse;
+#[cfg(feature = "poem-openapi")]
+pub mod poem_openapi;
+#[cfg(feature = "postcard-schema")]
+pub mod postcard_schema;
+#[cfg(feature = "postgres-types")]
+pub mod postgres_types;
+#[cfg(feature = "alloc")]
+mod problem_details;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "pyo3")]
+pub mod pyo3;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "redis")]
+pub mod redis;
+#[cfg(feature = "regex")]
+pub mod regex;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+#[cfg(feature = "salvo")]
+pub mod salvo;
+#[cfg(any(
+    feature = "aide",
+    feature = "apistos",
+    feature = "okapi",
+    feature = "poem-openapi",
+    feature = "salvo",
+    feature = "schemars",
+    feature = "utoipa",
+    feature = "utoipa-4"
+))]
+mod schema;
+#[cfg(feature = "schemars")]
+pub mod schemars;
+#[cfg(feature = "scylla")]
+pub mod scylla;
+#[cfg(feature = "sea-orm")]
+pub mod sea_orm;
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(feature = "serde-reflection")]
+pub mod serde_reflection;
+#[cfg(feature = "serde_with")]
+pub mod serde_with;
+mod set;
+#[cfg(feature = "specta")]
+pub mod specta;
+#[cfg(feature = "speedy")]
+pub mod speedy;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+#[cfg(feature = "alloc")]
+mod tagged_enum;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "ts-rs")]
+pub mod ts_rs;
+#[cfg(feature = "tsify")]
+pub mod tsify;
+#[cfg(feature = "alloc")]
+mod typed_map;
+#[cfg(any(feature = "codegen", feature = "ts-rs"))]
+mod typescript;
+#[cfg(feature = "ufmt")]
+pub mod ufmt;
+#[cfg(feature = "uniffi")]
+pub mod uniffi;
+#[cfg(feature = "ustr")]
+pub mod ustr;
+#[cfg(feature = "utoipa")]
+pub mod utoipa;
+#[cfg(feature = "utoipa-4")]
+pub mod utoipa_4;
+pub mod version;
 
-#[cfg(all(feature = "serde", feature = "utoipa"))]
-/// Implement a constant string.
-///
-/// # Example
-/// ```
-/// # use constant_string::constant_string;
-/// #
-/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
-/// ```
-#[macro_export]
-macro_rules! constant_string {
-    ($name:ident, $code_name:ident, $code:literal) => {
-        $crate::constant_string_base!($name, $code_name, $code);
-        $crate::constant_string_serde!($name, $code_name, $code);
-        $crate::constant_string_utoipa!($name, $code_name, $code);
-    };
+#[cfg(feature = "alloc")]
+pub use coded_error::CodedError;
+#[cfg(feature = "nightly")]
+pub use const_str::ConstStr;
+#[cfg(feature = "alloc")]
+pub use constant_or::ConstantOr;
+#[cfg(feature = "alloc")]
+pub use error::{ConstantError, ConstantErrorCode};
+pub use event::Event;
+pub use maybe_constant::MaybeConstant;
+pub use one_of::{OneOf2, OneOf3};
+pub use options::{MismatchErrorFn, Options};
+#[cfg(feature = "alloc")]
+pub use parse::{ParseError, parse};
+#[cfg(feature = "alloc")]
+pub use problem_details::{PROBLEM_JSON, ProblemDetails};
+pub use set::{ConstantSetMember, ConstantStringSet};
+#[cfg(feature = "alloc")]
+pub use tagged_enum::TagError;
+#[cfg(feature = "alloc")]
+pub use typed_map::TypedMap;
+
+/// Dependencies referenced by macro expansions, so consumers don't need them as direct dependencies.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::parse::matches_constant;
+    #[cfg(feature = "apistos")]
+    pub use apistos_core;
+    #[cfg(feature = "apistos")]
+    pub use apistos_models;
+    #[cfg(feature = "apistos")]
+    pub use apistos_schemars;
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "arcstr")]
+    pub use arcstr;
+    #[cfg(feature = "async-graphql")]
+    pub use async_graphql;
+    #[cfg(feature = "bincode")]
+    pub use bincode;
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+    #[cfg(feature = "bson")]
+    pub use bson;
+    #[cfg(feature = "postgres-types")]
+    pub use bytes;
+    #[cfg(feature = "clap")]
+    pub use clap;
+    #[cfg(feature = "compact_str")]
+    pub use compact_str;
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+    #[cfg(feature = "dioxus")]
+    pub use dioxus_core;
+    #[cfg(feature = "fake")]
+    pub use fake;
+    #[cfg(feature = "garde")]
+    pub use garde;
+    #[cfg(feature = "http")]
+    pub use http;
+    #[cfg(feature = "icu_locale_core")]
+    pub use icu_locale_core;
+    #[cfg(feature = "implicit-clone")]
+    pub use implicit_clone;
+    #[cfg(feature = "inventory")]
+    pub use inventory;
+    #[cfg(feature = "juniper")]
+    pub use juniper;
+    #[cfg(feature = "maud")]
+    pub use maud;
+    #[cfg(feature = "metrics")]
+    pub use metrics;
+    #[cfg(feature = "minicbor")]
+    pub use minicbor;
+    #[cfg(feature = "miniserde")]
+    pub use miniserde;
+    #[cfg(feature = "musli")]
+    pub use musli;
+    #[cfg(feature = "nanoserde")]
+    pub use nanoserde;
+    #[cfg(feature = "napi")]
+    pub use napi;
+    #[cfg(feature = "okapi")]
+    pub use okapi;
+    #[cfg(feature = "opentelemetry")]
+    pub use opentelemetry;
+    #[cfg(feature = "poem-openapi")]
+    pub use poem_openapi;
+    #[cfg(feature = "postcard-schema")]
+    pub use postcard_schema;
+    #[cfg(feature = "postgres-types")]
+    pub use postgres_types;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    #[cfg(feature = "pyo3")]
+    pub use pyo3;
+    #[cfg(feature = "quickcheck")]
+    pub use quickcheck;
+    #[cfg(feature = "rand")]
+    pub use rand;
+    #[cfg(feature = "redis")]
+    pub use redis;
+    #[cfg(feature = "regex")]
+    pub use regex;
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+    #[cfg(feature = "rocket")]
+    pub use rocket;
+    #[cfg(feature = "rusqlite")]
+    pub use rusqlite;
+    #[cfg(feature = "salvo")]
+    pub use salvo_oapi;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(feature = "aide")]
+    pub use schemars_0_9;
+    #[cfg(feature = "scylla")]
+    pub use scylla_cql_core;
+    #[cfg(feature = "sea-orm")]
+    pub use sea_orm;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "poem-openapi")]
+    pub use serde_json;
+    #[cfg(feature = "serde_yaml")]
+    pub use serde_yaml;
+    #[cfg(feature = "smol_str")]
+    pub use smol_str;
+    #[cfg(feature = "specta")]
+    pub use specta;
+    #[cfg(feature = "speedy")]
+    pub use speedy;
+    #[cfg(feature = "sqlx")]
+    pub use sqlx;
+    #[cfg(feature = "leptos")]
+    pub use tachys;
+    #[cfg(feature = "toml")]
+    pub use toml;
+    #[cfg(feature = "ts-rs")]
+    pub use ts_rs;
+    #[cfg(feature = "tsify")]
+    pub use tsify;
+    #[cfg(feature = "ufmt")]
+    pub use ufmt;
+    #[cfg(feature = "uniffi")]
+    pub use uniffi;
+    #[cfg(feature = "ustr")]
+    pub use ustr;
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+    #[cfg(feature = "utoipa-4")]
+    pub use utoipa_4;
+    #[cfg(feature = "validator")]
+    pub use validator;
+    #[cfg(feature = "valuable")]
+    pub use valuable;
+    #[cfg(feature = "wasm-bindgen")]
+    pub use wasm_bindgen;
+    #[cfg(feature = "yew")]
+    pub use yew;
+}
+
+/// Constant string type implemented by [`constant_string`].
+pub trait ConstantString: Default + core::ops::Deref<Target = str> {
+    /// Name of the constant string type.
+    const NAME: &'static str;
+
+    /// Constant string value.
+    const VALUE: &'static str;
+
+    /// Constant string options.
+    const OPTIONS: Options;
+
+    /// Description from the [`description`](Options::description) option, or an empty string if unset.
+    fn description() -> &'static str {
+        Self::OPTIONS.description.unwrap_or_default()
+    }
 }
 
-#[cfg(all(feature = "serde", not(feature = "utoipa")))]
 /// Implement a constant string.
 ///
 /// # Example
@@ -55,44 +441,92 @@ macro_rules! constant_string {
 /// #
 /// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
 /// ```
-#[macro_export]
-macro_rules! constant_string {
-    ($name:ident, $code_name:ident, $code:literal) => {
-        $crate::constant_string_base!($name, $code_name, $code);
-        $crate::constant_string_serde!($name, $code_name, $code);
-    };
-}
-
-#[cfg(all(not(feature = "serde"), feature = "utoipa"))]
-/// Implement a constant string.
 ///
-/// # Example
+/// Trailing arguments set [`Options`]:
 /// ```
 /// # use constant_string::constant_string;
 /// #
-/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound", case_insensitive);
 /// ```
-#[macro_export]
-macro_rules! constant_string {
-    ($name:ident, $code_name:ident, $code:literal) => {
-        $crate::constant_string_base!($name, $code_name, $code);
-        $crate::constant_string_utoipa!($name, $code_name, $code);
-    };
-}
-
-#[cfg(all(not(feature = "serde"), not(feature = "utoipa")))]
-/// Implement a constant string.
 ///
-/// # Example
+/// Leading attributes are applied to the type:
 /// ```
 /// # use constant_string::constant_string;
 /// #
-/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(
+///     #[deprecated = "Use `MissingErrorCode` instead."]
+///     NotFoundErrorCode,
+///     NOT_FOUND_ERROR_CODE,
+///     "notFound",
+///     deprecated,
+/// );
 /// ```
 #[macro_export]
 macro_rules! constant_string {
-    ($name:ident, $code_name:ident, $code:literal) => {
-        $crate::constant_string_base!($name, $code_name, $code);
+    ($(#[$meta:meta])* $name:ident, $code_name:ident, $code:literal $(, $($options:tt)*)?) => {
+        $crate::constant_string_base!($(#[$meta])* $name, $code_name, $code $(, $($options)*)?);
+        $crate::constant_string_aide!($name, $code_name, $code);
+        $crate::constant_string_apistos!($name, $code_name, $code);
+        $crate::constant_string_arbitrary!($name, $code_name, $code);
+        $crate::constant_string_arcstr!($name, $code_name, $code);
+        $crate::constant_string_askama!($name, $code_name, $code);
+        $crate::constant_string_async_graphql!($name, $code_name, $code);
+        $crate::constant_string_bincode!($name, $code_name, $code);
+        $crate::constant_string_borsh!($name, $code_name, $code);
+        $crate::constant_string_bson!($name, $code_name, $code);
+        $crate::constant_string_clap!($name, $code_name, $code);
+        $crate::constant_string_compact_str!($name, $code_name, $code);
+        $crate::constant_string_defmt!($name, $code_name, $code);
+        $crate::constant_string_diesel!($name, $code_name, $code);
+        $crate::constant_string_dioxus!($name, $code_name, $code);
+        $crate::constant_string_fake!($name, $code_name, $code);
+        $crate::constant_string_garde!($name, $code_name, $code);
+        $crate::constant_string_icu_locale_core!($name, $code_name, $code);
+        $crate::constant_string_implicit_clone!($name, $code_name, $code);
+        $crate::constant_string_inventory!($name, $code_name, $code);
+        $crate::constant_string_juniper!($name, $code_name, $code);
+        $crate::constant_string_leptos!($name, $code_name, $code);
+        $crate::constant_string_maud!($name, $code_name, $code);
+        $crate::constant_string_metrics!($name, $code_name, $code);
+        $crate::constant_string_minicbor!($name, $code_name, $code);
+        $crate::constant_string_miniserde!($name, $code_name, $code);
+        $crate::constant_string_musli!($name, $code_name, $code);
+        $crate::constant_string_nanoserde!($name, $code_name, $code);
+        $crate::constant_string_napi!($name, $code_name, $code);
+        $crate::constant_string_okapi!($name, $code_name, $code);
+        $crate::constant_string_opentelemetry!($name, $code_name, $code);
+        $crate::constant_string_poem_openapi!($name, $code_name, $code);
+        $crate::constant_string_postcard_schema!($name, $code_name, $code);
+        $crate::constant_string_postgres_types!($name, $code_name, $code);
+        $crate::constant_string_proptest!($name, $code_name, $code);
+        $crate::constant_string_pyo3!($name, $code_name, $code);
+        $crate::constant_string_quickcheck!($name, $code_name, $code);
+        $crate::constant_string_rand!($name, $code_name, $code);
+        $crate::constant_string_redis!($name, $code_name, $code);
+        $crate::constant_string_rkyv!($name, $code_name, $code);
+        $crate::constant_string_rocket!($name, $code_name, $code);
+        $crate::constant_string_rusqlite!($name, $code_name, $code);
+        $crate::constant_string_salvo!($name, $code_name, $code);
+        $crate::constant_string_schemars!($name, $code_name, $code);
+        $crate::constant_string_scylla!($name, $code_name, $code);
+        $crate::constant_string_sea_orm!($name, $code_name, $code);
+        $crate::constant_string_serde!($name, $code_name, $code);
+        $crate::constant_string_serde_yaml!($name, $code_name, $code);
+        $crate::constant_string_smol_str!($name, $code_name, $code);
+        $crate::constant_string_specta!($name, $code_name, $code);
+        $crate::constant_string_speedy!($name, $code_name, $code);
+        $crate::constant_string_sqlx!($name, $code_name, $code);
+        $crate::constant_string_toml!($name, $code_name, $code);
+        $crate::constant_string_ts_rs!($name, $code_name, $code);
+        $crate::constant_string_tsify!($name, $code_name, $code);
+        $crate::constant_string_ufmt!($name, $code_name, $code);
+        $crate::constant_string_ustr!($name, $code_name, $code);
+        $crate::constant_string_utoipa!($name, $code_name, $code);
+        $crate::constant_string_utoipa_4!($name, $code_name, $code);
+        $crate::constant_string_validator!($name, $code_name, $code);
+        $crate::constant_string_valuable!($name, $code_name, $code);
+        $crate::constant_string_wasm_bindgen!($name, $code_name, $code);
+        $crate::constant_string_yew!($name, $code_name, $code);
     };
 }
 
@@ -100,135 +534,3597 @@ macro_rules! constant_string {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! constant_string_base {
-    ($name:ident, $code_name:ident, $code:literal) => {
-        #[doc = concat!("Constant for [`", stringify!($name), "`].")]
+    ($(#[$meta:meta])* $name:ident, $code_name:ident, $code:literal $(, $($options:tt)*)?) => {
+        #[doc = ::core::concat!("Constant for [`", ::core::stringify!($name), "`].")]
         const $code_name: &str = $code;
 
-        #[doc = concat!("Constant string `", stringify!($code), "`.")]
-        #[derive(Eq, PartialEq)]
+        #[doc = ::core::concat!("Constant string `", ::core::stringify!($code), "`.")]
+        $(#[$meta])*
+        #[derive(::core::cmp::Eq, ::core::cmp::PartialEq)]
         pub struct $name;
 
-        impl Default for $name {
+        #[allow(deprecated)]
+        impl ::core::default::Default for $name {
+            #[inline]
             fn default() -> Self {
                 Self
             }
         }
 
-        impl ::std::ops::Deref for $name {
+        #[allow(deprecated)]
+        impl ::core::ops::Deref for $name {
             type Target = str;
 
+            #[inline]
             fn deref(&self) -> &Self::Target {
                 $code_name
             }
         }
 
-        impl ::std::fmt::Debug for $name {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                ::std::fmt::Debug::fmt(&**self, f)
+        #[allow(deprecated)]
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&**self, f)
             }
         }
+
+        #[allow(deprecated)]
+        impl $crate::ConstantString for $name {
+            const NAME: &'static str = ::core::stringify!($name);
+
+            const VALUE: &'static str = $code_name;
+
+            const OPTIONS: $crate::Options = $crate::constant_string_options!(@fields [] $($($options)*)?);
+        }
+
+        #[allow(deprecated)]
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$name>() == 0,
+            ::core::concat!("`", ::core::stringify!($name), "` must be zero-sized"),
+        );
+
+        $crate::constant_string_clone!($name);
     };
 }
 
-/// Implement [`serde`] traits for a constant string.
-#[cfg(feature = "serde")]
+/// Implement [`Clone`] for a constant string, as required by the `clap` and `quickcheck` integrations.
+#[cfg(any(feature = "clap", feature = "quickcheck"))]
 #[doc(hidden)]
 #[macro_export]
-macro_rules! constant_string_serde {
+macro_rules! constant_string_clone {
+    ($name:ident) => {
+        #[allow(deprecated)]
+        impl ::core::clone::Clone for $name {
+            fn clone(&self) -> Self {
+                Self
+            }
+        }
+    };
+}
+
+#[cfg(not(any(feature = "clap", feature = "quickcheck")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_clone {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`schemars` 0.9](https://docs.rs/schemars/0.9/schemars/) traits for a constant string, as used by
+/// [`aide`](https://docs.rs/aide/latest/aide/).
+#[cfg(feature = "aide")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_aide {
     ($name:ident, $code_name:ident, $code:literal) => {
-        impl<'de> ::serde::Deserialize<'de> for $name {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: ::serde::Deserializer<'de>,
-            {
-                deserializer
-                    .deserialize_any($crate::serde::MustBeStrVisitor($code_name))
-                    .map(|()| Self)
+        #[allow(deprecated)]
+        impl $crate::__private::schemars_0_9::JsonSchema for $name {
+            fn schema_name() -> ::std::borrow::Cow<'static, str> {
+                $crate::aide::name::<Self>()
+            }
+
+            fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!($name)
+                ))
+            }
+
+            fn json_schema(
+                _generator: &mut $crate::__private::schemars_0_9::SchemaGenerator,
+            ) -> $crate::__private::schemars_0_9::Schema {
+                $crate::aide::schema::<Self>()
             }
         }
+    };
+}
 
-        impl ::serde::Serialize for $name {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: ::serde::Serializer,
-            {
-                serializer.serialize_str($code_name)
+#[cfg(not(feature = "aide"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_aide {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`apistos`](https://docs.rs/apistos/latest/apistos/) traits for a constant string.
+#[cfg(feature = "apistos")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_apistos {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::apistos_schemars::JsonSchema for $name {
+            fn schema_name() -> ::std::string::String {
+                $crate::apistos::name::<Self>()
+            }
+
+            fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!($name)
+                ))
+            }
+
+            fn json_schema(
+                _generator: &mut $crate::__private::apistos_schemars::r#gen::SchemaGenerator,
+            ) -> $crate::__private::apistos_schemars::schema::Schema {
+                $crate::apistos::schema::<Self>()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::apistos_core::ApiComponent for $name {
+            fn child_schemas() -> ::std::vec::Vec<(
+                ::std::string::String,
+                $crate::__private::apistos_models::reference_or::ReferenceOr<
+                    $crate::__private::apistos_models::Schema,
+                >,
+            )> {
+                ::std::vec::Vec::new()
+            }
+
+            fn schema() -> ::core::option::Option<(
+                ::std::string::String,
+                $crate::__private::apistos_models::reference_or::ReferenceOr<
+                    $crate::__private::apistos_models::Schema,
+                >,
+            )> {
+                $crate::apistos::component::<Self>()
             }
         }
     };
 }
 
-/// Implement [`utoipa`] traits for a constant string.
-#[cfg(feature = "utoipa")]
+#[cfg(not(feature = "apistos"))]
 #[doc(hidden)]
 #[macro_export]
-macro_rules! constant_string_utoipa {
+macro_rules! constant_string_apistos {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`arbitrary`] traits for a constant string.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_arbitrary {
     ($name:ident, $code_name:ident, $code:literal) => {
-        impl ::utoipa::PartialSchema for $name {
-            fn schema() -> ::utoipa::openapi::RefOr<::utoipa::openapi::schema::Schema> {
-                ::utoipa::openapi::schema::ObjectBuilder::new()
-                    .schema_type(::utoipa::openapi::schema::Type::String)
-                    .enum_values(Some([$code_name]))
-                    .build()
-                    .into()
+        #[allow(deprecated)]
+        impl<'a> $crate::__private::arbitrary::Arbitrary<'a> for $name {
+            fn arbitrary(
+                _: &mut $crate::__private::arbitrary::Unstructured<'a>,
+            ) -> $crate::__private::arbitrary::Result<Self> {
+                ::core::result::Result::Ok(Self)
+            }
+
+            fn size_hint(_: usize) -> (usize, ::core::option::Option<usize>) {
+                (0, ::core::option::Option::Some(0))
             }
         }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_arbitrary {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`arcstr`] traits for a constant string.
+#[cfg(feature = "arcstr")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_arcstr {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::arcstr::ArcStr {
+            fn from(_: $name) -> Self {
+                // `literal!` refers to `Some` by name.
+                use ::core::option::Option::Some;
 
-        impl ::utoipa::ToSchema for $name {}
+                $crate::__private::arcstr::literal!($code)
+            }
+        }
     };
 }
 
-#[cfg(test)]
-mod tests {
-    use std::ops::Deref;
+#[cfg(not(feature = "arcstr"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_arcstr {
+    ($($tt:tt)*) => {};
+}
 
-    constant_string!(Constant, CONSTANT, "constant");
+/// Implement [`Display`](std::fmt::Display) for a constant string, as required by `askama` templates.
+#[cfg(feature = "askama")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_askama {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str($code_name)
+            }
+        }
+    };
+}
 
-    #[test]
-    fn constant() {
-        assert_eq!(Constant.deref(), "constant");
-        assert_eq!(Constant.to_string(), "constant".to_owned());
-    }
+#[cfg(not(feature = "askama"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_askama {
+    ($($tt:tt)*) => {};
+}
 
-    #[test]
-    #[expect(clippy::default_constructed_unit_structs)]
-    fn default() {
-        assert_eq!(Constant::default().deref(), "constant");
-        assert_eq!(Constant::default().to_string(), "constant".to_owned());
-    }
+/// Implement [`async_graphql`] traits for a constant string.
+#[cfg(feature = "async-graphql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_async_graphql {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::async_graphql::ScalarType for $name {
+            fn parse(
+                value: $crate::__private::async_graphql::Value,
+            ) -> $crate::__private::async_graphql::InputValueResult<Self> {
+                $crate::async_graphql::parse_value(value)
+            }
 
-    #[cfg(feature = "serde")]
-    #[test]
-    fn serde() {
-        assert_eq!(
-            "\"constant\"",
-            serde_json::to_string(&Constant).expect("serializable value")
-        );
-        assert_eq!(
-            Constant,
-            serde_json::from_str("\"constant\"").expect("deserializable value")
-        );
-    }
+            fn is_valid(value: &$crate::__private::async_graphql::Value) -> bool {
+                $crate::async_graphql::is_valid::<Self>(value)
+            }
 
-    #[cfg(feature = "utoipa")]
-    #[test]
-    fn utoipa() {
-        use utoipa::{
-            PartialSchema,
-            openapi::{
-                RefOr, Type,
-                schema::{Object, Schema},
-            },
+            fn to_value(&self) -> $crate::__private::async_graphql::Value {
+                $crate::async_graphql::to_value::<Self>()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::async_graphql::InputType for $name {
+            type RawValueType = Self;
+
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                $crate::async_graphql::name::<Self>()
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> ::std::string::String {
+                $crate::async_graphql::create_input_type_info::<Self>(registry)
+            }
+
+            fn parse(
+                value: ::core::option::Option<$crate::__private::async_graphql::Value>,
+            ) -> $crate::__private::async_graphql::InputValueResult<Self> {
+                $crate::async_graphql::parse_value(value.unwrap_or_default())
+            }
+
+            fn to_value(&self) -> $crate::__private::async_graphql::Value {
+                $crate::async_graphql::to_value::<Self>()
+            }
+
+            fn as_raw_value(&self) -> ::core::option::Option<&Self::RawValueType> {
+                ::core::option::Option::Some(self)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::async_graphql::OutputType for $name {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                $crate::async_graphql::name::<Self>()
+            }
+
+            fn create_type_info(
+                registry: &mut $crate::__private::async_graphql::registry::Registry,
+            ) -> ::std::string::String {
+                $crate::async_graphql::create_output_type_info::<Self>(registry)
+            }
+
+            async fn resolve(
+                &self,
+                _: &$crate::__private::async_graphql::ContextSelectionSet<'_>,
+                _: &$crate::__private::async_graphql::Positioned<
+                    $crate::__private::async_graphql::parser::types::Field,
+                >,
+            ) -> $crate::__private::async_graphql::ServerResult<
+                $crate::__private::async_graphql::Value,
+            > {
+                ::core::result::Result::Ok($crate::async_graphql::to_value::<Self>())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "async-graphql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_async_graphql {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`bincode`] traits for a constant string.
+#[cfg(feature = "bincode")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_bincode {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::bincode::Encode for $name {
+            fn encode<E: $crate::__private::bincode::enc::Encoder>(
+                &self,
+                encoder: &mut E,
+            ) -> ::core::result::Result<(), $crate::__private::bincode::error::EncodeError> {
+                $crate::bincode::encode::<Self, E>(encoder)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<Context> $crate::__private::bincode::Decode<Context> for $name {
+            fn decode<D: $crate::__private::bincode::de::Decoder<Context = Context>>(
+                decoder: &mut D,
+            ) -> ::core::result::Result<Self, $crate::__private::bincode::error::DecodeError> {
+                $crate::bincode::decode(decoder)
+            }
+        }
+
+        #[allow(deprecated)]
+        const _: () = {
+            // `impl_borrow_decode!` refers to `core` by name.
+            use ::core;
+
+            $crate::__private::bincode::impl_borrow_decode!($name);
         };
+    };
+}
 
-        assert_eq!(
-            RefOr::T(Schema::Object(
-                Object::builder()
-                    .schema_type(Type::String)
-                    .enum_values(Some(["constant"]))
-                    .build()
-            )),
-            Constant::schema()
-        )
+#[cfg(not(feature = "bincode"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_bincode {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`borsh`] traits for a constant string.
+#[cfg(feature = "borsh")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_borsh {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::borsh::BorshSerialize for $name {
+            fn serialize<W: $crate::__private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::__private::borsh::io::Result<()> {
+                $crate::borsh::serialize::<Self, W>(writer)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::borsh::BorshDeserialize for $name {
+            fn deserialize_reader<R: $crate::__private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::__private::borsh::io::Result<Self> {
+                $crate::borsh::deserialize_reader(reader)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "borsh"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_borsh {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`bson`] conversions for a constant string.
+#[cfg(feature = "bson")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_bson {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::bson::Bson {
+            fn from(_: $name) -> Self {
+                $crate::bson::to_bson::<$name>()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "bson"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_bson {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`clap`] traits for a constant string.
+#[cfg(feature = "clap")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_clap {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::clap::builder::ValueParserFactory for $name {
+            type Parser = $crate::clap::ConstantValueParser<Self>;
+
+            fn value_parser() -> Self::Parser {
+                $crate::clap::ConstantValueParser::new()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "clap"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_clap {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`compact_str`] traits for a constant string.
+#[cfg(feature = "compact_str")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_compact_str {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::compact_str::CompactString {
+            fn from(_: $name) -> Self {
+                Self::const_new($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "compact_str"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_compact_str {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`defmt`] traits for a constant string.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_defmt {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        const _: () = {
+            // The `defmt` macros refer to `defmt` by name.
+            use $crate::__private::defmt;
+
+            #[allow(deprecated)]
+            impl defmt::Format for $name {
+                fn format(&self, f: defmt::Formatter<'_>) {
+                    defmt::write!(f, "{=istr}", defmt::intern!($code));
+                }
+            }
+        };
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_defmt {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`diesel`] traits for a constant string.
+#[cfg(feature = "diesel")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_diesel {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        $crate::constant_string_diesel!(@as_expression $name, $crate::__private::diesel::sql_types::Text);
+        $crate::constant_string_diesel!(
+            @as_expression $name,
+            $crate::__private::diesel::sql_types::Nullable<$crate::__private::diesel::sql_types::Text>
+        );
+
+        #[allow(deprecated)]
+        impl<DB> $crate::__private::diesel::serialize::ToSql<$crate::__private::diesel::sql_types::Text, DB>
+            for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            str: $crate::__private::diesel::serialize::ToSql<$crate::__private::diesel::sql_types::Text, DB>,
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                $crate::__private::diesel::serialize::ToSql::<
+                    $crate::__private::diesel::sql_types::Text,
+                    DB,
+                >::to_sql($code_name, out)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<DB> $crate::__private::diesel::deserialize::FromSql<$crate::__private::diesel::sql_types::Text, DB>
+            for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            ::std::string::String:
+                $crate::__private::diesel::deserialize::FromSql<$crate::__private::diesel::sql_types::Text, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> $crate::__private::diesel::deserialize::Result<Self> {
+                $crate::diesel::from_sql::<Self, DB>(bytes)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<ST, DB> $crate::__private::diesel::deserialize::Queryable<ST, DB> for $name
+        where
+            ST: $crate::__private::diesel::sql_types::SingleValue,
+            DB: $crate::__private::diesel::backend::Backend,
+            Self: $crate::__private::diesel::deserialize::FromSql<ST, DB>,
+        {
+            type Row = Self;
+
+            fn build(row: Self) -> $crate::__private::diesel::deserialize::Result<Self> {
+                ::core::result::Result::Ok(row)
+            }
+        }
+    };
+    (@as_expression $name:ident, $sql_type:ty) => {
+        #[allow(deprecated)]
+        impl $crate::__private::diesel::expression::AsExpression<$sql_type> for $name {
+            type Expression =
+                <&'static str as $crate::__private::diesel::expression::AsExpression<$sql_type>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::__private::diesel::expression::AsExpression::<$sql_type>::as_expression(
+                    <Self as $crate::ConstantString>::VALUE,
+                )
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::diesel::expression::AsExpression<$sql_type> for &$name {
+            type Expression =
+                <&'static str as $crate::__private::diesel::expression::AsExpression<$sql_type>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::__private::diesel::expression::AsExpression::<$sql_type>::as_expression(
+                    <$name as $crate::ConstantString>::VALUE,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "diesel"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_diesel {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`dioxus_core`] traits for a constant string.
+#[cfg(feature = "dioxus")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_dioxus {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::dioxus_core::IntoAttributeValue for $name {
+            fn into_value(self) -> $crate::__private::dioxus_core::AttributeValue {
+                $crate::__private::dioxus_core::IntoAttributeValue::into_value($code_name)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::dioxus_core::IntoDynNode for $name {
+            fn into_dyn_node(self) -> $crate::__private::dioxus_core::DynamicNode {
+                $crate::__private::dioxus_core::IntoDynNode::into_dyn_node($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "dioxus"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_dioxus {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`fake`] traits for a constant string.
+#[cfg(feature = "fake")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_fake {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::fake::Dummy<$crate::__private::fake::Faker> for $name {
+            fn dummy_with_rng<R: $crate::__private::fake::RngExt + ?::core::marker::Sized>(
+                _: &$crate::__private::fake::Faker,
+                _: &mut R,
+            ) -> Self {
+                Self
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "fake"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_fake {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`garde`] traits for a constant string.
+#[cfg(feature = "garde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_garde {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::garde::Validate for $name {
+            type Context = ();
+
+            fn validate_into(
+                &self,
+                _: &Self::Context,
+                _: &mut dyn ::core::ops::FnMut() -> $crate::__private::garde::Path,
+                _: &mut $crate::__private::garde::Report,
+            ) {
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "garde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_garde {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement localized messages with [`icu_locale_core`] for a constant string.
+#[cfg(feature = "icu_locale_core")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_icu_locale_core {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(dead_code, deprecated)]
+        impl $name {
+            /// Message for `locale` from the `messages` option.
+            ///
+            /// Falls back to a message for the language of `locale`, then the first message, then the constant.
+            pub fn message(
+                &self,
+                locale: &$crate::__private::icu_locale_core::LanguageIdentifier,
+            ) -> &'static str {
+                $crate::icu_locale_core::message::<Self>(locale)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "icu_locale_core"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_icu_locale_core {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`implicit_clone`] traits for a constant string.
+#[cfg(feature = "implicit-clone")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_implicit_clone {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::implicit_clone::unsync::IString {
+            fn from(_: $name) -> Self {
+                Self::Static($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "implicit-clone"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_implicit_clone {
+    ($($tt:tt)*) => {};
+}
+
+/// Register a constant string with [`inventory`].
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_inventory {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        $crate::__private::inventory::submit! {
+            #[allow(deprecated)]
+            $crate::inventory::Constant::of::<$name>()
+        }
+    };
+}
+
+#[cfg(not(feature = "inventory"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_inventory {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`juniper`] traits for a constant string.
+///
+/// The `#[graphql_scalar]` attribute names the scalar after the type alias it is applied to, so the alias shadows
+/// the constant string type in a nested block.
+#[cfg(feature = "juniper")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_juniper {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        const _: () = {
+            #[allow(deprecated)]
+            type ConstantString = $name;
+
+            const _: () = {
+                // `graphql_scalar` refers to the prelude.
+                use ::std::prelude::rust_2021::*;
+
+                #[$crate::__private::juniper::graphql_scalar]
+                #[graphql(with = $crate::juniper, parse_token(::std::string::String))]
+                #[allow(dead_code)]
+                type $name = ConstantString;
+            };
+        };
+    };
+}
+
+#[cfg(not(feature = "juniper"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_juniper {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`tachys`] traits, as used by `leptos`, for a constant string.
+///
+/// The class implementation delegates to the one of `&'static str`, so the constant replaces the class attribute.
+#[cfg(feature = "leptos")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_leptos {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::tachys::view::IntoRender for $name {
+            type Output = &'static str;
+
+            fn into_render(self) -> Self::Output {
+                $code_name
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::tachys::html::attribute::IntoAttributeValue for $name {
+            type Output = &'static str;
+
+            fn into_attribute_value(self) -> Self::Output {
+                $code_name
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::tachys::html::class::IntoClass for $name {
+            type AsyncOutput = &'static str;
+            type State = <&'static str as $crate::__private::tachys::html::class::IntoClass>::State;
+            type Cloneable = &'static str;
+            type CloneableOwned = &'static str;
+
+            fn html_len(&self) -> usize {
+                $code_name.len()
+            }
+
+            fn to_html(self, class: &mut ::std::string::String) {
+                $crate::__private::tachys::html::class::IntoClass::to_html($code_name, class);
+            }
+
+            fn should_overwrite(&self) -> bool {
+                true
+            }
+
+            fn hydrate<const FROM_SERVER: bool>(
+                self,
+                el: &$crate::__private::tachys::renderer::types::Element,
+            ) -> Self::State {
+                $crate::__private::tachys::html::class::IntoClass::hydrate::<FROM_SERVER>(
+                    $code_name, el,
+                )
+            }
+
+            fn build(
+                self,
+                el: &$crate::__private::tachys::renderer::types::Element,
+            ) -> Self::State {
+                $crate::__private::tachys::html::class::IntoClass::build($code_name, el)
+            }
+
+            fn rebuild(self, state: &mut Self::State) {
+                $crate::__private::tachys::html::class::IntoClass::rebuild($code_name, state);
+            }
+
+            fn into_cloneable(self) -> Self::Cloneable {
+                $code_name
+            }
+
+            fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                $code_name
+            }
+
+            fn dry_resolve(&mut self) {}
+
+            async fn resolve(self) -> Self::AsyncOutput {
+                $code_name
+            }
+
+            fn reset(state: &mut Self::State) {
+                <&'static str as $crate::__private::tachys::html::class::IntoClass>::reset(state);
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "leptos"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_leptos {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`maud`] traits for a constant string.
+#[cfg(feature = "maud")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_maud {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::maud::Render for $name {
+            fn render_to(&self, buffer: &mut ::std::string::String) {
+                $crate::__private::maud::Render::render_to($code_name, buffer);
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "maud"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_maud {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`metrics`] traits for a constant string.
+#[cfg(feature = "metrics")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_metrics {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::metrics::SharedString {
+            fn from(_: $name) -> Self {
+                Self::const_str($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "metrics"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_metrics {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`minicbor`] traits for a constant string.
+#[cfg(feature = "minicbor")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_minicbor {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl<Context> $crate::__private::minicbor::Encode<Context> for $name {
+            fn encode<W: $crate::__private::minicbor::encode::Write>(
+                &self,
+                encoder: &mut $crate::__private::minicbor::Encoder<W>,
+                _: &mut Context,
+            ) -> ::core::result::Result<(), $crate::__private::minicbor::encode::Error<W::Error>>
+            {
+                $crate::minicbor::encode::<Self, W>(encoder)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'b, Context> $crate::__private::minicbor::Decode<'b, Context> for $name {
+            fn decode(
+                decoder: &mut $crate::__private::minicbor::Decoder<'b>,
+                _: &mut Context,
+            ) -> ::core::result::Result<Self, $crate::__private::minicbor::decode::Error> {
+                $crate::minicbor::decode(decoder)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "minicbor"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_minicbor {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`miniserde`] traits for a constant string.
+#[cfg(feature = "miniserde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_miniserde {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::miniserde::Serialize for $name {
+            fn begin(&self) -> $crate::__private::miniserde::ser::Fragment<'_> {
+                $crate::miniserde::serialize::<Self>()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::miniserde::Deserialize for $name {
+            fn begin(
+                out: &mut ::core::option::Option<Self>,
+            ) -> &mut dyn $crate::__private::miniserde::de::Visitor {
+                $crate::miniserde::begin(out)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "miniserde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_miniserde {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`musli`] traits for a constant string.
+#[cfg(feature = "musli")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_musli {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl<M> $crate::__private::musli::Encode<M> for $name {
+            type Encode = Self;
+
+            fn encode<E>(&self, encoder: E) -> ::core::result::Result<(), E::Error>
+            where
+                E: $crate::__private::musli::Encoder<Mode = M>,
+            {
+                $crate::musli::encode::<Self, E>(encoder)
+            }
+
+            fn as_encode(&self) -> &Self::Encode {
+                self
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'de, M, A> $crate::__private::musli::Decode<'de, M, A> for $name
+        where
+            A: $crate::__private::musli::Allocator,
+        {
+            fn decode<D>(decoder: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::musli::Decoder<'de, Mode = M, Allocator = A>,
+            {
+                $crate::musli::decode(decoder)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "musli"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_musli {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`nanoserde`] traits for a constant string.
+#[cfg(feature = "nanoserde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_nanoserde {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::nanoserde::SerJson for $name {
+            fn ser_json(
+                &self,
+                indent_level: usize,
+                state: &mut $crate::__private::nanoserde::SerJsonState,
+            ) {
+                $crate::nanoserde::ser_json::<Self>(indent_level, state);
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::nanoserde::DeJson for $name {
+            fn de_json(
+                state: &mut $crate::__private::nanoserde::DeJsonState,
+                input: &mut ::core::str::Chars,
+            ) -> ::core::result::Result<Self, $crate::__private::nanoserde::DeJsonErr> {
+                $crate::nanoserde::de_json(state, input)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::nanoserde::SerBin for $name {
+            fn ser_bin(&self, output: &mut ::std::vec::Vec<u8>) {
+                $crate::nanoserde::ser_bin::<Self>(output);
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::nanoserde::DeBin for $name {
+            fn de_bin(
+                offset: &mut usize,
+                bytes: &[u8],
+            ) -> ::core::result::Result<Self, $crate::__private::nanoserde::DeBinErr> {
+                $crate::nanoserde::de_bin(offset, bytes)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::nanoserde::SerRon for $name {
+            fn ser_ron(
+                &self,
+                indent_level: usize,
+                state: &mut $crate::__private::nanoserde::SerRonState,
+            ) {
+                $crate::nanoserde::ser_ron::<Self>(indent_level, state);
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::nanoserde::DeRon for $name {
+            fn de_ron(
+                state: &mut $crate::__private::nanoserde::DeRonState,
+                input: &mut ::core::str::Chars,
+            ) -> ::core::result::Result<Self, $crate::__private::nanoserde::DeRonErr> {
+                $crate::nanoserde::de_ron(state, input)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "nanoserde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_nanoserde {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`napi`] traits for a constant string.
+#[cfg(feature = "napi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_napi {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::napi::bindgen_prelude::TypeName for $name {
+            fn type_name() -> &'static str {
+                "String"
+            }
+
+            fn value_type() -> $crate::__private::napi::ValueType {
+                $crate::__private::napi::ValueType::String
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::napi::bindgen_prelude::ValidateNapiValue for $name {}
+
+        #[allow(deprecated, unsafe_code)]
+        impl $crate::__private::napi::bindgen_prelude::ToNapiValue for $name {
+            unsafe fn to_napi_value(
+                env: $crate::__private::napi::sys::napi_env,
+                _: Self,
+            ) -> $crate::__private::napi::Result<$crate::__private::napi::sys::napi_value> {
+                // SAFETY: The caller guarantees that `env` is a valid environment.
+                unsafe {
+                    <&str as $crate::__private::napi::bindgen_prelude::ToNapiValue>::to_napi_value(
+                        env, $code_name,
+                    )
+                }
+            }
+        }
+
+        #[allow(deprecated, unsafe_code)]
+        impl $crate::__private::napi::bindgen_prelude::FromNapiValue for $name {
+            unsafe fn from_napi_value(
+                env: $crate::__private::napi::sys::napi_env,
+                napi_val: $crate::__private::napi::sys::napi_value,
+            ) -> $crate::__private::napi::Result<Self> {
+                // SAFETY: The caller guarantees that `env` and `napi_val` are valid.
+                let value = unsafe {
+                    <::std::string::String as $crate::__private::napi::bindgen_prelude::FromNapiValue>::from_napi_value(
+                        env, napi_val,
+                    )
+                }?;
+
+                $crate::napi::from_string(&value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "napi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_napi {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`okapi`] traits for a constant string.
+#[cfg(feature = "okapi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_okapi {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::okapi::schemars::JsonSchema for $name {
+            fn schema_name() -> ::std::string::String {
+                $crate::okapi::name::<Self>()
+            }
+
+            fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!($name)
+                ))
+            }
+
+            fn json_schema(
+                _generator: &mut $crate::__private::okapi::schemars::r#gen::SchemaGenerator,
+            ) -> $crate::__private::okapi::schemars::schema::Schema {
+                $crate::okapi::schema::<Self>()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "okapi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_okapi {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`opentelemetry`] traits for a constant string.
+#[cfg(feature = "opentelemetry")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_opentelemetry {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::opentelemetry::StringValue {
+            fn from(_: $name) -> Self {
+                Self::from($code_name)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::opentelemetry::Value {
+            fn from(_: $name) -> Self {
+                Self::String(::core::convert::Into::into($code_name))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "opentelemetry"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_opentelemetry {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`poem_openapi`] traits for a constant string.
+#[cfg(feature = "poem-openapi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_poem_openapi {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::poem_openapi::types::Type for $name {
+            const IS_REQUIRED: bool = true;
+
+            type RawValueType = Self;
+
+            type RawElementValueType = Self;
+
+            fn name() -> ::std::borrow::Cow<'static, str> {
+                $crate::poem_openapi::name::<Self>()
+            }
+
+            fn schema_ref() -> $crate::__private::poem_openapi::registry::MetaSchemaRef {
+                $crate::__private::poem_openapi::registry::MetaSchemaRef::Reference(
+                    $crate::poem_openapi::name::<Self>().into_owned(),
+                )
+            }
+
+            fn register(registry: &mut $crate::__private::poem_openapi::registry::Registry) {
+                registry.create_schema::<Self, _>(
+                    $crate::poem_openapi::name::<Self>().into_owned(),
+                    |_| $crate::poem_openapi::schema::<Self>(),
+                );
+            }
+
+            fn as_raw_value(&self) -> ::core::option::Option<&Self::RawValueType> {
+                ::core::option::Option::Some(self)
+            }
+
+            fn raw_element_iter<'a>(
+                &'a self,
+            ) -> ::std::boxed::Box<
+                dyn ::core::iter::Iterator<Item = &'a Self::RawElementValueType> + 'a,
+            > {
+                ::std::boxed::Box::new(::core::iter::once(self))
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::poem_openapi::types::ParseFromJSON for $name {
+            fn parse_from_json(
+                value: ::core::option::Option<$crate::__private::serde_json::Value>,
+            ) -> $crate::__private::poem_openapi::types::ParseResult<Self> {
+                $crate::poem_openapi::parse_from_json(value)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::poem_openapi::types::ToJSON for $name {
+            fn to_json(&self) -> ::core::option::Option<$crate::__private::serde_json::Value> {
+                ::core::option::Option::Some(::core::convert::Into::into(
+                    <Self as $crate::ConstantString>::VALUE,
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "poem-openapi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_poem_openapi {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`postcard_schema`] traits for a constant string.
+#[cfg(feature = "postcard-schema")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_postcard_schema {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::postcard_schema::Schema for $name {
+            const SCHEMA: &'static $crate::__private::postcard_schema::schema::NamedType =
+                &$crate::postcard_schema::named_type::<$name>();
+        }
+    };
+}
+
+#[cfg(not(feature = "postcard-schema"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_postcard_schema {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`postgres_types`] traits for a constant string.
+#[cfg(feature = "postgres-types")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_postgres_types {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        const _: () = {
+            // `to_sql_checked!` refers to `Box` by name.
+            use ::std::boxed::Box;
+
+            #[allow(deprecated)]
+            impl $crate::__private::postgres_types::ToSql for $name {
+                fn to_sql(
+                    &self,
+                    ty: &$crate::__private::postgres_types::Type,
+                    out: &mut $crate::__private::bytes::BytesMut,
+                ) -> ::core::result::Result<
+                    $crate::__private::postgres_types::IsNull,
+                    ::std::boxed::Box<
+                        dyn ::core::error::Error + ::core::marker::Sync + ::core::marker::Send,
+                    >,
+                > {
+                    $crate::postgres_types::to_sql::<Self>(ty, out)
+                }
+
+                fn accepts(ty: &$crate::__private::postgres_types::Type) -> bool {
+                    $crate::postgres_types::to_sql_accepts(ty)
+                }
+
+                $crate::__private::postgres_types::to_sql_checked!();
+            }
+        };
+
+        #[allow(deprecated)]
+        impl<'a> $crate::__private::postgres_types::FromSql<'a> for $name {
+            fn from_sql(
+                ty: &$crate::__private::postgres_types::Type,
+                raw: &'a [u8],
+            ) -> ::core::result::Result<
+                Self,
+                ::std::boxed::Box<
+                    dyn ::core::error::Error + ::core::marker::Sync + ::core::marker::Send,
+                >,
+            > {
+                $crate::postgres_types::from_sql(ty, raw)
+            }
+
+            fn accepts(ty: &$crate::__private::postgres_types::Type) -> bool {
+                $crate::postgres_types::from_sql_accepts(ty)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "postgres-types"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_postgres_types {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`proptest`] traits for a constant string.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_proptest {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::proptest::arbitrary::Arbitrary for $name {
+            type Parameters = ();
+
+            type Strategy = $crate::__private::proptest::strategy::LazyJust<Self, fn() -> Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                $crate::proptest::constant::<Self>()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_proptest {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`pyo3`] traits for a constant string.
+///
+/// The Python string is interned once per constant string type.
+#[cfg(feature = "pyo3")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_pyo3 {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl<'py> $crate::__private::pyo3::IntoPyObject<'py> for $name {
+            type Target = $crate::__private::pyo3::types::PyString;
+
+            type Output = $crate::__private::pyo3::Bound<'py, Self::Target>;
+
+            type Error = ::core::convert::Infallible;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> ::core::result::Result<Self::Output, Self::Error> {
+                (&self).into_pyobject(py)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'py> $crate::__private::pyo3::IntoPyObject<'py> for &$name {
+            type Target = $crate::__private::pyo3::types::PyString;
+
+            type Output = $crate::__private::pyo3::Bound<'py, Self::Target>;
+
+            type Error = ::core::convert::Infallible;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> ::core::result::Result<Self::Output, Self::Error> {
+                ::core::result::Result::Ok(::core::clone::Clone::clone(
+                    $crate::__private::pyo3::intern!(py, $code_name),
+                ))
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'a, 'py> $crate::__private::pyo3::FromPyObject<'a, 'py> for $name {
+            type Error = $crate::__private::pyo3::PyErr;
+
+            fn extract(
+                obj: $crate::__private::pyo3::Borrowed<'a, 'py, $crate::__private::pyo3::PyAny>,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                $crate::pyo3::extract(obj)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "pyo3"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_pyo3 {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`quickcheck`] traits for a constant string.
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_quickcheck {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::quickcheck::Arbitrary for $name {
+            fn arbitrary(_: &mut $crate::__private::quickcheck::Gen) -> Self {
+                Self
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_quickcheck {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`rand`] traits for a constant string.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rand {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::rand::distr::Distribution<$name>
+            for $crate::__private::rand::distr::StandardUniform
+        {
+            fn sample<R>(&self, _: &mut R) -> $name
+            where
+                R: $crate::__private::rand::Rng + ?::core::marker::Sized,
+            {
+                $name
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rand {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`redis`] traits for a constant string.
+#[cfg(feature = "redis")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_redis {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::redis::ToRedisArgs for $name {
+            fn write_redis_args<W>(&self, out: &mut W)
+            where
+                W: ?::core::marker::Sized + $crate::__private::redis::RedisWrite,
+            {
+                $crate::redis::write_redis_args::<Self, W>(out);
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::redis::FromRedisValue for $name {
+            fn from_redis_value_ref(
+                value: &$crate::__private::redis::Value,
+            ) -> ::core::result::Result<Self, $crate::__private::redis::ParsingError> {
+                $crate::redis::from_redis_value(value)
+            }
+
+            fn from_redis_value(
+                value: $crate::__private::redis::Value,
+            ) -> ::core::result::Result<Self, $crate::__private::redis::ParsingError> {
+                $crate::redis::from_redis_value(&value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "redis"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_redis {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`rkyv`] traits for a constant string.
+#[cfg(feature = "rkyv")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rkyv {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::rkyv::Archive for $name {
+            type Archived = $crate::__private::rkyv::string::ArchivedString;
+            type Resolver = $crate::__private::rkyv::string::StringResolver;
+
+            fn resolve(
+                &self,
+                resolver: Self::Resolver,
+                out: $crate::__private::rkyv::Place<Self::Archived>,
+            ) {
+                $crate::rkyv::resolve::<Self>(resolver, out);
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<S> $crate::__private::rkyv::Serialize<S> for $name
+        where
+            S: $crate::__private::rkyv::rancor::Fallible + ?::core::marker::Sized,
+            S::Error: $crate::__private::rkyv::rancor::Source,
+            str: $crate::__private::rkyv::SerializeUnsized<S>,
+        {
+            fn serialize(
+                &self,
+                serializer: &mut S,
+            ) -> ::core::result::Result<Self::Resolver, S::Error> {
+                $crate::rkyv::serialize::<Self, S>(serializer)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<D> $crate::__private::rkyv::Deserialize<$name, D>
+            for $crate::__private::rkyv::string::ArchivedString
+        where
+            D: $crate::__private::rkyv::rancor::Fallible + ?::core::marker::Sized,
+            D::Error: $crate::__private::rkyv::rancor::Source,
+        {
+            fn deserialize(&self, _: &mut D) -> ::core::result::Result<$name, D::Error> {
+                $crate::rkyv::deserialize::<$name, D>(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rkyv"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rkyv {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`rocket`] traits for a constant string.
+#[cfg(feature = "rocket")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rocket {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl<'a> $crate::__private::rocket::request::FromParam<'a> for $name {
+            type Error = $crate::ParseError;
+
+            fn from_param(param: &'a str) -> ::core::result::Result<Self, Self::Error> {
+                $crate::rocket::from_param(param)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'v> $crate::__private::rocket::form::FromFormField<'v> for $name {
+            fn from_value(
+                field: $crate::__private::rocket::form::ValueField<'v>,
+            ) -> $crate::__private::rocket::form::Result<'v, Self> {
+                $crate::rocket::from_value(field)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<P: $crate::__private::rocket::http::uri::fmt::Part>
+            $crate::__private::rocket::http::uri::fmt::UriDisplay<P> for $name
+        {
+            fn fmt(
+                &self,
+                f: &mut $crate::__private::rocket::http::uri::fmt::Formatter<'_, P>,
+            ) -> ::core::fmt::Result {
+                $crate::rocket::fmt::<Self, P>(f)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<P: $crate::__private::rocket::http::uri::fmt::Part>
+            $crate::__private::rocket::http::uri::fmt::FromUriParam<P, $name> for $name
+        {
+            type Target = $name;
+
+            fn from_uri_param(param: $name) -> $name {
+                param
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'a, P: $crate::__private::rocket::http::uri::fmt::Part>
+            $crate::__private::rocket::http::uri::fmt::FromUriParam<P, &'a $name> for $name
+        {
+            type Target = &'a $name;
+
+            fn from_uri_param(param: &'a $name) -> &'a $name {
+                param
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rocket"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rocket {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`rusqlite`] traits for a constant string.
+#[cfg(feature = "rusqlite")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rusqlite {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::rusqlite::ToSql for $name {
+            fn to_sql(
+                &self,
+            ) -> $crate::__private::rusqlite::Result<
+                $crate::__private::rusqlite::types::ToSqlOutput<'_>,
+            > {
+                ::core::result::Result::Ok($crate::rusqlite::to_sql::<Self>())
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::rusqlite::types::FromSql for $name {
+            fn column_result(
+                value: $crate::__private::rusqlite::types::ValueRef<'_>,
+            ) -> $crate::__private::rusqlite::types::FromSqlResult<Self> {
+                $crate::rusqlite::column_result(value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rusqlite"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_rusqlite {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`salvo_oapi`] traits for a constant string.
+#[cfg(feature = "salvo")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_salvo {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::salvo_oapi::ToSchema for $name {
+            fn to_schema(
+                components: &mut $crate::__private::salvo_oapi::Components,
+            ) -> $crate::__private::salvo_oapi::RefOr<$crate::__private::salvo_oapi::Schema> {
+                $crate::salvo::to_schema::<Self>(components)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::salvo_oapi::ComposeSchema for $name {
+            fn compose(
+                components: &mut $crate::__private::salvo_oapi::Components,
+                _generics: ::std::vec::Vec<
+                    $crate::__private::salvo_oapi::RefOr<$crate::__private::salvo_oapi::Schema>,
+                >,
+            ) -> $crate::__private::salvo_oapi::RefOr<$crate::__private::salvo_oapi::Schema> {
+                $crate::salvo::to_schema::<Self>(components)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "salvo"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_salvo {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`schemars`] traits for a constant string.
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_schemars {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::schemars::JsonSchema for $name {
+            fn schema_name() -> ::std::borrow::Cow<'static, str> {
+                $crate::schemars::name::<Self>()
+            }
+
+            fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!($name)
+                ))
+            }
+
+            fn json_schema(
+                _generator: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::Schema {
+                $crate::schemars::schema::<Self>()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_schemars {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`scylla_cql_core`] traits for a constant string.
+#[cfg(feature = "scylla")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_scylla {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::scylla_cql_core::serialize::value::SerializeValue for $name {
+            fn serialize<'b>(
+                &self,
+                typ: &$crate::__private::scylla_cql_core::frame::response::result::ColumnType,
+                writer: $crate::__private::scylla_cql_core::serialize::writers::CellWriter<'b>,
+            ) -> ::core::result::Result<
+                $crate::__private::scylla_cql_core::serialize::writers::WrittenCellProof<'b>,
+                $crate::__private::scylla_cql_core::serialize::SerializationError,
+            > {
+                $crate::scylla::serialize::<Self>(typ, writer)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'frame, 'metadata>
+            $crate::__private::scylla_cql_core::deserialize::value::DeserializeValue<'frame, 'metadata>
+            for $name
+        {
+            fn type_check(
+                typ: &$crate::__private::scylla_cql_core::frame::response::result::ColumnType,
+            ) -> ::core::result::Result<(), $crate::__private::scylla_cql_core::deserialize::TypeCheckError> {
+                $crate::scylla::type_check(typ)
+            }
+
+            fn deserialize(
+                typ: &'metadata $crate::__private::scylla_cql_core::frame::response::result::ColumnType<'metadata>,
+                v: ::core::option::Option<$crate::__private::scylla_cql_core::deserialize::FrameSlice<'frame>>,
+            ) -> ::core::result::Result<Self, $crate::__private::scylla_cql_core::deserialize::DeserializationError> {
+                $crate::scylla::deserialize(typ, v)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "scylla"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_scylla {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`sea_orm`] traits for a constant string.
+#[cfg(feature = "sea-orm")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_sea_orm {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::sea_orm::Value {
+            fn from(_: $name) -> Self {
+                ::core::convert::From::from($code_name)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::sea_orm::TryGetable for $name {
+            fn try_get_by<I: $crate::__private::sea_orm::ColIdx>(
+                res: &$crate::__private::sea_orm::QueryResult,
+                index: I,
+            ) -> ::core::result::Result<Self, $crate::__private::sea_orm::TryGetError> {
+                $crate::sea_orm::try_get_by(res, index)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::sea_orm::sea_query::ValueType for $name {
+            fn try_from(
+                value: $crate::__private::sea_orm::Value,
+            ) -> ::core::result::Result<Self, $crate::__private::sea_orm::sea_query::ValueTypeErr>
+            {
+                $crate::sea_orm::try_from(value)
+            }
+
+            fn type_name() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(<Self as $crate::ConstantString>::NAME)
+            }
+
+            fn array_type() -> $crate::__private::sea_orm::sea_query::ArrayType {
+                $crate::__private::sea_orm::sea_query::ArrayType::String
+            }
+
+            fn column_type() -> $crate::__private::sea_orm::sea_query::ColumnType {
+                $crate::sea_orm::column_type()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::sea_orm::sea_query::Nullable for $name {
+            fn null() -> $crate::__private::sea_orm::Value {
+                $crate::__private::sea_orm::Value::String(::core::option::Option::None)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::sea_orm::IntoActiveValue<$name> for $name {
+            fn into_active_value(self) -> $crate::__private::sea_orm::ActiveValue<$name> {
+                $crate::__private::sea_orm::ActiveValue::Set(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "sea-orm"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_sea_orm {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`serde`] traits for a constant string.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_serde {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let visitor = $crate::serde::ConstantStringVisitor::<Self>::new();
+
+                if <Self as $crate::ConstantString>::OPTIONS.compact
+                    && !deserializer.is_human_readable()
+                {
+                    deserializer.deserialize_unit(visitor)
+                } else if <Self as $crate::ConstantString>::OPTIONS.null {
+                    deserializer.deserialize_option(visitor)
+                } else {
+                    deserializer.deserialize_str(visitor)
+                }
+                .map(|()| Self)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::serde::Serialize for $name {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                if <Self as $crate::ConstantString>::OPTIONS.compact
+                    && !serializer.is_human_readable()
+                {
+                    serializer.serialize_unit()
+                } else {
+                    serializer.serialize_str($code_name)
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'de, E> $crate::__private::serde::de::IntoDeserializer<'de, E> for $name
+        where
+            E: $crate::__private::serde::de::Error,
+        {
+            type Deserializer =
+                $crate::__private::serde::de::value::BorrowedStrDeserializer<'de, E>;
+
+            #[inline]
+            fn into_deserializer(self) -> Self::Deserializer {
+                $crate::__private::serde::de::value::BorrowedStrDeserializer::new($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_serde {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`serde_yaml`] conversions for a constant string.
+#[cfg(feature = "serde_yaml")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_serde_yaml {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::serde_yaml::Value {
+            fn from(_: $name) -> Self {
+                Self::String(::std::string::String::from($code_name))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde_yaml"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_serde_yaml {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`smol_str`] traits for a constant string.
+#[cfg(feature = "smol_str")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_smol_str {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::smol_str::SmolStr {
+            fn from(_: $name) -> Self {
+                Self::new_static($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "smol_str"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_smol_str {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`specta`] traits for a constant string.
+#[cfg(feature = "specta")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_specta {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::specta::Type for $name {
+            fn inline(
+                _: $crate::__private::specta::DefOpts,
+                _: &[$crate::__private::specta::DataType],
+            ) -> ::core::result::Result<
+                $crate::__private::specta::DataType,
+                $crate::__private::specta::ExportError,
+            > {
+                ::core::result::Result::Ok($crate::specta::data_type::<Self>())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "specta"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_specta {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`speedy`] traits for a constant string.
+#[cfg(feature = "speedy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_speedy {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl<Context> $crate::__private::speedy::Writable<Context> for $name
+        where
+            Context: $crate::__private::speedy::Context,
+        {
+            fn write_to<W: ?::core::marker::Sized + $crate::__private::speedy::Writer<Context>>(
+                &self,
+                writer: &mut W,
+            ) -> ::core::result::Result<(), Context::Error> {
+                $crate::speedy::write_to::<Self, Context, W>(writer)
+            }
+
+            fn bytes_needed(&self) -> ::core::result::Result<usize, Context::Error> {
+                $crate::speedy::bytes_needed::<Self, Context>()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'a, Context> $crate::__private::speedy::Readable<'a, Context> for $name
+        where
+            Context: $crate::__private::speedy::Context,
+        {
+            fn read_from<R: $crate::__private::speedy::Reader<'a, Context>>(
+                reader: &mut R,
+            ) -> ::core::result::Result<Self, Context::Error> {
+                $crate::speedy::read_from(reader)
+            }
+
+            fn minimum_bytes_needed() -> usize {
+                $crate::speedy::minimum_bytes_needed::<Context>()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "speedy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_speedy {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`sqlx`] traits for a constant string.
+#[cfg(feature = "sqlx")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_sqlx {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl<DB> $crate::__private::sqlx::Type<DB> for $name
+        where
+            DB: $crate::__private::sqlx::Database,
+            str: $crate::__private::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <str as $crate::__private::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <str as $crate::__private::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'q, DB> $crate::__private::sqlx::Encode<'q, DB> for $name
+        where
+            DB: $crate::__private::sqlx::Database,
+            &'q str: $crate::__private::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut DB::ArgumentBuffer,
+            ) -> ::core::result::Result<
+                $crate::__private::sqlx::encode::IsNull,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                $crate::sqlx::encode::<Self, DB>(buf)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'r, DB> $crate::__private::sqlx::Decode<'r, DB> for $name
+        where
+            DB: $crate::__private::sqlx::Database,
+            &'r str: $crate::__private::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: DB::ValueRef<'r>,
+            ) -> ::core::result::Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                $crate::sqlx::decode::<Self, DB>(value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "sqlx"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_sqlx {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`toml`] conversions for a constant string.
+#[cfg(feature = "toml")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_toml {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::toml::Value {
+            fn from(_: $name) -> Self {
+                Self::String(::std::string::String::from($code_name))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "toml"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_toml {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`ts_rs`] traits for a constant string.
+#[cfg(feature = "ts-rs")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_ts_rs {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::ts_rs::TS for $name {
+            type WithoutGenerics = Self;
+
+            type OptionInnerType = Self;
+
+            fn docs() -> ::core::option::Option<::std::string::String> {
+                <Self as $crate::ConstantString>::OPTIONS
+                    .description
+                    .map(::std::borrow::ToOwned::to_owned)
+            }
+
+            fn name(_: &$crate::__private::ts_rs::Config) -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(<Self as $crate::ConstantString>::NAME)
+            }
+
+            fn inline(_: &$crate::__private::ts_rs::Config) -> ::std::string::String {
+                $crate::ts_rs::inline::<Self>()
+            }
+
+            fn decl(_: &$crate::__private::ts_rs::Config) -> ::std::string::String {
+                $crate::ts_rs::decl::<Self>()
+            }
+
+            fn decl_concrete(_: &$crate::__private::ts_rs::Config) -> ::std::string::String {
+                $crate::ts_rs::decl::<Self>()
+            }
+
+            fn output_path() -> ::core::option::Option<::std::path::PathBuf> {
+                ::core::option::Option::Some($crate::ts_rs::output_path::<Self>())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ts-rs"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_ts_rs {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`tsify`] traits for a constant string.
+#[cfg(feature = "tsify")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_tsify {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::tsify::Tsify for $name {
+            type JsType = $crate::__private::wasm_bindgen::JsValue;
+
+            const DECL: &'static str = {
+                const LEN: usize = $crate::tsify::decl_len(::core::stringify!($name), $code);
+                const DECL: [u8; LEN] = $crate::tsify::decl(::core::stringify!($name), $code);
+
+                $crate::tsify::decl_str(&DECL)
+            };
+        }
+
+        const _: () = {
+            // The `wasm_bindgen` path of custom sections can't be overridden, so resolve it here.
+            use $crate::__private::wasm_bindgen;
+
+            #[wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+            const DECL: &'static str = <$name as $crate::__private::tsify::Tsify>::DECL;
+        };
+    };
+}
+
+#[cfg(not(feature = "tsify"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_tsify {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`ufmt`] traits for a constant string.
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_ufmt {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::ufmt::uDisplay for $name {
+            fn fmt<W>(
+                &self,
+                f: &mut $crate::__private::ufmt::Formatter<'_, W>,
+            ) -> ::core::result::Result<(), W::Error>
+            where
+                W: $crate::__private::ufmt::uWrite + ?::core::marker::Sized,
+            {
+                f.write_str($code_name)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::ufmt::uDebug for $name {
+            fn fmt<W>(
+                &self,
+                f: &mut $crate::__private::ufmt::Formatter<'_, W>,
+            ) -> ::core::result::Result<(), W::Error>
+            where
+                W: $crate::__private::ufmt::uWrite + ?::core::marker::Sized,
+            {
+                $crate::ufmt::debug_str($code_name, f)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ufmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_ufmt {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`ustr`] traits for a constant string.
+#[cfg(feature = "ustr")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_ustr {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::ustr::Ustr {
+            fn from(_: $name) -> Self {
+                static USTR: ::std::sync::OnceLock<$crate::__private::ustr::Ustr> =
+                    ::std::sync::OnceLock::new();
+
+                *USTR.get_or_init(|| $crate::__private::ustr::ustr($code_name))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ustr"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_ustr {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`utoipa`] traits for a constant string.
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_utoipa {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa::PartialSchema for $name {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<
+                $crate::__private::utoipa::openapi::schema::Schema,
+            > {
+                static SCHEMA: ::std::sync::LazyLock<
+                    $crate::__private::utoipa::openapi::RefOr<
+                        $crate::__private::utoipa::openapi::schema::Schema,
+                    >,
+                > = ::std::sync::LazyLock::new($crate::utoipa::schema::<$name>);
+
+                ::core::clone::Clone::clone(&*SCHEMA)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa::ToSchema for $name {
+            fn name() -> ::std::borrow::Cow<'static, str> {
+                $crate::utoipa::name::<Self>()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa::IntoParams for $name {
+            fn into_params(
+                parameter_in_provider: impl ::core::ops::Fn() -> ::core::option::Option<
+                    $crate::__private::utoipa::openapi::path::ParameterIn,
+                >,
+            ) -> ::std::vec::Vec<$crate::__private::utoipa::openapi::path::Parameter> {
+                ::std::vec![$crate::utoipa::parameter::<Self>(parameter_in_provider())]
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_utoipa {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`utoipa` 4](https://docs.rs/utoipa/4/utoipa/) traits for a constant string.
+#[cfg(feature = "utoipa-4")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_utoipa_4 {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa_4::PartialSchema for $name {
+            fn schema() -> $crate::__private::utoipa_4::openapi::RefOr<
+                $crate::__private::utoipa_4::openapi::schema::Schema,
+            > {
+                static SCHEMA: ::std::sync::LazyLock<
+                    $crate::__private::utoipa_4::openapi::RefOr<
+                        $crate::__private::utoipa_4::openapi::schema::Schema,
+                    >,
+                > = ::std::sync::LazyLock::new($crate::utoipa_4::schema::<$name>);
+
+                ::core::clone::Clone::clone(&*SCHEMA)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'__s> $crate::__private::utoipa_4::ToSchema<'__s> for $name {
+            fn schema() -> (
+                &'__s str,
+                $crate::__private::utoipa_4::openapi::RefOr<
+                    $crate::__private::utoipa_4::openapi::schema::Schema,
+                >,
+            ) {
+                (
+                    $crate::utoipa_4::name::<Self>(),
+                    <Self as $crate::__private::utoipa_4::PartialSchema>::schema(),
+                )
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa_4::IntoParams for $name {
+            fn into_params(
+                parameter_in_provider: impl ::core::ops::Fn() -> ::core::option::Option<
+                    $crate::__private::utoipa_4::openapi::path::ParameterIn,
+                >,
+            ) -> ::std::vec::Vec<$crate::__private::utoipa_4::openapi::path::Parameter> {
+                ::std::vec![$crate::utoipa_4::parameter::<Self>(parameter_in_provider())]
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "utoipa-4"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_utoipa_4 {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`validator`] traits for a constant string.
+#[cfg(feature = "validator")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_validator {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::validator::Validate for $name {
+            fn validate(
+                &self,
+            ) -> ::core::result::Result<(), $crate::__private::validator::ValidationErrors> {
+                ::core::result::Result::Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "validator"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_validator {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`valuable`] traits for a constant string.
+#[cfg(feature = "valuable")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_valuable {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl $crate::__private::valuable::Valuable for $name {
+            fn as_value(&self) -> $crate::__private::valuable::Value<'_> {
+                $crate::__private::valuable::Value::String($code_name)
+            }
+
+            fn visit(&self, visit: &mut dyn $crate::__private::valuable::Visit) {
+                visit.visit_value(self.as_value());
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "valuable"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_valuable {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`wasm_bindgen`] traits for a constant string.
+#[cfg(feature = "wasm-bindgen")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_wasm_bindgen {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::wasm_bindgen::JsValue {
+            fn from(_: $name) -> Self {
+                Self::from_str($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "wasm-bindgen"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_wasm_bindgen {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`yew`] traits for a constant string.
+///
+/// Mirrors the prop conversions of `&'static str`, including into optional props, without allocating.
+#[cfg(feature = "yew")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_yew {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        #[allow(deprecated)]
+        impl
+            $crate::__private::yew::html::IntoPropValue<
+                $crate::__private::yew::virtual_dom::AttrValue,
+            > for $name
+        {
+            fn into_prop_value(self) -> $crate::__private::yew::virtual_dom::AttrValue {
+                $crate::__private::yew::virtual_dom::AttrValue::Static($code_name)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl
+            $crate::__private::yew::html::IntoPropValue<
+                ::core::option::Option<$crate::__private::yew::virtual_dom::AttrValue>,
+            > for $name
+        {
+            fn into_prop_value(
+                self,
+            ) -> ::core::option::Option<$crate::__private::yew::virtual_dom::AttrValue> {
+                ::core::option::Option::Some(
+                    $crate::__private::yew::virtual_dom::AttrValue::Static($code_name),
+                )
+            }
+        }
+
+        $crate::constant_string_yew_html!($name, $code_name);
+
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::yew::html::Classes {
+            fn from(_: $name) -> Self {
+                Self::from($code_name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "yew"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_yew {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement conversion into [`Html`](yew::Html) for a constant string.
+///
+/// With the `askama` feature, the constant string implements [`Display`](std::fmt::Display), which `yew` already
+/// converts.
+#[cfg(all(feature = "yew", not(feature = "askama")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_yew_html {
+    ($name:ident, $code_name:ident) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__private::yew::Html {
+            fn from(_: $name) -> Self {
+                Self::VText($crate::__private::yew::virtual_dom::VText::new($code_name))
+            }
+        }
+    };
+}
+
+#[cfg(not(all(feature = "yew", not(feature = "askama"))))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_yew_html {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    constant_string!(Constant, CONSTANT, "constant");
+
+    #[test]
+    fn constant() {
+        assert_eq!(Constant.deref(), "constant");
+        assert_eq!(Constant.to_string(), "constant".to_owned());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn constant_attributes() {
+        constant_string!(
+            /// Legacy constant.
+            #[deprecated]
+            Legacy,
+            LEGACY,
+            "legacy",
+            deprecated
+        );
+
+        assert_eq!(Legacy.deref(), "legacy");
+        const { assert!(<Legacy as crate::ConstantString>::OPTIONS.deprecated) };
+    }
+
+    #[test]
+    fn description() {
+        use crate::ConstantString;
+
+        constant_string!(
+            NotFound,
+            NOT_FOUND,
+            "notFound",
+            description = "The entity was not found.",
+        );
+
+        fn description<C: ConstantString>() -> &'static str {
+            C::description()
+        }
+
+        const DESCRIPTION: Option<&str> = NotFound::OPTIONS.description;
+        assert_eq!(DESCRIPTION, Some("The entity was not found."));
+        assert_eq!(description::<NotFound>(), "The entity was not found.");
+        assert_eq!(Constant::description(), "");
+        assert_eq!(description::<Constant>(), "");
+    }
+
+    #[test]
+    fn zero_sized() {
+        assert_eq!(size_of::<Constant>(), 0);
+        assert!(std::ptr::eq(Constant.deref(), CONSTANT));
+    }
+
+    #[test]
+    #[expect(clippy::default_constructed_unit_structs)]
+    fn default() {
+        assert_eq!(Constant::default().deref(), "constant");
+        assert_eq!(Constant::default().to_string(), "constant".to_owned());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        #[derive(Arbitrary, Debug)]
+        struct Request {
+            format: Constant,
+        }
+
+        assert_eq!(Constant::size_hint(0), (0, Some(0)));
+        assert_eq!(
+            Constant::arbitrary(&mut Unstructured::new(&[])).expect("arbitrary value"),
+            Constant
+        );
+        assert_eq!(
+            Request::arbitrary(&mut Unstructured::new(&[]))
+                .expect("arbitrary value")
+                .format,
+            Constant
+        );
+    }
+
+    #[cfg(feature = "arcstr")]
+    #[test]
+    fn arcstr() {
+        use arcstr::ArcStr;
+
+        let constant = ArcStr::from(Constant);
+        assert_eq!(constant, "constant");
+        assert!(ArcStr::is_static(&constant));
+    }
+
+    #[cfg(feature = "askama")]
+    #[test]
+    fn askama() {
+        use askama::Template;
+
+        #[derive(Template)]
+        #[template(source = r#"<p title="{{ code }}">{{ code|upper }}</p>"#, ext = "html")]
+        struct Page {
+            code: Constant,
+        }
+
+        let page = Page { code: Constant };
+        assert_eq!(
+            page.render().expect("renderable template"),
+            r#"<p title="constant">CONSTANT</p>"#
+        );
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn compact_str() {
+        use compact_str::CompactString;
+
+        constant_string!(Long, LONG, "aConstantTooLongToBeInlined");
+
+        let long = CompactString::from(Long);
+        assert_eq!(long, LONG);
+        assert!(!long.is_heap_allocated());
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn defmt() {
+        use defmt::{
+            Format,
+            export::{fetch_bytes, fetch_string_index, make_formatter},
+        };
+
+        let index = fetch_string_index();
+        Constant.format(make_formatter());
+
+        // The write logs the indices of its format string and the interned constant, but not the constant itself.
+        let mut indices = fetch_bytes()
+            .chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        assert_eq!(indices, [index, index + 1]);
+    }
+
+    #[cfg(feature = "dioxus")]
+    #[test]
+    fn dioxus() {
+        use dioxus::prelude::*;
+
+        let html = dioxus_ssr::render_element(rsx! {
+            p { class: Constant, {Constant} }
+        });
+        assert_eq!(html, r#"<p class="constant">constant</p>"#);
+    }
+
+    #[cfg(feature = "garde")]
+    #[test]
+    fn garde() {
+        use garde::Validate;
+
+        #[derive(Validate)]
+        struct Request {
+            #[garde(dive)]
+            format: Constant,
+        }
+
+        assert!(Request { format: Constant }.validate().is_ok());
+    }
+
+    #[cfg(feature = "implicit-clone")]
+    #[test]
+    fn implicit_clone() {
+        use implicit_clone::unsync::IString;
+
+        assert_eq!(IString::from(Constant), IString::Static("constant"));
+    }
+
+    #[cfg(feature = "leptos")]
+    #[test]
+    fn leptos() {
+        use leptos::prelude::*;
+
+        let html = view! { <p class=Constant>{Constant}</p> }.to_html();
+        assert_eq!(html, r#"<p class="constant">constant</p>"#);
+    }
+
+    #[cfg(feature = "maud")]
+    #[test]
+    fn maud() {
+        use maud::html;
+
+        let markup = html! {
+            p title=(Constant) { (Constant) }
+        };
+        assert_eq!(markup.into_string(), r#"<p title="constant">constant</p>"#);
+    }
+
+    #[cfg(feature = "opentelemetry")]
+    #[test]
+    fn opentelemetry() {
+        use opentelemetry::{KeyValue, StringValue, Value};
+
+        assert_eq!(StringValue::from(Constant).as_str(), "constant");
+        assert_eq!(
+            KeyValue::new("code", Constant).value,
+            Value::from("constant")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(
+            "\"constant\"",
+            serde_json::to_string(&Constant).expect("serializable value")
+        );
+        assert_eq!(
+            Constant,
+            serde_json::from_str("\"constant\"").expect("deserializable value")
+        );
+        assert!(serde_json::from_str::<Constant>("\"other\"").is_err());
+        assert!(serde_json::from_str::<Constant>("1").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_case_insensitive() {
+        constant_string!(NotFound, NOT_FOUND, "notFound", case_insensitive);
+
+        assert_eq!(
+            "\"notFound\"",
+            serde_json::to_string(&NotFound).expect("serializable value")
+        );
+        for value in ["\"notFound\"", "\"NOTFOUND\"", "\"notfound\""] {
+            assert_eq!(
+                NotFound,
+                serde_json::from_str(value).expect("deserializable value")
+            );
+        }
+        assert!(serde_json::from_str::<NotFound>("\"not_found\"").is_err());
+        assert!(serde_json::from_str::<Constant>("\"CONSTANT\"").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_aliases() {
+        constant_string!(
+            NotFound,
+            NOT_FOUND,
+            "notFound",
+            aliases = ["not_found", "missing"]
+        );
+
+        assert_eq!(
+            "\"notFound\"",
+            serde_json::to_string(&NotFound).expect("serializable value")
+        );
+        for value in ["\"notFound\"", "\"not_found\"", "\"missing\""] {
+            assert_eq!(
+                NotFound,
+                serde_json::from_str(value).expect("deserializable value")
+            );
+        }
+        assert_eq!(
+            serde_json::from_str::<NotFound>("\"NOT_FOUND\"")
+                .map_err(|error| error.to_string()),
+            Err("invalid value: string \"NOT_FOUND\", expected constant \"notFound\" or alias \"not_found\" or alias \"missing\" for NotFound at line 1 column 11".to_owned())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_trim() {
+        constant_string!(NotFound, NOT_FOUND, "notFound", trim);
+
+        for value in ["\"notFound\"", "\"notFound \"", "\" \\tnotFound\\n\""] {
+            assert_eq!(
+                NotFound,
+                serde_json::from_str(value).expect("deserializable value")
+            );
+        }
+        assert!(serde_json::from_str::<NotFound>("\"not Found\"").is_err());
+        assert!(serde_json::from_str::<Constant>("\"constant \"").is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "unicode-normalization"))]
+    #[test]
+    fn serde_nfc() {
+        constant_string!(Cafe, CAFE, "caf\u{e9}", nfc);
+        constant_string!(
+            CafeIgnoreCase,
+            CAFE_IGNORE_CASE,
+            "caf\u{e9}",
+            nfc,
+            case_insensitive
+        );
+
+        for value in ["\"caf\u{e9}\"", "\"cafe\u{301}\""] {
+            assert_eq!(
+                Cafe,
+                serde_json::from_str(value).expect("deserializable value")
+            );
+        }
+        assert_eq!(
+            CafeIgnoreCase,
+            serde_json::from_str("\"CAFE\u{301}\"").expect("deserializable value")
+        );
+        assert!(serde_json::from_str::<Cafe>("\"cafe\"").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_lenient() {
+        use std::sync::Mutex;
+
+        static MISMATCHES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        fn record(expected: &'static str, found: &str) {
+            MISMATCHES
+                .lock()
+                .expect("unpoisoned lock")
+                .push(format!("{expected} {found}"));
+        }
+
+        constant_string!(Lenient, LENIENT, "lenient", lenient);
+        constant_string!(
+            Recorded,
+            RECORDED,
+            "recorded",
+            lenient,
+            on_mismatch = record
+        );
+
+        assert_eq!(
+            "\"lenient\"",
+            serde_json::to_string(&Lenient).expect("serializable value")
+        );
+        assert_eq!(
+            Lenient,
+            serde_json::from_str("\"other\"").expect("deserializable value")
+        );
+        assert!(serde_json::from_str::<Lenient>("1").is_err());
+
+        assert_eq!(
+            Recorded,
+            serde_json::from_str("\"recorded\"").expect("deserializable value")
+        );
+        assert_eq!(
+            Recorded,
+            serde_json::from_str("\"other\"").expect("deserializable value")
+        );
+        assert_eq!(
+            *MISMATCHES.lock().expect("unpoisoned lock"),
+            vec!["recorded other".to_owned()]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_mismatch_error() {
+        use std::fmt;
+
+        struct IncidentError<'a> {
+            expected: &'static str,
+            found: &'a str,
+        }
+
+        impl fmt::Display for IncidentError<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "E1042: expected {}, found {}", self.expected, self.found)
+            }
+        }
+
+        fn incident_error<'a>(expected: &'static str, found: &'a str) -> IncidentError<'a> {
+            IncidentError { expected, found }
+        }
+
+        constant_string!(
+            NotFound,
+            NOT_FOUND,
+            "notFound",
+            mismatch_error = incident_error
+        );
+
+        assert_eq!(
+            NotFound,
+            serde_json::from_str("\"notFound\"").expect("deserializable value")
+        );
+        assert_eq!(
+            serde_json::from_str::<NotFound>("\"other\"").map_err(|error| error.to_string()),
+            Err("E1042: expected notFound, found other at line 1 column 7".to_owned())
+        );
+        assert!(
+            serde_json::from_str::<NotFound>("1")
+                .is_err_and(|error| error.to_string().starts_with("invalid type"))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_expecting() {
+        constant_string!(
+            UserCreated,
+            USER_CREATED,
+            "user.created",
+            expecting = "the fixed event type \"user.created\""
+        );
+
+        assert_eq!(
+            serde_json::from_str::<UserCreated>("\"user.deleted\"").map_err(|error| error.to_string()),
+            Err("invalid value: string \"user.deleted\", expected the fixed event type \"user.created\" at line 1 column 14".to_owned())
+        );
+        assert_eq!(
+            serde_json::from_str::<UserCreated>("1").map_err(|error| error.to_string()),
+            Err("invalid type: integer `1`, expected the fixed event type \"user.created\" at line 1 column 1".to_owned())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_null() {
+        use serde::Deserialize;
+
+        constant_string!(Nullable, NULLABLE, "nullable", null);
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Error {
+            code: Nullable,
+        }
+
+        assert_eq!(
+            "\"nullable\"",
+            serde_json::to_string(&Nullable).expect("serializable value")
+        );
+        for json in ["\"nullable\"", "null"] {
+            assert_eq!(
+                Nullable,
+                serde_json::from_str(json).expect("deserializable value")
+            );
+        }
+        assert_eq!(
+            Error { code: Nullable },
+            serde_json::from_str(r#"{"code":null}"#).expect("deserializable value")
+        );
+        assert!(serde_json::from_str::<Nullable>("\"other\"").is_err());
+        assert!(serde_json::from_str::<Constant>("null").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_unchecked() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static MISMATCHES: AtomicUsize = AtomicUsize::new(0);
+
+        fn record(_expected: &'static str, _found: &str) {
+            MISMATCHES.fetch_add(1, Ordering::Relaxed);
+        }
+
+        constant_string!(
+            Unchecked,
+            UNCHECKED,
+            "unchecked",
+            unchecked,
+            lenient,
+            on_mismatch = record
+        );
+
+        for json in ["\"unchecked\"", "\"other\""] {
+            assert_eq!(
+                Unchecked,
+                serde_json::from_str(json).expect("deserializable value")
+            );
+        }
+        assert!(serde_json::from_str::<Unchecked>("1").is_err());
+        assert_eq!(MISMATCHES.load(Ordering::Relaxed), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_compact() {
+        constant_string!(Compact, COMPACT, "compact", compact);
+
+        assert_eq!(
+            "\"compact\"",
+            serde_json::to_string(&Compact).expect("serializable value")
+        );
+        assert_eq!(
+            Compact,
+            serde_json::from_str("\"compact\"").expect("deserializable value")
+        );
+
+        let bytes = postcard::to_allocvec(&Compact).expect("serializable value");
+        assert!(bytes.is_empty());
+        assert_eq!(
+            Compact,
+            postcard::from_bytes(&bytes).expect("deserializable value")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_into_deserializer() {
+        use serde::{
+            Deserialize,
+            de::{IntoDeserializer, value::Error},
+        };
+
+        assert_eq!(
+            Ok(Constant),
+            Constant::deserialize(IntoDeserializer::<Error>::into_deserializer(Constant))
+        );
+        assert_eq!(
+            Ok("constant"),
+            <&str>::deserialize(IntoDeserializer::<Error>::into_deserializer(Constant))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_untagged() {
+        use serde::Deserialize;
+
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+        constant_string!(Conflict, CONFLICT, "conflict", aliases = ["CONFLICT"]);
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(untagged)]
+        enum Error {
+            NotFound { code: NotFound },
+            Conflict { code: Conflict, resource: String },
+        }
+
+        for (json, value) in [
+            (r#"{"code":"notFound"}"#, Error::NotFound { code: NotFound }),
+            (
+                r#"{"code":"conflict","resource":"user"}"#,
+                Error::Conflict {
+                    code: Conflict,
+                    resource: "user".to_owned(),
+                },
+            ),
+            (
+                r#"{"code":"CONFLICT","resource":"user"}"#,
+                Error::Conflict {
+                    code: Conflict,
+                    resource: "user".to_owned(),
+                },
+            ),
+        ] {
+            assert_eq!(
+                value,
+                serde_json::from_str(json).expect("deserializable value")
+            );
+            assert_eq!(
+                value,
+                serde_json::from_reader(json.as_bytes()).expect("deserializable value")
+            );
+            assert_eq!(
+                value,
+                serde_json::from_value(
+                    serde_json::from_str::<serde_json::Value>(json).expect("deserializable value")
+                )
+                .expect("deserializable value")
+            );
+        }
+        for json in [r#"{"code":"other"}"#, r#"{"code":null}"#, r#"{"code":1}"#] {
+            assert!(serde_json::from_str::<Error>(json).is_err());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_flatten() {
+        use serde::{Deserialize, Serialize};
+
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Code {
+            code: NotFound,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Error {
+            #[serde(flatten)]
+            code: Code,
+            message: String,
+        }
+
+        let error = Error {
+            code: Code { code: NotFound },
+            message: "Not found.".to_owned(),
+        };
+        let json = r#"{"code":"notFound","message":"Not found."}"#;
+
+        assert_eq!(
+            json,
+            serde_json::to_string(&error).expect("serializable value")
+        );
+        assert_eq!(
+            error,
+            serde_json::from_str(json).expect("deserializable value")
+        );
+        assert_eq!(
+            error,
+            serde_json::from_reader(json.as_bytes()).expect("deserializable value")
+        );
+        assert_eq!(
+            serde_json::from_str::<Error>(r#"{"code":"other","message":"Not found."}"#)
+                .map_err(|error| error.to_string()),
+            Err("invalid value: string \"other\", expected constant \"notFound\" for NotFound at line 1 column 39".to_owned())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_non_self_describing() {
+        let bytes = postcard::to_allocvec(&Constant).expect("serializable value");
+
+        assert_eq!(
+            Constant,
+            postcard::from_bytes(&bytes).expect("deserializable value")
+        );
+        assert!(
+            postcard::from_bytes::<Constant>(
+                &postcard::to_allocvec("other").expect("serializable value")
+            )
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    #[test]
+    fn serde_yaml() {
+        use serde_yaml::Mapping;
+
+        let mut mapping = Mapping::new();
+        mapping.insert("code".into(), Constant.into());
+        assert_eq!(
+            serde_yaml::to_string(&mapping).ok().as_deref(),
+            Some("code: constant\n")
+        );
+    }
+
+    #[cfg(feature = "smol_str")]
+    #[test]
+    fn smol_str() {
+        use smol_str::SmolStr;
+
+        constant_string!(Long, LONG, "aConstantTooLongToBeInlined");
+
+        let long = SmolStr::from(Long);
+        assert_eq!(long, LONG);
+        assert!(!long.is_heap_allocated());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml() {
+        use toml::Table;
+
+        let mut table = Table::new();
+        table.insert("code".to_owned(), Constant.into());
+        assert_eq!(table.to_string(), "code = \"constant\"\n");
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{
+            PartialSchema, ToSchema,
+            openapi::{
+                RefOr, Type,
+                schema::{Object, Schema},
+            },
+        };
+
+        assert_eq!(
+            RefOr::T(Schema::Object(
+                Object::builder()
+                    .schema_type(Type::String)
+                    .default(Some("constant".into()))
+                    .examples(["constant"])
+                    .enum_values(Some(["constant"]))
+                    .build()
+            )),
+            Constant::schema()
+        );
+        assert_eq!(Constant::name(), "Constant");
+
+        constant_string!(
+            NotFound,
+            NOT_FOUND,
+            "notFound",
+            schema_name = "ErrorCode.NotFound"
+        );
+
+        assert_eq!(NotFound::name(), "ErrorCode.NotFound");
+
+        #[allow(deprecated)]
+        {
+            constant_string!(
+                #[deprecated]
+                Legacy,
+                LEGACY,
+                "legacy",
+                deprecated,
+                skip_schema_default,
+                skip_schema_examples,
+            );
+
+            assert_eq!(
+                r#"{"type":"string","enum":["legacy"],"deprecated":true}"#,
+                serde_json::to_string(&Legacy::schema()).expect("serializable value")
+            );
+        }
+
+        constant_string!(
+            ServerPopulated,
+            SERVER_POPULATED,
+            "server",
+            read_only,
+            skip_schema_default,
+            skip_schema_examples,
+        );
+        constant_string!(
+            ClientPopulated,
+            CLIENT_POPULATED,
+            "client",
+            write_only,
+            skip_schema_default,
+            skip_schema_examples,
+        );
+
+        assert_eq!(
+            r#"{"type":"string","enum":["server"],"readOnly":true}"#,
+            serde_json::to_string(&ServerPopulated::schema()).expect("serializable value")
+        );
+        assert_eq!(
+            r#"{"type":"string","enum":["client"],"writeOnly":true}"#,
+            serde_json::to_string(&ClientPopulated::schema()).expect("serializable value")
+        );
+
+        constant_string!(
+            UserCreated,
+            USER_CREATED,
+            "user.created",
+            schema_pattern,
+            skip_schema_enum,
+            skip_schema_default,
+            skip_schema_examples,
+        );
+
+        assert_eq!(
+            r#"{"type":"string","pattern":"^user\\.created$"}"#,
+            serde_json::to_string(&UserCreated::schema()).expect("serializable value")
+        );
+
+        constant_string!(
+            Strict,
+            STRICT,
+            "strict",
+            schema_length,
+            skip_schema_default,
+            skip_schema_examples,
+        );
+
+        assert_eq!(
+            r#"{"type":"string","enum":["strict"],"maxLength":6,"minLength":6}"#,
+            serde_json::to_string(&Strict::schema()).expect("serializable value")
+        );
+
+        constant_string!(
+            Extended,
+            EXTENDED,
+            "extended",
+            schema_const,
+            skip_schema_default,
+            skip_schema_examples,
+            extensions = { "x-error-category": "client", "owner": "platform" },
+        );
+
+        let schema = serde_json::to_value(Extended::schema()).expect("serializable value");
+        assert_eq!(schema["const"], "extended");
+        assert_eq!(schema["x-error-category"], "client");
+        assert_eq!(schema["x-owner"], "platform");
+
+        constant_string!(
+            Const,
+            CONST,
+            "const",
+            schema_const,
+            skip_schema_default,
+            skip_schema_examples
+        );
+
+        assert_eq!(
+            r#"{"type":"string","const":"const"}"#,
+            serde_json::to_string(&Const::schema()).expect("serializable value")
+        );
+
+        constant_string!(
+            Described,
+            DESCRIBED,
+            "described",
+            title = "Described",
+            description = "Constant with a description.",
+        );
+
+        assert_eq!(
+            RefOr::T(Schema::Object(
+                Object::builder()
+                    .schema_type(Type::String)
+                    .title(Some("Described"))
+                    .description(Some("Constant with a description."))
+                    .default(Some("described".into()))
+                    .examples(["described"])
+                    .enum_values(Some(["described"]))
+                    .build()
+            )),
+            Described::schema()
+        );
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa_into_params() {
+        use utoipa::{IntoParams, openapi::path::ParameterIn};
+
+        constant_string!(
+            FullResponseFormat,
+            FULL_RESPONSE_FORMAT,
+            "full",
+            parameter_name = "responseFormat",
+            skip_schema_default,
+            skip_schema_examples,
+        );
+
+        assert_eq!(
+            r#"[{"name":"responseFormat","in":"query","required":true,"schema":{"type":"string","enum":["full"]}}]"#,
+            serde_json::to_string(&FullResponseFormat::into_params(|| None))
+                .expect("serializable value")
+        );
+        assert_eq!(
+            r#"[{"name":"Constant","in":"header","required":true,"schema":{"type":"string","default":"constant","enum":["constant"],"examples":["constant"]}}]"#,
+            serde_json::to_string(&Constant::into_params(|| Some(ParameterIn::Header)))
+                .expect("serializable value")
+        );
+    }
+
+    #[cfg(feature = "validator")]
+    #[test]
+    fn validator() {
+        use validator::Validate;
+
+        #[derive(Validate)]
+        struct Request {
+            #[validate(nested)]
+            format: Constant,
+        }
+
+        assert!(Request { format: Constant }.validate().is_ok());
+    }
+
+    #[cfg(feature = "valuable")]
+    #[test]
+    fn valuable() {
+        use valuable::{Valuable, Value};
+
+        #[derive(Valuable)]
+        struct Request {
+            format: Constant,
+        }
+
+        assert!(matches!(Constant.as_value(), Value::String("constant")));
+        assert!(matches!(
+            Request { format: Constant }.as_value(),
+            Value::Structable(_)
+        ));
+    }
+
+    #[cfg(feature = "yew")]
+    #[test]
+    fn yew() {
+        use yew::{AttrValue, Classes, Html, Properties, classes, html, props};
+
+        #[derive(PartialEq, Properties)]
+        struct Props {
+            role: AttrValue,
+            test_id: Option<AttrValue>,
+        }
+
+        let props = props!(Props {
+            role: Constant,
+            test_id: Constant,
+        });
+        assert_eq!(props.role, "constant");
+        assert_eq!(props.test_id.as_deref(), Some("constant"));
+
+        assert_eq!(Html::from(Constant), html! { "constant" });
+        assert_eq!(Classes::from(Constant), classes!("constant"));
+    }
+
+    #[allow(dead_code)]
+    #[no_implicit_prelude]
+    mod no_implicit_prelude {
+        crate::constant_string!(
+            NotFound,
+            NOT_FOUND,
+            "notFound",
+            aliases = ["not_found"],
+            description = "The entity was not found.",
+        );
+
+        crate::constant_string_group! {
+            struct Missing;
+            trait MissingCode;
+            NotFound,
+        }
+
+        crate::constant_string_set! {
+            struct Set;
+            NotFound,
+        }
+
+        #[cfg(feature = "alloc")]
+        crate::constant_error!(
+            ConflictError,
+            ConflictErrorCode,
+            CONFLICT_ERROR_CODE,
+            "conflict",
+            status = 409,
+            message = "Entity exists",
+        );
+
+        #[cfg(feature = "alloc")]
+        crate::constant_tagged_enum! {
+            #[derive(::core::fmt::Debug)]
+            enum EntityError(tag = "code") {
+                Conflict(ConflictError) = ConflictErrorCode,
+            }
+        }
+
+        crate::constant_cstring!(Cstring, CSTRING, "cstring");
+
+        crate::constant_version!(Version, VERSION, "1.2.3");
+
+        #[cfg(feature = "async-graphql")]
+        crate::constant_graphql_enum! {
+            enum GraphQlEnum {
+                NotFound = NotFound,
+            }
+        }
+
+        #[cfg(feature = "http")]
+        crate::constant_header!(Header, HEADER, "x-header", "header");
+
+        #[cfg(feature = "icu_locale_core")]
+        crate::constant_language_tag!(LanguageTag, LANGUAGE_TAG, "en-US");
+
+        #[cfg(feature = "regex")]
+        crate::constant_regex!(Pattern, PATTERN, "^pattern$");
+
+        #[cfg(feature = "time")]
+        crate::constant_time_format!(TimeFormat, TIME_FORMAT, "[year]");
+
+        #[cfg(feature = "utoipa")]
+        crate::constant_enum_schema! {
+            struct EnumSchema;
+            NotFound,
+        }
+
+        #[cfg(feature = "utoipa")]
+        crate::constant_string_components! {
+            struct Components;
+            NotFound,
+        }
+
+        #[cfg(feature = "utoipa")]
+        crate::constant_response!(ConflictError, description = "Entity exists.");
+
+        fn value<C: MissingCode>() -> &'static str {
+            C::VALUE
+        }
+
+        #[test]
+        fn no_implicit_prelude() {
+            ::core::assert_eq!(value::<NotFound>(), NOT_FOUND);
+            ::core::assert_eq!(
+                <NotFound as crate::ConstantString>::description(),
+                "The entity was not found."
+            );
+            ::core::assert!(Missing::contains("not_found"));
+            ::core::assert_eq!(Version::MAJOR, 1);
+            ::core::assert_eq!(Cstring::C_STR.to_bytes(), CSTRING.as_bytes());
+
+            #[cfg(feature = "utoipa")]
+            {
+                let mut openapi = ::core::default::Default::default();
+                ::utoipa::Modify::modify(&Components, &mut openapi);
+                ::core::assert_eq!(
+                    openapi
+                        .components
+                        .map(|components| components.schemas.len()),
+                    ::core::option::Option::Some(1)
+                );
+                ::core::assert_eq!(<EnumSchema as ::utoipa::ToSchema>::name(), "EnumSchema");
+            }
+
+            #[cfg(feature = "alloc")]
+            ::core::assert_eq!(
+                ::core::result::Result::ok(::core::str::FromStr::from_str("conflict"))
+                    .map(|error: EntityError| error.code()),
+                ::core::option::Option::Some(CONFLICT_ERROR_CODE)
+            );
+        }
     }
 }
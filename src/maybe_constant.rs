@@ -0,0 +1,613 @@
+use core::ops::Deref;
+
+use crate::ConstantString;
+
+/// Constant string `C` that defaults to the constant when missing or `null`.
+///
+/// Combine with `#[serde(default)]` to accept omitted fields. Binary formats encode the constant as is.
+///
+/// # Example
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// # extern crate serde;
+/// #
+/// use constant_string::{MaybeConstant, constant_string};
+/// use serde::Deserialize;
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+///
+/// #[derive(Deserialize)]
+/// struct NotFoundError {
+///     #[serde(default)]
+///     code: MaybeConstant<NotFoundErrorCode>,
+/// }
+///
+/// let error: NotFoundError = serde_json::from_str("{}").expect("deserializable value");
+/// assert_eq!(*error.code, NotFoundErrorCode);
+///
+/// let error: NotFoundError = serde_json::from_str(r#"{"code":null}"#).expect("deserializable value");
+/// assert_eq!(*error.code, NotFoundErrorCode);
+/// ```
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct MaybeConstant<C>(pub C);
+
+impl<C: ConstantString> Clone for MaybeConstant<C> {
+    fn clone(&self) -> Self {
+        Self(C::default())
+    }
+}
+
+impl<C: ConstantString> Deref for MaybeConstant<C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<C: ConstantString> From<C> for MaybeConstant<C> {
+    fn from(value: C) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, C> arbitrary::Arbitrary<'a> for MaybeConstant<C>
+where
+    C: ConstantString + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        C::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        C::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arcstr")]
+impl<C> From<MaybeConstant<C>> for arcstr::ArcStr
+where
+    C: ConstantString + Into<arcstr::ArcStr>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "askama")]
+impl<C> core::fmt::Display for MaybeConstant<C>
+where
+    C: ConstantString + core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "clap")]
+impl<C> clap::builder::ValueParserFactory for MaybeConstant<C>
+where
+    C: ConstantString + clap::builder::ValueParserFactory + Send + Sync + 'static,
+    C::Parser: clap::builder::TypedValueParser<Value = C>,
+{
+    type Parser = clap::builder::MapValueParser<C::Parser, fn(C) -> Self>;
+
+    fn value_parser() -> Self::Parser {
+        use clap::builder::TypedValueParser;
+
+        C::value_parser().map(Self)
+    }
+}
+
+#[cfg(feature = "compact_str")]
+impl<C> From<MaybeConstant<C>> for compact_str::CompactString
+where
+    C: ConstantString + Into<compact_str::CompactString>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<C> defmt::Format for MaybeConstant<C>
+where
+    C: ConstantString + defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.0.format(f);
+    }
+}
+
+#[cfg(feature = "dioxus")]
+impl<C> dioxus_core::IntoAttributeValue for MaybeConstant<C>
+where
+    C: ConstantString + dioxus_core::IntoAttributeValue,
+{
+    fn into_value(self) -> dioxus_core::AttributeValue {
+        self.0.into_value()
+    }
+}
+
+#[cfg(feature = "dioxus")]
+impl<C> dioxus_core::IntoDynNode for MaybeConstant<C>
+where
+    C: ConstantString + dioxus_core::IntoDynNode,
+{
+    fn into_dyn_node(self) -> dioxus_core::DynamicNode {
+        self.0.into_dyn_node()
+    }
+}
+
+#[cfg(feature = "fake")]
+impl<C> fake::Dummy<fake::Faker> for MaybeConstant<C>
+where
+    C: ConstantString + fake::Dummy<fake::Faker>,
+{
+    fn dummy_with_rng<R: fake::RngExt + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        Self(C::dummy_with_rng(config, rng))
+    }
+}
+
+#[cfg(feature = "garde")]
+impl<C> garde::Validate for MaybeConstant<C>
+where
+    C: ConstantString + garde::Validate,
+{
+    type Context = C::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> garde::Path,
+        report: &mut garde::Report,
+    ) {
+        self.0.validate_into(ctx, parent, report);
+    }
+}
+
+#[cfg(feature = "http")]
+impl<C> From<MaybeConstant<C>> for http::HeaderValue
+where
+    C: ConstantString + Into<http::HeaderValue>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "implicit-clone")]
+impl<C> From<MaybeConstant<C>> for implicit_clone::unsync::IString
+where
+    C: ConstantString + Into<implicit_clone::unsync::IString>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "leptos")]
+impl<C> tachys::view::IntoRender for MaybeConstant<C>
+where
+    C: ConstantString + tachys::view::IntoRender,
+{
+    type Output = C::Output;
+
+    fn into_render(self) -> Self::Output {
+        self.0.into_render()
+    }
+}
+
+#[cfg(feature = "leptos")]
+impl<C> tachys::html::attribute::IntoAttributeValue for MaybeConstant<C>
+where
+    C: ConstantString + tachys::html::attribute::IntoAttributeValue,
+{
+    type Output = C::Output;
+
+    fn into_attribute_value(self) -> Self::Output {
+        self.0.into_attribute_value()
+    }
+}
+
+#[cfg(feature = "maud")]
+impl<C> maud::Render for MaybeConstant<C>
+where
+    C: ConstantString + maud::Render,
+{
+    fn render_to(&self, buffer: &mut String) {
+        self.0.render_to(buffer);
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl<C> From<MaybeConstant<C>> for metrics::SharedString
+where
+    C: ConstantString + Into<metrics::SharedString>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "napi")]
+impl<C> napi::bindgen_prelude::TypeName for MaybeConstant<C>
+where
+    C: ConstantString + napi::bindgen_prelude::TypeName,
+{
+    fn type_name() -> &'static str {
+        C::type_name()
+    }
+
+    fn value_type() -> napi::ValueType {
+        C::value_type()
+    }
+}
+
+#[cfg(feature = "napi")]
+#[allow(unsafe_code)]
+impl<C> napi::bindgen_prelude::ValidateNapiValue for MaybeConstant<C>
+where
+    C: ConstantString + napi::bindgen_prelude::ValidateNapiValue,
+{
+    unsafe fn validate(
+        env: napi::sys::napi_env,
+        napi_val: napi::sys::napi_value,
+    ) -> napi::Result<napi::sys::napi_value> {
+        // SAFETY: The caller guarantees that `env` and `napi_val` are valid.
+        unsafe { C::validate(env, napi_val) }
+    }
+}
+
+#[cfg(feature = "napi")]
+#[allow(unsafe_code)]
+impl<C> napi::bindgen_prelude::ToNapiValue for MaybeConstant<C>
+where
+    C: ConstantString + napi::bindgen_prelude::ToNapiValue,
+{
+    unsafe fn to_napi_value(
+        env: napi::sys::napi_env,
+        val: Self,
+    ) -> napi::Result<napi::sys::napi_value> {
+        // SAFETY: The caller guarantees that `env` is a valid environment.
+        unsafe { C::to_napi_value(env, val.0) }
+    }
+}
+
+#[cfg(feature = "napi")]
+#[allow(unsafe_code)]
+impl<C> napi::bindgen_prelude::FromNapiValue for MaybeConstant<C>
+where
+    C: ConstantString + napi::bindgen_prelude::FromNapiValue,
+{
+    unsafe fn from_napi_value(
+        env: napi::sys::napi_env,
+        napi_val: napi::sys::napi_value,
+    ) -> napi::Result<Self> {
+        // SAFETY: The caller guarantees that `env` and `napi_val` are valid.
+        unsafe { C::from_napi_value(env, napi_val) }.map(Self)
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+impl<C> From<MaybeConstant<C>> for opentelemetry::StringValue
+where
+    C: ConstantString + Into<opentelemetry::StringValue>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+impl<C> From<MaybeConstant<C>> for opentelemetry::Value
+where
+    C: ConstantString + Into<opentelemetry::Value>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "postcard-schema")]
+impl<C> postcard_schema::Schema for MaybeConstant<C>
+where
+    C: ConstantString + postcard_schema::Schema,
+{
+    const SCHEMA: &'static postcard_schema::schema::NamedType = C::SCHEMA;
+}
+
+#[cfg(feature = "proptest")]
+impl<C> proptest::arbitrary::Arbitrary for MaybeConstant<C>
+where
+    C: ConstantString + proptest::arbitrary::Arbitrary,
+{
+    type Parameters = C::Parameters;
+
+    type Strategy = proptest::strategy::Map<C::Strategy, fn(C) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        C::arbitrary_with(args).prop_map(Self)
+    }
+}
+
+#[cfg(feature = "pyo3")]
+impl<'py, C> pyo3::IntoPyObject<'py> for MaybeConstant<C>
+where
+    C: ConstantString + pyo3::IntoPyObject<'py>,
+{
+    type Target = <C as pyo3::IntoPyObject<'py>>::Target;
+
+    type Output = C::Output;
+
+    type Error = C::Error;
+
+    fn into_pyobject(self, py: pyo3::Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.0.into_pyobject(py)
+    }
+}
+
+#[cfg(feature = "pyo3")]
+impl<'a, 'py, C> pyo3::FromPyObject<'a, 'py> for MaybeConstant<C>
+where
+    C: ConstantString + pyo3::FromPyObject<'a, 'py>,
+{
+    type Error = C::Error;
+
+    fn extract(obj: pyo3::Borrowed<'a, 'py, pyo3::PyAny>) -> Result<Self, Self::Error> {
+        C::extract(obj).map(Self)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<C> quickcheck::Arbitrary for MaybeConstant<C>
+where
+    C: ConstantString + quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self(C::arbitrary(g))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<C> rand::distr::Distribution<MaybeConstant<C>> for rand::distr::StandardUniform
+where
+    C: ConstantString,
+    rand::distr::StandardUniform: rand::distr::Distribution<C>,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> MaybeConstant<C> {
+        MaybeConstant(self.sample(rng))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for MaybeConstant<C>
+where
+    C: ConstantString + serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for MaybeConstant<C>
+where
+    C: ConstantString + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Binary formats encode the constant as is, so `null` is only accepted from human-readable formats.
+        if deserializer.is_human_readable() {
+            crate::serde::deserialize_or_default(deserializer).map(Self)
+        } else {
+            C::deserialize(deserializer).map(Self)
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+impl<C> From<MaybeConstant<C>> for serde_yaml::Value
+where
+    C: ConstantString + Into<serde_yaml::Value>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "smol_str")]
+impl<C> From<MaybeConstant<C>> for smol_str::SmolStr
+where
+    C: ConstantString + Into<smol_str::SmolStr>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<C> From<MaybeConstant<C>> for toml::Value
+where
+    C: ConstantString + Into<toml::Value>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<C> ufmt::uDisplay for MaybeConstant<C>
+where
+    C: ConstantString + ufmt::uDisplay,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "ustr")]
+impl<C> From<MaybeConstant<C>> for ustr::Ustr
+where
+    C: ConstantString + Into<ustr::Ustr>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<C> utoipa::PartialSchema for MaybeConstant<C>
+where
+    C: ConstantString + utoipa::PartialSchema,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        C::schema()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<C> utoipa::ToSchema for MaybeConstant<C>
+where
+    C: ConstantString + utoipa::ToSchema,
+{
+    fn name() -> std::borrow::Cow<'static, str> {
+        C::name()
+    }
+}
+
+#[cfg(feature = "validator")]
+impl<C> validator::Validate for MaybeConstant<C>
+where
+    C: ConstantString + validator::Validate,
+{
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        self.0.validate()
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<C> valuable::Valuable for MaybeConstant<C>
+where
+    C: ConstantString + valuable::Valuable,
+{
+    fn as_value(&self) -> valuable::Value<'_> {
+        self.0.as_value()
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        self.0.visit(visit);
+    }
+}
+
+#[cfg(feature = "yew")]
+impl<C> yew::html::IntoPropValue<yew::virtual_dom::AttrValue> for MaybeConstant<C>
+where
+    C: ConstantString + yew::html::IntoPropValue<yew::virtual_dom::AttrValue>,
+{
+    fn into_prop_value(self) -> yew::virtual_dom::AttrValue {
+        self.0.into_prop_value()
+    }
+}
+
+#[cfg(feature = "yew")]
+impl<C> yew::html::IntoPropValue<Option<yew::virtual_dom::AttrValue>> for MaybeConstant<C>
+where
+    C: ConstantString + yew::html::IntoPropValue<Option<yew::virtual_dom::AttrValue>>,
+{
+    fn into_prop_value(self) -> Option<yew::virtual_dom::AttrValue> {
+        self.0.into_prop_value()
+    }
+}
+
+#[cfg(all(feature = "yew", not(feature = "askama")))]
+impl<C> From<MaybeConstant<C>> for yew::Html
+where
+    C: ConstantString + Into<yew::Html>,
+{
+    fn from(value: MaybeConstant<C>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde::{Deserialize, Serialize};
+
+        use super::MaybeConstant;
+        use crate::constant_string;
+
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Error {
+            #[serde(default)]
+            code: MaybeConstant<NotFound>,
+            #[serde(default = "crate::serde::default")]
+            plain_code: NotFound,
+            #[serde(default, deserialize_with = "crate::serde::deserialize_or_default")]
+            nullable_code: NotFound,
+        }
+
+        let error = Error {
+            code: MaybeConstant(NotFound),
+            plain_code: NotFound,
+            nullable_code: NotFound,
+        };
+
+        assert_eq!(
+            r#"{"code":"notFound","plain_code":"notFound","nullable_code":"notFound"}"#,
+            serde_json::to_string(&error).expect("serializable value")
+        );
+        for json in [
+            "{}",
+            r#"{"code":null,"nullable_code":null}"#,
+            r#"{"code":"notFound","plain_code":"notFound","nullable_code":"notFound"}"#,
+        ] {
+            assert_eq!(
+                error,
+                serde_json::from_str(json).expect("deserializable value")
+            );
+        }
+        assert!(serde_json::from_str::<Error>(r#"{"code":"other"}"#).is_err());
+        assert!(serde_json::from_str::<Error>(r#"{"plain_code":null}"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn binary() {
+        use super::MaybeConstant;
+        use crate::constant_string;
+
+        constant_string!(Plain, PLAIN, "plain");
+        constant_string!(Compact, COMPACT, "compact", compact);
+
+        let bytes = postcard::to_allocvec(&MaybeConstant(Plain)).expect("serializable value");
+        assert_eq!(
+            postcard::from_bytes::<MaybeConstant<Plain>>(&bytes).ok(),
+            Some(MaybeConstant(Plain))
+        );
+
+        let bytes = postcard::to_allocvec(&MaybeConstant(Compact)).expect("serializable value");
+        assert!(bytes.is_empty());
+        assert_eq!(
+            postcard::from_bytes::<MaybeConstant<Compact>>(&bytes).ok(),
+            Some(MaybeConstant(Compact))
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Metrics integration.
+//!
+//! Constant strings convert into [`SharedString`](metrics::SharedString) by borrowing the constant, so they can be
+//! used as label values in the [`metrics`] macros without allocating per observation.
+
+use metrics::Label;
+
+use crate::ConstantString;
+
+/// Label with the constant string `C` as value.
+///
+/// Both parts are borrowed, so the label can be created in a constant, e.g. for
+/// [`Key::from_static_labels`](metrics::Key::from_static_labels).
+pub const fn label<C: ConstantString>(key: &'static str) -> Label {
+    Label::from_static_parts(key, C::VALUE)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use metrics::{Key, Label, SharedString, counter};
+
+    use super::label;
+    use crate::{ConstantOr, ConstantString, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    static LABELS: [Label; 1] = [label::<NotFound>("code")];
+
+    #[test]
+    fn shared_string() {
+        for value in [
+            SharedString::from(NotFound),
+            SharedString::from(MaybeConstant(NotFound)),
+            SharedString::from(ConstantOr::<NotFound>::default()),
+        ] {
+            assert!(ptr::eq(&*value, NotFound::VALUE));
+        }
+        assert_eq!(
+            SharedString::from(ConstantOr::<NotFound>::Other("other".to_owned())),
+            SharedString::const_str("other")
+        );
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(Label::new("code", NotFound), LABELS[0]);
+        assert_eq!(LABELS[0].value(), "notFound");
+
+        let key = Key::from_static_labels("requests", &LABELS);
+        assert_eq!(key.labels().collect::<Vec<_>>(), [&LABELS[0]]);
+
+        counter!("requests", "code" => NotFound).increment(1);
+    }
+}
@@ -0,0 +1,80 @@
+//! Minicbor integration.
+//!
+//! Constant strings are encoded as a definite-length text string and the decoded value is validated against their
+//! [`Options`](crate::Options).
+
+use minicbor::{
+    Decoder, Encoder,
+    decode::Error as DecodeError,
+    encode::{Error as EncodeError, Write},
+};
+
+use crate::ConstantString;
+
+/// Encode the constant string `C` as a text string.
+///
+/// Used by the [`Encode`](minicbor::Encode) implementation of [`constant_string`](crate::constant_string).
+pub fn encode<C: ConstantString, W: Write>(
+    encoder: &mut Encoder<W>,
+) -> Result<(), EncodeError<W::Error>> {
+    encoder.str(C::VALUE)?;
+    Ok(())
+}
+
+/// Decode the constant string `C` from a text string, failing with a [`ParseError`](crate::ParseError) message on a
+/// mismatch.
+///
+/// Used by the [`Decode`](minicbor::Decode) implementation of [`constant_string`](crate::constant_string).
+pub fn decode<C: ConstantString>(decoder: &mut Decoder<'_>) -> Result<C, DecodeError> {
+    let position = decoder.position();
+
+    crate::parse(decoder.str()?).map_err(|error| DecodeError::message(error).at(position))
+}
+
+#[cfg(test)]
+mod tests {
+    use minicbor::{Decode, Encode};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(Debug, Decode, Encode, PartialEq)]
+    struct Error {
+        #[n(0)]
+        code: NotFound,
+        #[n(1)]
+        status: u16,
+    }
+
+    #[test]
+    fn encode() {
+        let error = Error {
+            code: NotFound,
+            status: 404,
+        };
+        let bytes = minicbor::to_vec(&error).expect("encodable value");
+
+        assert_eq!(bytes, b"\x82\x68notFound\x19\x01\x94");
+        assert_eq!(
+            minicbor::decode::<Error>(&bytes).expect("decodable value"),
+            error
+        );
+    }
+
+    #[test]
+    fn decode() {
+        let bytes = minicbor::to_vec("not_found").expect("encodable value");
+        assert!(minicbor::decode::<NotFound>(&bytes).is_ok());
+
+        let bytes = minicbor::to_vec(404).expect("encodable value");
+        assert!(minicbor::decode::<NotFound>(&bytes).is_err());
+
+        let bytes = minicbor::to_vec("missing").expect("encodable value");
+        let error = minicbor::decode::<NotFound>(&bytes).expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"decode error at position 0: invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Miniserde integration.
+//!
+//! Constant strings are serialized as their value and the deserialized value is validated against their
+//! [`Options`](crate::Options). Miniserde errors carry no message, so a mismatch fails without details.
+
+use alloc::borrow::Cow;
+
+use miniserde::{Result, de::Visitor, ser::Fragment};
+
+use crate::ConstantString;
+
+miniserde::make_place!(Place);
+
+/// Serialize the constant string `C` as a string.
+///
+/// Used by the [`Serialize`](miniserde::Serialize) implementation of [`constant_string`](crate::constant_string).
+pub fn serialize<C: ConstantString>() -> Fragment<'static> {
+    Fragment::Str(Cow::Borrowed(C::VALUE))
+}
+
+/// Begin deserializing the constant string `C` into `out`.
+///
+/// Used by the [`Deserialize`](miniserde::Deserialize) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn begin<C: ConstantString>(out: &mut Option<C>) -> &mut dyn Visitor {
+    Place::new(out)
+}
+
+impl<C: ConstantString> Visitor for Place<C> {
+    fn string(&mut self, s: &str) -> Result<()> {
+        self.out = Some(crate::parse(s).map_err(|_| miniserde::Error)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miniserde::{Deserialize, Serialize, json};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Error {
+        code: NotFound,
+        status: u16,
+    }
+
+    #[test]
+    fn miniserde() {
+        let error = Error {
+            code: NotFound,
+            status: 404,
+        };
+
+        assert_eq!(
+            json::to_string(&error),
+            r#"{"code":"notFound","status":404}"#
+        );
+        assert_eq!(
+            json::from_str::<Error>(r#"{"code":"not_found","status":404}"#).ok(),
+            Some(error)
+        );
+        assert!(json::from_str::<NotFound>(r#""missing""#).is_err());
+        assert!(json::from_str::<NotFound>("404").is_err());
+    }
+}
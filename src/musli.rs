@@ -0,0 +1,78 @@
+//! Müsli integration.
+//!
+//! Constant strings are encoded as a string in every mode and the decoded value is validated against their
+//! [`Options`](crate::Options).
+
+use musli::{Context, Decoder, Encoder};
+
+use crate::ConstantString;
+
+/// Encode the constant string `C` as a string.
+///
+/// Used by the [`Encode`](musli::Encode) implementation of [`constant_string`](crate::constant_string).
+pub fn encode<C: ConstantString, E: Encoder>(encoder: E) -> Result<(), E::Error> {
+    encoder.encode_string(C::VALUE)
+}
+
+/// Decode the constant string `C` from a string, failing with a [`ParseError`](crate::ParseError) on a mismatch.
+///
+/// Used by the [`Decode`](musli::Decode) implementation of [`constant_string`](crate::constant_string).
+pub fn decode<'de, C: ConstantString, D: Decoder<'de>>(decoder: D) -> Result<C, D::Error> {
+    let cx = decoder.cx();
+
+    decoder.decode_unsized(|value: &str| crate::parse(value).map_err(cx.map()))
+}
+
+#[cfg(test)]
+mod tests {
+    use musli::{Decode, Encode};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(Debug, Decode, Encode, PartialEq)]
+    struct Error {
+        code: NotFound,
+        status: u16,
+    }
+
+    #[test]
+    fn storage() {
+        let error = Error {
+            code: NotFound,
+            status: 404,
+        };
+        let bytes = musli::storage::to_vec(&error).expect("encodable value");
+
+        assert_eq!(
+            musli::storage::from_slice::<Error>(&bytes).expect("decodable value"),
+            error
+        );
+    }
+
+    #[test]
+    fn json() {
+        let error = Error {
+            code: NotFound,
+            status: 404,
+        };
+
+        assert_eq!(
+            musli::json::to_string(&error).expect("encodable value"),
+            r#"{"code":"notFound","status":404}"#
+        );
+        assert_eq!(
+            musli::json::from_str::<Error>(r#"{"code":"not_found","status":404}"#)
+                .expect("decodable value"),
+            error
+        );
+
+        let error =
+            musli::json::from_str::<NotFound>(r#""missing""#).expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
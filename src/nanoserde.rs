@@ -0,0 +1,130 @@
+//! Nanoserde integration.
+//!
+//! Constant strings are serialized as their value in the JSON, binary and RON formats, and the deserialized value is
+//! validated against their [`Options`](crate::Options). Binary errors only describe lengths, so a mismatch is reported
+//! as a length error at the offset of the string.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::Chars;
+
+use nanoserde::{
+    DeBin, DeBinErr, DeJson, DeJsonErr, DeJsonState, DeRon, DeRonErr, DeRonState, SerBin, SerJson,
+    SerJsonState, SerRon, SerRonState,
+};
+
+use crate::ConstantString;
+
+/// Serialize the constant string `C` as a JSON string.
+///
+/// Used by the [`SerJson`] implementation of [`constant_string`](crate::constant_string).
+pub fn ser_json<C: ConstantString>(indent_level: usize, state: &mut SerJsonState) {
+    C::VALUE.ser_json(indent_level, state);
+}
+
+/// Deserialize the constant string `C` from a JSON string, failing with a [`ParseError`](crate::ParseError) message on
+/// a mismatch.
+///
+/// Used by the [`DeJson`] implementation of [`constant_string`](crate::constant_string).
+pub fn de_json<C: ConstantString>(
+    state: &mut DeJsonState,
+    input: &mut Chars,
+) -> Result<C, DeJsonErr> {
+    let value = String::de_json(state, input)?;
+
+    crate::parse(&value).map_err(|error| state.err_parse(&error.to_string()))
+}
+
+/// Serialize the constant string `C` as a length-prefixed binary string.
+///
+/// Used by the [`SerBin`] implementation of [`constant_string`](crate::constant_string).
+pub fn ser_bin<C: ConstantString>(output: &mut Vec<u8>) {
+    C::VALUE.len().ser_bin(output);
+    output.extend_from_slice(C::VALUE.as_bytes());
+}
+
+/// Deserialize the constant string `C` from a length-prefixed binary string.
+///
+/// Used by the [`DeBin`] implementation of [`constant_string`](crate::constant_string).
+pub fn de_bin<C: ConstantString>(offset: &mut usize, bytes: &[u8]) -> Result<C, DeBinErr> {
+    let start = *offset;
+    let value = String::de_bin(offset, bytes)?;
+
+    crate::parse(&value).map_err(|_| DeBinErr::new(start, C::VALUE.len(), value.len()))
+}
+
+/// Serialize the constant string `C` as a RON string.
+///
+/// Used by the [`SerRon`] implementation of [`constant_string`](crate::constant_string).
+pub fn ser_ron<C: ConstantString>(indent_level: usize, state: &mut SerRonState) {
+    String::from(C::VALUE).ser_ron(indent_level, state);
+}
+
+/// Deserialize the constant string `C` from a RON string, failing with a [`ParseError`](crate::ParseError) message on
+/// a mismatch.
+///
+/// Used by the [`DeRon`] implementation of [`constant_string`](crate::constant_string).
+pub fn de_ron<C: ConstantString>(state: &mut DeRonState, input: &mut Chars) -> Result<C, DeRonErr> {
+    let value = String::de_ron(state, input)?;
+
+    crate::parse(&value).map_err(|error| state.err_parse(&error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(Debug, DeBin, DeJson, DeRon, PartialEq, SerBin, SerJson, SerRon)]
+    struct Error {
+        code: NotFound,
+        status: u16,
+    }
+
+    fn error() -> Error {
+        Error {
+            code: NotFound,
+            status: 404,
+        }
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            error().serialize_json(),
+            r#"{"code":"notFound","status":404}"#
+        );
+        assert_eq!(
+            Error::deserialize_json(r#"{"code":"not_found","status":404}"#).ok(),
+            Some(error())
+        );
+        assert!(
+            Error::deserialize_json(r#"{"code":"missing","status":404}"#)
+                .map_err(|error| error.to_string())
+                .err()
+                .is_some_and(|error| error.contains(
+                    r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+                ))
+        );
+    }
+
+    #[test]
+    fn bin() {
+        let bytes = error().serialize_bin();
+        assert_eq!(Error::deserialize_bin(&bytes).ok(), Some(error()));
+        assert!(NotFound::deserialize_bin(&"missing".to_owned().serialize_bin()).is_err());
+    }
+
+    #[test]
+    fn ron() {
+        let ron = error().serialize_ron();
+        assert!(ron.contains(r#"code:"notFound""#));
+        assert_eq!(Error::deserialize_ron(&ron).ok(), Some(error()));
+        assert!(NotFound::deserialize_ron(r#""missing""#).is_err());
+    }
+}
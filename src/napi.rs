@@ -0,0 +1,52 @@
+//! N-API integration.
+//!
+//! Constant strings convert into JavaScript strings and are converted from JavaScript strings, failing with
+//! [`Status::InvalidArg`] for other values. This allows structs with constant strings to be used as
+//! [`#[napi(object)]`](https://napi.rs/docs/concepts/object) fields and function arguments.
+
+use napi::{Error, Status};
+
+use crate::{ConstantString, parse};
+
+/// Parse the constant string `C` from a JavaScript string, applying its [`Options`](crate::Options).
+///
+/// Used by the [`FromNapiValue`](napi::bindgen_prelude::FromNapiValue) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn from_string<C: ConstantString>(value: &str) -> Result<C, Error> {
+    parse(value).map_err(|error| Error::new(Status::InvalidArg, error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use napi::{Status, ValueType, bindgen_prelude::ValidateNapiValue};
+
+    use super::from_string;
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    fn assert_validate_napi_value<T: ValidateNapiValue>() {
+        assert_eq!(T::type_name(), "String");
+        assert_eq!(T::value_type(), ValueType::String);
+    }
+
+    #[test]
+    fn type_name() {
+        assert_validate_napi_value::<NotFound>();
+        assert_validate_napi_value::<MaybeConstant<NotFound>>();
+        assert_validate_napi_value::<ConstantOr<NotFound>>();
+        assert_validate_napi_value::<Option<NotFound>>();
+    }
+
+    #[test]
+    fn from_napi_string() {
+        assert_eq!(from_string::<NotFound>("not_found").ok(), Some(NotFound));
+
+        let error = from_string::<NotFound>("other").expect_err("mismatched value");
+        assert_eq!(error.status, Status::InvalidArg);
+        assert_eq!(
+            error.reason,
+            r#"invalid value "other", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Okapi integration.
+//!
+//! Implements [`JsonSchema`](okapi::schemars::JsonSchema) from the schemars 0.8 re-exported by
+//! [`okapi`](https://docs.rs/okapi/latest/okapi/), which [`rocket_okapi`](https://docs.rs/rocket_okapi/latest/rocket_okapi/)
+//! uses to generate schemas.
+
+use crate::ConstantString;
+
+crate::schema::schemars_0_8_schema!(
+    /// JSON Schema of the constant string `C`, applying its [`Options`](crate::Options).
+    ///
+    /// Used by the [`JsonSchema`](okapi::schemars::JsonSchema) implementation of
+    /// [`constant_string`](crate::constant_string).
+    okapi::schemars
+);
+
+/// Schema name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`JsonSchema`](okapi::schemars::JsonSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> String {
+    C::OPTIONS.schema_name.unwrap_or(C::NAME).to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use okapi::schemars::{JsonSchema, r#gen::SchemaGenerator};
+    use serde_json::json;
+
+    use crate::constant_string;
+
+    #[test]
+    fn schema() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+        constant_string!(
+            Described,
+            DESCRIBED,
+            "described",
+            schema_const,
+            schema_length,
+            description = "Described constant.",
+            skip_schema_default,
+            skip_schema_examples,
+        );
+
+        let mut generator = SchemaGenerator::default();
+
+        assert_eq!(NotFound::schema_name(), "NotFound");
+        assert_eq!(
+            serde_json::to_value(generator.subschema_for::<NotFound>())
+                .expect("serializable value"),
+            json!({ "$ref": "#/definitions/NotFound" })
+        );
+        assert_eq!(
+            serde_json::to_value(&generator.definitions()["NotFound"]).expect("serializable value"),
+            json!({
+                "type": "string",
+                "enum": ["notFound"],
+                "default": "notFound",
+                "examples": ["notFound"],
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(super::schema::<Described>()).expect("serializable value"),
+            json!({
+                "type": "string",
+                "description": "Described constant.",
+                "const": "described",
+                "minLength": 9,
+                "maxLength": 9,
+            })
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use core::{fmt, ops::Deref};
+
+use crate::{ConstantString, parse::matches_constant};
+
+macro_rules! one_of {
+    ($(#[$meta:meta])* $name:ident, $($variant:ident),+) => {
+        $(#[$meta])*
+        #[derive(Debug, Eq, PartialEq)]
+        pub enum $name<$($variant),+> {
+            $(
+                #[doc = ::core::concat!("The constant string `", ::core::stringify!($variant), "`.")]
+                $variant($variant),
+            )+
+        }
+
+        impl<$($variant: ConstantString),+> $name<$($variant),+> {
+            /// Values of the members.
+            pub const ALL: &'static [&'static str] = &[$($variant::VALUE),+];
+
+            /// The string value.
+            #[inline]
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => $variant::VALUE),+
+                }
+            }
+
+            /// Member matching `value`, its value or one of its aliases under its options.
+            pub fn from_value(value: &str) -> Option<Self> {
+                $(
+                    if matches_constant::<$variant>(value) {
+                        return Some(Self::$variant($variant::default()));
+                    }
+                )+
+
+                None
+            }
+        }
+
+        impl<$($variant: ConstantString),+> Clone for $name<$($variant),+> {
+            fn clone(&self) -> Self {
+                match self {
+                    $(Self::$variant(_) => Self::$variant($variant::default())),+
+                }
+            }
+        }
+
+        impl<$($variant: ConstantString),+> Deref for $name<$($variant),+> {
+            type Target = str;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.as_str()
+            }
+        }
+
+        impl<$($variant: ConstantString),+> fmt::Display for $name<$($variant),+> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<$($variant: ConstantString),+> serde::Serialize for $name<$($variant),+> {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($variant: ConstantString),+> serde::Deserialize<'de> for $name<$($variant),+> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct Visitor<T>(core::marker::PhantomData<T>);
+
+                impl<'de, $($variant: ConstantString),+> serde::de::Visitor<'de>
+                    for Visitor<$name<$($variant),+>>
+                {
+                    type Value = $name<$($variant),+>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str("one of")?;
+                        for (index, value) in Self::Value::ALL.iter().enumerate() {
+                            formatter.write_str(if index == 0 { " " } else { ", " })?;
+                            write!(formatter, "{value:?}")?;
+                        }
+                        Ok(())
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Self::Value::from_value(v)
+                            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor(core::marker::PhantomData))
+            }
+        }
+
+        #[cfg(feature = "utoipa")]
+        impl<$($variant: ConstantString),+> utoipa::PartialSchema for $name<$($variant),+> {
+            fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+                crate::utoipa::enum_schema(Self::ALL.iter().copied())
+            }
+        }
+
+        #[cfg(feature = "utoipa")]
+        impl<$($variant),+> utoipa::ToSchema for $name<$($variant),+>
+        where
+            $($variant: ConstantString + utoipa::ToSchema),+
+        {
+            fn name() -> std::borrow::Cow<'static, str> {
+                [$($variant::name()),+].join("Or").into()
+            }
+        }
+    };
+}
+
+one_of!(
+    /// One of the constant strings `A` and `B`, recording which one.
+    ///
+    /// Deserialized from the value or an alias of a member under its options, trying members in order. See
+    /// [`OneOf3`] for three members.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "serde", doc = "```")]
+    #[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+    /// use constant_string::{OneOf2, constant_string};
+    ///
+    /// constant_string!(SummaryFormat, SUMMARY_FORMAT, "summary");
+    /// constant_string!(FullFormat, FULL_FORMAT, "full");
+    ///
+    /// type Format = OneOf2<SummaryFormat, FullFormat>;
+    ///
+    /// assert_eq!(
+    ///     serde_json::from_str::<Format>(r#""full""#).expect("deserializable value"),
+    ///     OneOf2::B(FullFormat)
+    /// );
+    /// assert!(serde_json::from_str::<Format>(r#""other""#).is_err());
+    /// ```
+    OneOf2,
+    A,
+    B
+);
+
+one_of!(
+    /// One of the constant strings `A`, `B` and `C`, recording which one.
+    ///
+    /// Deserialized from the value or an alias of a member under its options, trying members in order. See
+    /// [`OneOf2`] for an example.
+    OneOf3,
+    A,
+    B,
+    C
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{OneOf2, OneOf3};
+    use crate::constant_string;
+
+    constant_string!(Summary, SUMMARY, "summary", aliases = ["short"]);
+    constant_string!(Full, FULL, "full", case_insensitive);
+    constant_string!(Raw, RAW, "raw");
+
+    type Format = OneOf3<Summary, Full, Raw>;
+
+    #[test]
+    fn one_of() {
+        assert_eq!(Format::ALL, ["summary", "full", "raw"]);
+        assert_eq!(Format::from_value("short"), Some(OneOf3::A(Summary)));
+        assert_eq!(Format::from_value("FULL"), Some(OneOf3::B(Full)));
+        assert_eq!(Format::from_value("other"), None);
+        assert_eq!(OneOf3::<Summary, Full, Raw>::C(Raw).as_str(), "raw");
+        assert_eq!(OneOf2::<Summary, Full>::A(Summary).to_string(), "summary");
+        assert_eq!(OneOf2::<Summary, Full>::B(Full).clone(), OneOf2::B(Full));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        assert_eq!(to_string(&Format::C(Raw)).ok().as_deref(), Some(r#""raw""#));
+        assert_eq!(
+            from_str::<Format>(r#""short""#).ok(),
+            Some(OneOf3::A(Summary))
+        );
+        assert_eq!(
+            from_str::<Format>(r#""other""#)
+                .map_err(|error| error.to_string())
+                .err()
+                .as_deref(),
+            Some(
+                r#"invalid value: string "other", expected one of "summary", "full", "raw" at line 1 column 7"#
+            )
+        );
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(OneOf2::<Summary, Full>::name(), "SummaryOrFull");
+        assert_eq!(
+            serde_json::to_value(Format::schema())
+                .ok()
+                .and_then(|schema| schema.get("enum").cloned()),
+            Some(serde_json::json!(["summary", "full", "raw"]))
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use core::fmt;
+
+/// Function formatting a mismatch error from the expected and found value.
+pub type MismatchErrorFn =
+    fn(expected: &'static str, found: &str, formatter: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+/// Options for a constant string.
+///
+/// Options are passed as trailing arguments to [`constant_string`](crate::constant_string):
+/// - `key` sets a flag to `true`.
+/// - `key = value` sets an optional value.
+/// - `key = [a, b]` sets a list.
+/// - `key = { "a": b }` sets a map.
+///
+/// # Example
+/// ```
+/// # use constant_string::constant_string;
+/// #
+/// constant_string!(
+///     NotFoundErrorCode,
+///     NOT_FOUND_ERROR_CODE,
+///     "notFound",
+///     aliases = ["not_found"],
+///     case_insensitive,
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    /// Alternative values accepted on deserialization. Serialization and schemas always use the canonical value.
+    pub aliases: &'static [&'static str],
+
+    /// Accept case-insensitive matches on deserialization. Serialization always emits the canonical value.
+    pub case_insensitive: bool,
+
+    /// Serialize as a unit instead of a string for formats that are not human-readable, and encode nothing with
+    /// native `bincode` traits.
+    pub compact: bool,
+
+    /// Mark generated schemas as deprecated.
+    ///
+    /// Combine with a leading `#[deprecated]` attribute to also deprecate the type.
+    pub deprecated: bool,
+
+    /// Description of the constant, returned by [`ConstantString::description`](crate::ConstantString::description)
+    /// at runtime and used in generated schemas.
+    pub description: Option<&'static str>,
+
+    /// OpenAPI vendor extensions of generated schemas. Keys are prefixed with `x-` if they aren't already.
+    pub extensions: &'static [(&'static str, &'static str)],
+
+    /// Message describing the expected value in deserialization errors, replacing the generated description.
+    pub expecting: Option<&'static str>,
+
+    /// Accept any string on deserialization instead of failing on a mismatch.
+    pub lenient: bool,
+
+    /// Localized messages by language tag, returned by the `message(locale)` accessor.
+    ///
+    /// Set with `messages = { "en": "Entity not found", "de": "Entität nicht gefunden" }`, which requires the
+    /// `icu_locale_core` feature and fails to compile without it.
+    pub messages: &'static [(&'static str, &'static str)],
+
+    /// Format the error for a mismatch on deserialization.
+    ///
+    /// Set with `mismatch_error = f`, where `f` is a `fn(expected: &'static str, found: &str) -> impl Display`. The
+    /// error is built with [`serde::de::Error::custom`](https://docs.rs/serde/latest/serde/de/trait.Error.html#tymethod.custom).
+    pub mismatch_error: Option<MismatchErrorFn>,
+
+    /// Compare under [NFC](https://unicode.org/reports/tr15/) normalization on deserialization.
+    ///
+    /// Set with `nfc`, which requires the `unicode-normalization` feature and fails to compile without it.
+    pub nfc: bool,
+
+    /// Deserialize `null` as the constant. Serialization always emits the canonical value.
+    pub null: bool,
+
+    /// Called with the expected and found value when a [`lenient`](Self::lenient) deserialization mismatches.
+    pub on_mismatch: Option<fn(expected: &'static str, found: &str)>,
+
+    /// Name of the parameter in [`IntoParams`](https://docs.rs/utoipa/latest/utoipa/trait.IntoParams.html), instead of
+    /// the type name.
+    pub parameter_name: Option<&'static str>,
+
+    /// Mark generated schemas as read-only, for values only populated by the server.
+    pub read_only: bool,
+
+    /// Describe the schema with the OpenAPI 3.1 `const` keyword instead of a single-value `enum`.
+    pub schema_const: bool,
+
+    /// Set `minLength` and `maxLength` of generated schemas to the length of the constant, for validators that ignore
+    /// `enum` on strings.
+    pub schema_length: bool,
+
+    /// Component name of generated schemas, instead of the type name.
+    pub schema_name: Option<&'static str>,
+
+    /// Describe the constant in generated schemas with an anchored regular expression `pattern`, for validators
+    /// that don't support single-value enums.
+    pub schema_pattern: bool,
+
+    /// Omit the constant as `default` from generated schemas.
+    pub skip_schema_default: bool,
+
+    /// Omit the single-value `enum` (or `const`) from generated schemas, e.g. in combination with
+    /// [`schema_pattern`](Self::schema_pattern).
+    pub skip_schema_enum: bool,
+
+    /// Omit the constant from `examples` in generated schemas.
+    pub skip_schema_examples: bool,
+
+    /// Title of the constant in generated schemas.
+    pub title: Option<&'static str>,
+
+    /// Trim leading and trailing whitespace before comparing on deserialization.
+    pub trim: bool,
+
+    /// Accept any string on deserialization without comparing it, for input from trusted producers.
+    ///
+    /// The value must still be a string. Unlike [`lenient`](Self::lenient), no comparison is made at all.
+    pub unchecked: bool,
+
+    /// Mark generated schemas as write-only, for values only sent by the client.
+    pub write_only: bool,
+}
+
+impl Options {
+    /// Default options.
+    pub const DEFAULT: Self = Self {
+        aliases: &[],
+        case_insensitive: false,
+        compact: false,
+        deprecated: false,
+        description: None,
+        expecting: None,
+        extensions: &[],
+        lenient: false,
+        messages: &[],
+        mismatch_error: None,
+        nfc: false,
+        null: false,
+        on_mismatch: None,
+        parameter_name: None,
+        read_only: false,
+        schema_const: false,
+        schema_length: false,
+        schema_name: None,
+        schema_pattern: false,
+        skip_schema_default: false,
+        skip_schema_enum: false,
+        skip_schema_examples: false,
+        title: None,
+        trim: false,
+        unchecked: false,
+        write_only: false,
+    };
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Build [`Options`] from macro arguments.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_options {
+    (@fields [$($fields:tt)*]) => {{
+        #[allow(clippy::needless_update)]
+        const OPTIONS: $crate::Options = $crate::Options {
+            $($fields)*
+            ..$crate::Options::DEFAULT
+        };
+
+        OPTIONS
+    }};
+    (@fields [$($fields:tt)*] nfc $(, $($rest:tt)*)?) => {
+        $crate::constant_string_options!(@fields [$($fields)* nfc: $crate::constant_string_options_nfc!(),] $($($rest)*)?)
+    };
+    (@fields [$($fields:tt)*] $key:ident $(, $($rest:tt)*)?) => {
+        $crate::constant_string_options!(@fields [$($fields)* $key: true,] $($($rest)*)?)
+    };
+    (@fields [$($fields:tt)*] mismatch_error = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::constant_string_options!(
+            @fields [
+                $($fields)*
+                mismatch_error: ::core::option::Option::Some(|expected, found, formatter| {
+                    ::core::fmt::Display::fmt(&($value)(expected, found), formatter)
+                }),
+            ]
+            $($($rest)*)?
+        )
+    };
+    (@fields [$($fields:tt)*] messages = { $($k:literal : $v:expr),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::constant_string_options!(
+            @fields [$($fields)* messages: $crate::constant_string_options_messages!(&[$(($k, $v)),*]),]
+            $($($rest)*)?
+        )
+    };
+    (@fields [$($fields:tt)*] $key:ident = [$($item:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::constant_string_options!(@fields [$($fields)* $key: &[$($item),*],] $($($rest)*)?)
+    };
+    (@fields [$($fields:tt)*] $key:ident = { $($k:literal : $v:expr),* $(,)? } $(, $($rest:tt)*)?) => {
+        $crate::constant_string_options!(@fields [$($fields)* $key: &[$(($k, $v)),*],] $($($rest)*)?)
+    };
+    (@fields [$($fields:tt)*] $key:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::constant_string_options!(
+            @fields [$($fields)* $key: ::core::option::Option::Some($value),] $($($rest)*)?
+        )
+    };
+}
+
+/// Value of the `nfc` option.
+#[cfg(feature = "unicode-normalization")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_options_nfc {
+    () => {
+        true
+    };
+}
+
+/// Value of the `nfc` option, which requires the `unicode-normalization` feature.
+///
+/// ```compile_fail
+/// # use constant_string::constant_string;
+/// #
+/// constant_string!(Cafe, CAFE, "caf\u{e9}", nfc);
+/// ```
+#[cfg(not(feature = "unicode-normalization"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_options_nfc {
+    () => {
+        ::core::compile_error!("the `nfc` option requires the `unicode-normalization` feature")
+    };
+}
+
+/// Value of the `messages` option.
+#[cfg(feature = "icu_locale_core")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_options_messages {
+    ($messages:expr) => {
+        $messages
+    };
+}
+
+/// Value of the `messages` option, which requires the `icu_locale_core` feature.
+///
+/// ```compile_fail
+/// # use constant_string::constant_string;
+/// #
+/// constant_string!(NotFound, NOT_FOUND, "notFound", messages = { "en": "Entity not found" });
+/// ```
+#[cfg(not(feature = "icu_locale_core"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_options_messages {
+    ($messages:expr) => {
+        ::core::compile_error!("the `messages` option requires the `icu_locale_core` feature")
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConstantString, constant_string};
+
+    fn on_mismatch(_expected: &'static str, _found: &str) {}
+
+    constant_string!(Plain, PLAIN, "plain");
+    constant_string!(
+        Configured,
+        CONFIGURED,
+        "configured",
+        case_insensitive,
+        aliases = ["configured_v1", "configured_v2"],
+        on_mismatch = on_mismatch,
+        extensions = { "x-category": "client" },
+    );
+    #[cfg(feature = "unicode-normalization")]
+    constant_string!(Normalized, NORMALIZED, "caf\u{e9}", nfc);
+
+    #[test]
+    fn options() {
+        assert_eq!(Plain::OPTIONS.aliases, &[] as &[&str]);
+        const { assert!(!Plain::OPTIONS.case_insensitive) };
+        assert!(Plain::OPTIONS.on_mismatch.is_none());
+        assert!(Plain::OPTIONS.extensions.is_empty());
+
+        assert_eq!(
+            Configured::OPTIONS.aliases,
+            &["configured_v1", "configured_v2"]
+        );
+        const { assert!(Configured::OPTIONS.case_insensitive) };
+        assert!(Configured::OPTIONS.on_mismatch.is_some());
+        assert_eq!(Configured::OPTIONS.extensions, &[("x-category", "client")]);
+    }
+
+    #[test]
+    fn messages() {
+        assert!(Plain::OPTIONS.messages.is_empty());
+    }
+
+    #[test]
+    fn nfc() {
+        const { assert!(!Plain::OPTIONS.nfc) };
+
+        #[cfg(feature = "unicode-normalization")]
+        {
+            const { assert!(Normalized::OPTIONS.nfc) };
+            assert!(crate::parse::matches_constant::<Normalized>("cafe\u{301}"));
+        }
+    }
+}
@@ -0,0 +1,227 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+#[cfg(feature = "alloc")]
+use core::error::Error;
+#[cfg(any(feature = "alloc", feature = "serde"))]
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::MismatchErrorFn;
+use crate::{ConstantString, Options};
+
+/// Compare characters case-insensitively.
+pub(crate) fn eq_ignore_case(a: impl Iterator<Item = char>, b: impl Iterator<Item = char>) -> bool {
+    a.flat_map(char::to_lowercase)
+        .eq(b.flat_map(char::to_lowercase))
+}
+
+/// Whether `found` matches `expected`, applying the `case_insensitive`, `nfc` and `trim` options.
+pub(crate) fn matches(options: &Options, expected: &str, found: &str) -> bool {
+    let found = if options.trim { found.trim() } else { found };
+
+    #[cfg(feature = "unicode-normalization")]
+    if options.nfc {
+        use unicode_normalization::UnicodeNormalization;
+
+        return if options.case_insensitive {
+            eq_ignore_case(expected.nfc(), found.nfc())
+        } else {
+            expected.nfc().eq(found.nfc())
+        };
+    }
+
+    if options.case_insensitive {
+        eq_ignore_case(expected.chars(), found.chars())
+    } else {
+        expected == found
+    }
+}
+
+/// Describe the comparison applied by the `case_insensitive`, `nfc` and `trim` options after the expected value in
+/// error messages, e.g. ` (case-insensitive, trimmed)`.
+#[cfg(any(feature = "alloc", feature = "serde"))]
+pub(crate) fn describe(
+    case_insensitive: bool,
+    nfc: bool,
+    trim: bool,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let mut separator = " (";
+    for (_, comparison) in [
+        (case_insensitive, "case-insensitive"),
+        (nfc, "NFC-normalized"),
+        (trim, "trimmed"),
+    ]
+    .iter()
+    .filter(|(enabled, _)| *enabled)
+    {
+        write!(f, "{separator}{comparison}")?;
+        separator = ", ";
+    }
+
+    if separator == ", " {
+        f.write_str(")")
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether `found` matches the constant string `C` or one of its aliases.
+pub fn matches_constant<C: ConstantString>(found: &str) -> bool {
+    [C::VALUE]
+        .iter()
+        .chain(C::OPTIONS.aliases)
+        .any(|expected| matches(&C::OPTIONS, expected, found))
+}
+
+/// Parse the constant string `C` from `value`, applying its [`Options`].
+///
+/// Used by integrations decoding constant strings from databases and other non-serde sources.
+///
+/// # Example
+/// ```
+/// use constant_string::{constant_string, parse};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound", aliases = ["not_found"]);
+///
+/// assert!(parse::<NotFoundErrorCode>("not_found").is_ok());
+/// assert_eq!(
+///     parse::<NotFoundErrorCode>("missing").map_err(|error| error.to_string()),
+///     Err(r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFoundErrorCode"#.to_owned())
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse<C: ConstantString>(value: &str) -> Result<C, ParseError> {
+    let options = C::OPTIONS;
+
+    if options.unchecked || matches_constant::<C>(value) {
+        Ok(C::default())
+    } else if options.lenient {
+        if let Some(on_mismatch) = options.on_mismatch {
+            on_mismatch(C::VALUE, value);
+        }
+        Ok(C::default())
+    } else {
+        Err(ParseError {
+            name: C::NAME,
+            expected: C::VALUE,
+            aliases: options.aliases,
+            case_insensitive: options.case_insensitive,
+            expecting: options.expecting,
+            mismatch_error: options.mismatch_error,
+            nfc: options.nfc,
+            trim: options.trim,
+            found: value.to_owned(),
+        })
+    }
+}
+
+/// Error parsing a constant string from a value that doesn't match it.
+///
+/// Formatted with the [`mismatch_error`](Options::mismatch_error) and [`expecting`](Options::expecting) options of
+/// the constant string, if set.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ParseError {
+    name: &'static str,
+    expected: &'static str,
+    aliases: &'static [&'static str],
+    case_insensitive: bool,
+    expecting: Option<&'static str>,
+    mismatch_error: Option<MismatchErrorFn>,
+    nfc: bool,
+    trim: bool,
+    found: String,
+}
+
+#[cfg(feature = "alloc")]
+impl ParseError {
+    /// Name of the constant string type.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Expected constant string value.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Found value.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(mismatch_error) = self.mismatch_error {
+            return mismatch_error(self.expected, &self.found, f);
+        }
+
+        write!(f, "invalid value {:?}, expected ", self.found)?;
+        if let Some(expecting) = self.expecting {
+            return f.write_str(expecting);
+        }
+
+        write!(f, "constant {:?}", self.expected)?;
+        for alias in self.aliases {
+            write!(f, " or alias {alias:?}")?;
+        }
+        write!(f, " for {}", self.name)?;
+        describe(self.case_insensitive, self.nfc, self.trim, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::constant_string;
+
+    fn mismatch_error(expected: &'static str, found: &str) -> String {
+        format!("unknown code {found:?}, use {expected:?}")
+    }
+
+    #[test]
+    fn parse_options() {
+        constant_string!(NotFound, NOT_FOUND, "notFound", case_insensitive, trim);
+        constant_string!(Lenient, LENIENT, "lenient", lenient);
+        constant_string!(Custom, CUSTOM, "custom", mismatch_error = mismatch_error);
+
+        for value in ["notFound", "NOTFOUND", " notfound\n"] {
+            assert!(parse::<NotFound>(value).is_ok());
+        }
+        assert!(parse::<Lenient>("other").is_ok());
+
+        let error = parse::<NotFound>("not_found").expect_err("mismatched value");
+        assert_eq!(error.name(), "NotFound");
+        assert_eq!(error.expected(), "notFound");
+        assert_eq!(error.found(), "not_found");
+        assert_eq!(
+            error.to_string(),
+            r#"invalid value "not_found", expected constant "notFound" for NotFound (case-insensitive, trimmed)"#
+        );
+        assert_eq!(
+            parse::<Custom>("other").map_err(|error| error.to_string()),
+            Err(r#"unknown code "other", use "custom""#.to_owned())
+        );
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn parse_nfc() {
+        constant_string!(Cafe, CAFE, "caf\u{e9}", nfc);
+
+        assert!(parse::<Cafe>("cafe\u{301}").is_ok());
+        assert_eq!(
+            parse::<Cafe>("cafe").map_err(|error| error.to_string()),
+            Err(
+                "invalid value \"cafe\", expected constant \"caf\u{e9}\" for Cafe (NFC-normalized)"
+                    .to_owned()
+            )
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Poem OpenAPI integration.
+
+use std::borrow::Cow;
+
+use poem_openapi::{
+    registry::MetaSchema,
+    types::{ParseError, ParseResult, Type},
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{ConstantString, schema};
+
+/// Schema of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// OpenAPI 3.1 options, such as [`schema_const`](crate::Options::schema_const), and schema
+/// [`extensions`](crate::Options::extensions) are not supported by `poem-openapi` and are ignored. Used by the
+/// [`Type`] implementation of [`constant_string`](crate::constant_string).
+pub fn schema<C: ConstantString>() -> MetaSchema {
+    let options = C::OPTIONS;
+
+    MetaSchema {
+        title: options.title.map(ToOwned::to_owned),
+        description: options.description,
+        deprecated: options.deprecated,
+        default: (!options.skip_schema_default).then(|| C::VALUE.into()),
+        example: (!options.skip_schema_examples).then(|| C::VALUE.into()),
+        enum_items: if options.skip_schema_enum {
+            Vec::new()
+        } else {
+            vec![C::VALUE.into()]
+        },
+        read_only: options.read_only,
+        write_only: options.write_only,
+        pattern: options.schema_pattern.then(|| schema::pattern(C::VALUE)),
+        min_length: options.schema_length.then(|| schema::length(C::VALUE)),
+        max_length: options.schema_length.then(|| schema::length(C::VALUE)),
+        ..MetaSchema::new("string")
+    }
+}
+
+/// Component name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`Type`] implementation of [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> Cow<'static, str> {
+    Cow::Borrowed(C::OPTIONS.schema_name.unwrap_or(C::NAME))
+}
+
+/// Parse the constant string `C` from a JSON value, with its [`Deserialize`](serde::Deserialize) implementation.
+///
+/// A missing value is parsed as `null`. Used by the [`ParseFromJSON`](poem_openapi::types::ParseFromJSON)
+/// implementation of [`constant_string`](crate::constant_string).
+pub fn parse_from_json<C>(value: Option<Value>) -> ParseResult<C>
+where
+    C: ConstantString + DeserializeOwned + Type,
+{
+    serde_json::from_value(value.unwrap_or_default()).map_err(ParseError::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use poem_openapi::{
+        registry::{MetaSchemaRef, Registry},
+        types::{ParseFromJSON, ToJSON, Type},
+    };
+    use serde_json::{Value, json};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[test]
+    fn schema() {
+        assert_eq!(NotFound::name(), "NotFound");
+        assert_eq!(
+            NotFound::schema_ref(),
+            MetaSchemaRef::Reference("NotFound".to_owned())
+        );
+
+        let mut registry = Registry::new();
+        NotFound::register(&mut registry);
+
+        assert_eq!(
+            serde_json::to_value(&registry.schemas["NotFound"]).expect("serializable value"),
+            json!({
+                "type": "string",
+                "default": "notFound",
+                "enum": ["notFound"],
+                "example": "notFound",
+            })
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(NotFound.to_json(), Some(Value::from("notFound")));
+
+        for value in ["notFound", "not_found"] {
+            assert!(NotFound::parse_from_json(Some(value.into())).is_ok());
+        }
+        assert!(NotFound::parse_from_json(Some("other".into())).is_err());
+        assert!(NotFound::parse_from_json(None).is_err());
+    }
+}
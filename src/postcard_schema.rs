@@ -0,0 +1,98 @@
+//! Postcard schema integration.
+//!
+//! Schemas describe the wire format of the serde `Serialize` implementation: a string, or a unit with the
+//! [`compact`](crate::Options::compact) option, as postcard is not human-readable.
+
+use postcard_schema::schema::{DataModelType, NamedType};
+
+use crate::ConstantString;
+
+/// Schema of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`Schema`](postcard_schema::Schema) implementation of [`constant_string`](crate::constant_string).
+pub const fn named_type<C: ConstantString>() -> NamedType {
+    NamedType {
+        name: match C::OPTIONS.schema_name {
+            Some(name) => name,
+            None => C::NAME,
+        },
+        ty: if C::OPTIONS.compact {
+            &DataModelType::Unit
+        } else {
+            &DataModelType::String
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use postcard_schema::{
+        Schema,
+        schema::{DataModelType, NamedType, NamedValue},
+    };
+
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound");
+    constant_string!(
+        Compact,
+        COMPACT,
+        "compact",
+        compact,
+        schema_name = "CompactCode"
+    );
+
+    #[allow(dead_code)]
+    #[derive(Schema)]
+    struct Error {
+        code: NotFound,
+        compact: Compact,
+        other: ConstantOr<NotFound>,
+        maybe: MaybeConstant<NotFound>,
+    }
+
+    #[test]
+    fn schema() {
+        assert_eq!(
+            NotFound::SCHEMA,
+            &NamedType {
+                name: "NotFound",
+                ty: &DataModelType::String,
+            }
+        );
+        assert_eq!(
+            Compact::SCHEMA,
+            &NamedType {
+                name: "CompactCode",
+                ty: &DataModelType::Unit,
+            }
+        );
+        assert_eq!(
+            Error::SCHEMA,
+            &NamedType {
+                name: "Error",
+                ty: &DataModelType::Struct(&[
+                    &NamedValue {
+                        name: "code",
+                        ty: NotFound::SCHEMA,
+                    },
+                    &NamedValue {
+                        name: "compact",
+                        ty: Compact::SCHEMA,
+                    },
+                    &NamedValue {
+                        name: "other",
+                        ty: &NamedType {
+                            name: "ConstantOr<C>",
+                            ty: &DataModelType::String,
+                        },
+                    },
+                    &NamedValue {
+                        name: "maybe",
+                        ty: NotFound::SCHEMA,
+                    },
+                ]),
+            }
+        );
+    }
+}
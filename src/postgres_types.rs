@@ -0,0 +1,82 @@
+//! Postgres types integration.
+//!
+//! Constant strings are written as text and the stored value is validated against their
+//! [`Options`](crate::Options) when read.
+
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+use crate::ConstantString;
+
+/// Write the constant string `C` as text.
+///
+/// Used by the [`ToSql`] implementation of [`constant_string`](crate::constant_string).
+pub fn to_sql<C: ConstantString>(
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    C::VALUE.to_sql(ty, out)
+}
+
+/// Whether the constant string can be written to a value of type `ty`.
+///
+/// Used by the [`ToSql`] implementation of [`constant_string`](crate::constant_string).
+pub fn to_sql_accepts(ty: &Type) -> bool {
+    <&str as ToSql>::accepts(ty)
+}
+
+/// Read the constant string `C` from a text value, failing with a [`ParseError`](crate::ParseError) on a mismatch.
+///
+/// Used by the [`FromSql`] implementation of [`constant_string`](crate::constant_string).
+pub fn from_sql<C: ConstantString>(
+    ty: &Type,
+    raw: &[u8],
+) -> Result<C, Box<dyn Error + Sync + Send>> {
+    Ok(crate::parse(<&str as FromSql>::from_sql(ty, raw)?)?)
+}
+
+/// Whether the constant string can be read from a value of type `ty`.
+///
+/// Used by the [`FromSql`] implementation of [`constant_string`](crate::constant_string).
+pub fn from_sql_accepts(ty: &Type) -> bool {
+    <&str as FromSql>::accepts(ty)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[test]
+    fn to_sql() {
+        let mut out = BytesMut::new();
+
+        assert!(matches!(
+            NotFound.to_sql_checked(&Type::TEXT, &mut out),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(&out[..], b"notFound");
+        assert!(NotFound.to_sql_checked(&Type::INT4, &mut out).is_err());
+    }
+
+    #[test]
+    fn from_sql() {
+        for raw in [&b"notFound"[..], b"not_found"] {
+            assert!(NotFound::from_sql(&Type::VARCHAR, raw).is_ok());
+        }
+        assert!(<Option<NotFound>>::from_sql_null(&Type::TEXT).is_ok());
+        assert!(!<NotFound as FromSql>::accepts(&Type::INT4));
+
+        let error = NotFound::from_sql(&Type::TEXT, b"missing").expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use alloc::borrow::Cow;
+
+use crate::{ConstantError, ConstantErrorCode, ConstantString};
+
+/// Media type of problem details serialized as JSON.
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) problem details with the constant string `T` as the `type` URI.
+///
+/// Other members are optional and omitted from serialization when unset. Converting a [`ConstantError`] uses the
+/// status and default message of its code as `status` and `title`, and its message as `detail`.
+///
+/// # Example
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use constant_string::{ProblemDetails, constant_string};
+///
+/// constant_string!(OutOfCredit, OUT_OF_CREDIT, "https://example.com/probs/out-of-credit");
+///
+/// let problem = ProblemDetails::<OutOfCredit>::new()
+///     .with_status(403)
+///     .with_title("You do not have enough credit.")
+///     .with_detail("Your current balance is 30, but that costs 50.");
+///
+/// assert_eq!(
+///     serde_json::to_string(&problem).expect("serializable value"),
+///     r#"{"type":"https://example.com/probs/out-of-credit","title":"You do not have enough credit.","status":403,"detail":"Your current balance is 30, but that costs 50."}"#
+/// );
+/// ```
+#[derive(Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ProblemDetails<T> {
+    /// URI identifying the problem type.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub r#type: T,
+
+    /// Short, human-readable summary of the problem type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub title: Option<Cow<'static, str>>,
+
+    /// HTTP status code of this occurrence of the problem.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub status: Option<u16>,
+
+    /// Human-readable explanation specific to this occurrence of the problem.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub detail: Option<Cow<'static, str>>,
+
+    /// URI identifying this occurrence of the problem.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub instance: Option<Cow<'static, str>>,
+}
+
+impl<T: Default> ProblemDetails<T> {
+    /// Problem details with only the `type` URI.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T> ProblemDetails<T> {
+    /// Set the `status`.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the `title`.
+    pub fn with_title(mut self, title: impl Into<Cow<'static, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the `detail`.
+    pub fn with_detail(mut self, detail: impl Into<Cow<'static, str>>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Set the `instance`.
+    pub fn with_instance(mut self, instance: impl Into<Cow<'static, str>>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    /// HTTP status code, or `500 Internal Server Error` if unset.
+    pub fn status(&self) -> u16 {
+        self.status.unwrap_or(500)
+    }
+
+    /// Title, if set.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
+
+impl<T: ConstantString> Clone for ProblemDetails<T> {
+    fn clone(&self) -> Self {
+        Self {
+            r#type: T::default(),
+            title: self.title.clone(),
+            status: self.status,
+            detail: self.detail.clone(),
+            instance: self.instance.clone(),
+        }
+    }
+}
+
+impl<C: ConstantErrorCode> From<ConstantError<C>> for ProblemDetails<C> {
+    fn from(error: ConstantError<C>) -> Self {
+        Self::new()
+            .with_status(C::STATUS)
+            .with_title(C::MESSAGE)
+            .with_detail(error.message)
+    }
+}
+
+#[cfg(feature = "actix-web")]
+impl<T: serde::Serialize> actix_web::Responder for ProblemDetails<T> {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _: &actix_web::HttpRequest) -> actix_web::HttpResponse {
+        crate::actix_web::json_response(self.status(), PROBLEM_JSON, &self)
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<T> utoipa::PartialSchema for ProblemDetails<T>
+where
+    T: ConstantString + utoipa::PartialSchema,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::utoipa::problem_details_schema::<T>()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl<T> utoipa::ToSchema for ProblemDetails<T>
+where
+    T: ConstantString + utoipa::ToSchema,
+{
+    fn name() -> std::borrow::Cow<'static, str> {
+        format!("ProblemDetails_{}", T::name()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProblemDetails;
+    use crate::{constant_error, constant_string};
+
+    constant_string!(
+        OutOfCredit,
+        OUT_OF_CREDIT,
+        "https://example.com/probs/out-of-credit"
+    );
+    constant_error!(
+        NotFoundError,
+        NotFoundErrorCode,
+        NOT_FOUND_ERROR_CODE,
+        "https://example.com/probs/not-found",
+        status = 404,
+        message = "Entity not found",
+    );
+
+    #[test]
+    fn problem_details() {
+        let problem = ProblemDetails::<OutOfCredit>::new();
+        assert_eq!(problem.status(), 500);
+        assert_eq!(problem.title(), None);
+
+        let problem = problem.with_status(403).with_title("Not enough credit");
+        assert_eq!(problem.status(), 403);
+        assert_eq!(problem.title(), Some("Not enough credit"));
+
+        assert_eq!(
+            ProblemDetails::from(NotFoundError::with_message("Entity 42 not found")),
+            ProblemDetails::new()
+                .with_status(404)
+                .with_title("Entity not found")
+                .with_detail("Entity 42 not found")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        assert_eq!(
+            to_string(&ProblemDetails::<OutOfCredit>::new().with_instance("/account/12345"))
+                .ok()
+                .as_deref(),
+            Some(
+                r#"{"type":"https://example.com/probs/out-of-credit","instance":"/account/12345"}"#
+            )
+        );
+        assert_eq!(
+            from_str::<ProblemDetails<OutOfCredit>>(
+                r#"{"type":"https://example.com/probs/out-of-credit","status":403}"#
+            )
+            .ok(),
+            Some(ProblemDetails::new().with_status(403))
+        );
+        assert!(from_str::<ProblemDetails<OutOfCredit>>(r#"{"type":"about:blank"}"#).is_err());
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(
+            ProblemDetails::<OutOfCredit>::name(),
+            "ProblemDetails_OutOfCredit"
+        );
+        assert_eq!(
+            serde_json::to_value(ProblemDetails::<OutOfCredit>::schema())
+                .ok()
+                .and_then(|schema| schema.get("required").cloned()),
+            Some(serde_json::json!(["type"]))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Proptest integration.
+//!
+//! Constant strings implement [`Arbitrary`](proptest::arbitrary::Arbitrary) with a strategy always yielding the
+//! constant, so `any::<C>()` composes into payload strategies. Use [`constant_strategy`](crate::constant_strategy)
+//! for fields accepting a group of constants.
+
+use proptest::{
+    sample::{Select, select},
+    strategy::LazyJust,
+};
+
+use crate::ConstantString;
+
+/// Strategy always yielding the constant string `C`.
+///
+/// Used by the [`Arbitrary`](proptest::arbitrary::Arbitrary) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn constant<C: ConstantString>() -> LazyJust<C, fn() -> C> {
+    LazyJust::new(C::default)
+}
+
+/// Strategy picking one of the given constant values, for a field accepting a group of constants.
+///
+/// Used by [`constant_strategy`](crate::constant_strategy).
+pub fn one_of<const N: usize>(values: [&'static str; N]) -> Select<&'static str> {
+    select(values.to_vec())
+}
+
+/// Strategy picking the value of one of a group of constant string types.
+///
+/// Expands to a [`Strategy`](proptest::strategy::Strategy) of `&'static str`, shrinking towards the first member.
+///
+/// # Example
+/// ```
+/// # extern crate proptest;
+/// #
+/// use constant_string::{constant_strategy, constant_string};
+/// use proptest::{prelude::*, test_runner::TestRunner};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// #[derive(Debug)]
+/// struct Error {
+///     code: String,
+///     not_found: NotFoundErrorCode,
+/// }
+///
+/// let strategy = (
+///     constant_strategy!(NotFoundErrorCode, ConflictErrorCode),
+///     any::<NotFoundErrorCode>(),
+/// )
+///     .prop_map(|(code, not_found)| Error { code: code.to_owned(), not_found });
+///
+/// TestRunner::default()
+///     .run(&strategy, |error| {
+///         prop_assert!(["notFound", "conflict"].contains(&error.code.as_str()));
+///         prop_assert_eq!(error.not_found, NotFoundErrorCode);
+///         Ok(())
+///     })
+///     .expect("passing property");
+/// ```
+#[macro_export]
+macro_rules! constant_strategy {
+    ($($constant:ty),+ $(,)?) => {
+        $crate::proptest::one_of([$(<$constant as $crate::ConstantString>::VALUE),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Conflict, CONFLICT, "conflict");
+
+    proptest! {
+        #[test]
+        fn arbitrary(
+            constant in any::<NotFound>(),
+            maybe_constant in any::<MaybeConstant<NotFound>>(),
+            constant_or in any::<ConstantOr<NotFound>>(),
+        ) {
+            prop_assert_eq!(constant, NotFound);
+            prop_assert_eq!(maybe_constant.0, NotFound);
+            if let ConstantOr::Other(value) = constant_or {
+                prop_assert_ne!(value, "notFound");
+            }
+        }
+
+        #[test]
+        fn one_of(code in constant_strategy!(NotFound, Conflict)) {
+            prop_assert!(["notFound", "conflict"].contains(&code));
+        }
+    }
+
+    #[test]
+    fn shrink() {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = constant_strategy!(NotFound, Conflict)
+            .new_tree(&mut runner)
+            .expect("value tree");
+
+        while tree.simplify() {}
+        assert_eq!(tree.current(), "notFound");
+    }
+}
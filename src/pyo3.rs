@@ -0,0 +1,90 @@
+//! PyO3 integration.
+//!
+//! Constant strings convert into interned Python strings and are extracted from Python strings, raising a
+//! [`ValueError`](pyo3::exceptions::PyValueError) for other values.
+
+use pyo3::{
+    Borrowed, PyAny, PyErr,
+    exceptions::PyValueError,
+    types::{PyString, PyStringMethods},
+};
+
+use crate::{ConstantString, parse};
+
+/// Extract the constant string `C` from a Python string, applying its [`Options`](crate::Options).
+///
+/// Used by the [`FromPyObject`](pyo3::FromPyObject) implementation of [`constant_string`](crate::constant_string).
+pub fn extract<C: ConstantString>(obj: Borrowed<'_, '_, PyAny>) -> Result<C, PyErr> {
+    let value = obj.cast::<PyString>()?;
+    let value = value.to_cow()?;
+
+    parse(&value).map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::{
+        IntoPyObject, Python,
+        exceptions::{PyTypeError, PyValueError},
+        types::{PyAnyMethods, PyString},
+    };
+
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[test]
+    fn into_pyobject() {
+        Python::attach(|py| {
+            let value = NotFound.into_pyobject(py).expect("infallible conversion");
+            assert_eq!(value, "notFound");
+            assert!(value.is(NotFound.into_pyobject(py).expect("infallible conversion")));
+
+            assert_eq!(
+                MaybeConstant(NotFound)
+                    .into_pyobject(py)
+                    .expect("infallible conversion"),
+                "notFound"
+            );
+            assert_eq!(
+                ConstantOr::<NotFound>::Other("other".to_owned())
+                    .into_pyobject(py)
+                    .expect("infallible conversion"),
+                "other"
+            );
+        });
+    }
+
+    #[test]
+    fn extract() {
+        Python::attach(|py| {
+            let value = PyString::new(py, "not_found");
+            assert_eq!(value.extract::<NotFound>().expect("valid value"), NotFound);
+            assert_eq!(
+                value
+                    .extract::<MaybeConstant<NotFound>>()
+                    .expect("valid value")
+                    .0,
+                NotFound
+            );
+
+            let value = PyString::new(py, "other");
+            let error = value.extract::<NotFound>().expect_err("mismatched value");
+            assert!(error.is_instance_of::<PyValueError>(py));
+            assert_eq!(
+                error.value(py).to_string(),
+                r#"invalid value "other", expected constant "notFound" or alias "not_found" for NotFound"#
+            );
+            assert_eq!(
+                value
+                    .extract::<ConstantOr<NotFound>>()
+                    .expect("valid value"),
+                ConstantOr::Other("other".to_owned())
+            );
+
+            let value = 1_i32.into_pyobject(py).expect("infallible conversion");
+            let error = value.extract::<NotFound>().expect_err("mismatched type");
+            assert!(error.is_instance_of::<PyTypeError>(py));
+        });
+    }
+}
@@ -0,0 +1,94 @@
+//! QuickCheck integration.
+//!
+//! Constant strings implement [`Arbitrary`](quickcheck::Arbitrary), always generating the constant, and
+//! [`Clone`], which it requires. Use [`constant_choose`](crate::constant_choose) for fields accepting a group of
+//! constants.
+
+use quickcheck::Gen;
+
+/// Choose one of the given constant values, for a field accepting a group of constants.
+///
+/// Used by [`constant_choose`](crate::constant_choose).
+///
+/// # Panics
+/// Panics if `values` is empty.
+pub fn choose<const N: usize>(g: &mut Gen, values: [&'static str; N]) -> &'static str {
+    g.choose(&values).copied().expect("non-empty values")
+}
+
+/// Choose the value of one of a group of constant string types.
+///
+/// # Example
+/// ```
+/// # extern crate quickcheck;
+/// #
+/// use constant_string::{constant_choose, constant_string};
+/// use quickcheck::{Arbitrary, Gen};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// #[derive(Clone, Debug)]
+/// struct Error {
+///     code: String,
+///     not_found: NotFoundErrorCode,
+/// }
+///
+/// impl Arbitrary for Error {
+///     fn arbitrary(g: &mut Gen) -> Self {
+///         Self {
+///             code: constant_choose!(g, NotFoundErrorCode, ConflictErrorCode).to_owned(),
+///             not_found: NotFoundErrorCode::arbitrary(g),
+///         }
+///     }
+/// }
+///
+/// let error = Error::arbitrary(&mut Gen::new(10));
+/// assert!(["notFound", "conflict"].contains(&error.code.as_str()));
+/// ```
+#[macro_export]
+macro_rules! constant_choose {
+    ($g:expr, $($constant:ty),+ $(,)?) => {
+        $crate::quickcheck::choose($g, [$(<$constant as $crate::ConstantString>::VALUE),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Conflict, CONFLICT, "conflict");
+
+    quickcheck! {
+        fn arbitrary(
+            constant: NotFound,
+            maybe_constant: MaybeConstant<NotFound>,
+            constant_or: ConstantOr<NotFound>
+        ) -> bool {
+            constant == NotFound
+                && maybe_constant.0 == NotFound
+                && (constant_or.is_constant() || constant_or.as_str() != "notFound")
+        }
+    }
+
+    #[test]
+    fn choose() {
+        let mut g = Gen::new(10);
+
+        for _ in 0..10 {
+            assert!(
+                ["notFound", "conflict"].contains(&constant_choose!(&mut g, NotFound, Conflict))
+            );
+        }
+        assert_eq!(NotFound.shrink().count(), 0);
+        assert!(
+            ConstantOr::<NotFound>::Other("other".to_owned())
+                .shrink()
+                .next()
+                .is_some_and(|value| value.is_constant())
+        );
+    }
+}
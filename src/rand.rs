@@ -0,0 +1,110 @@
+//! Rand integration.
+//!
+//! Constant strings implement [`Distribution`] for [`StandardUniform`](rand::distr::StandardUniform), always
+//! sampling the constant, so `rng.random::<C>()` composes into fixture builders. Use [`OneOf`] and
+//! [`constant_distribution`](crate::constant_distribution) for fields accepting a group of constants.
+
+use rand::{Rng, distr::Distribution, seq::IndexedRandom};
+
+/// Sample one of the given constant values uniformly, for a field accepting a group of constants.
+///
+/// Used by the [`Distribution`] implementations of [`OneOf`].
+///
+/// # Panics
+/// Panics if `values` is empty.
+pub fn sample<R: Rng + ?Sized>(rng: &mut R, values: &[&'static str]) -> &'static str {
+    values.choose(rng).copied().expect("non-empty values")
+}
+
+/// Distribution sampling one of a group of constant values uniformly.
+///
+/// Samples [`String`] and `&'static str` values. Construct with [`constant_distribution`](crate::constant_distribution).
+#[derive(Clone, Copy, Debug)]
+pub struct OneOf<const N: usize>(pub [&'static str; N]);
+
+impl<const N: usize> Distribution<&'static str> for OneOf<N> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> &'static str {
+        sample(rng, &self.0)
+    }
+}
+
+impl<const N: usize> Distribution<String> for OneOf<N> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        sample(rng, &self.0).to_owned()
+    }
+}
+
+/// Define a [`OneOf`] distribution sampling the value of one of a group of constant string types.
+///
+/// # Example
+/// ```
+/// # extern crate rand;
+/// #
+/// use constant_string::{constant_distribution, constant_string};
+/// use rand::{Rng, RngExt};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// #[derive(Debug)]
+/// struct Error {
+///     code: String,
+///     not_found: NotFoundErrorCode,
+/// }
+///
+/// fn error<R: Rng>(rng: &mut R) -> Error {
+///     Error {
+///         code: rng.sample(constant_distribution!(NotFoundErrorCode, ConflictErrorCode)),
+///         not_found: rng.random(),
+///     }
+/// }
+///
+/// let error = error(&mut rand::rng());
+/// assert!(["notFound", "conflict"].contains(&error.code.as_str()));
+/// ```
+#[macro_export]
+macro_rules! constant_distribution {
+    ($($constant:ty),+ $(,)?) => {
+        $crate::rand::OneOf([$(<$constant as $crate::ConstantString>::VALUE),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{RngExt, SeedableRng, distr::Distribution, rngs::StdRng};
+
+    use super::sample;
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Conflict, CONFLICT, "conflict");
+
+    #[test]
+    fn standard_uniform() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(rng.random::<NotFound>(), NotFound);
+        assert_eq!(rng.random::<MaybeConstant<NotFound>>().0, NotFound);
+
+        let values = (0..100)
+            .map(|_| rng.random::<ConstantOr<NotFound>>())
+            .collect::<Vec<_>>();
+        assert!(values.iter().any(ConstantOr::is_constant));
+        assert!(values.iter().any(|value| !value.is_constant()));
+    }
+
+    #[test]
+    fn one_of() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let distribution = constant_distribution!(NotFound, Conflict);
+        assert_eq!(distribution.0, ["notFound", "conflict"]);
+
+        let values: Vec<&str> = distribution.sample_iter(&mut rng).take(100).collect();
+        assert!(values.contains(&"notFound"));
+        assert!(values.contains(&"conflict"));
+
+        let value: String = rng.sample(distribution);
+        assert!(["notFound", "conflict"].contains(&value.as_str()));
+        assert_eq!(sample(&mut rng, &["conflict"]), "conflict");
+    }
+}
@@ -0,0 +1,72 @@
+//! Redis integration.
+//!
+//! Constant strings are written as a single argument and replies are validated against their
+//! [`Options`](crate::Options) when read.
+
+use redis::{FromRedisValue, ParsingError, RedisWrite, Value};
+
+use crate::ConstantString;
+
+/// Write the constant string `C` as a single argument.
+///
+/// Used by the [`ToRedisArgs`](redis::ToRedisArgs) implementation of [`constant_string`](crate::constant_string).
+pub fn write_redis_args<C: ConstantString, W: ?Sized + RedisWrite>(out: &mut W) {
+    out.write_arg(C::VALUE.as_bytes());
+}
+
+/// Read the constant string `C` from a string compatible reply, failing with a
+/// [`ParseError`](crate::ParseError) message on a mismatch.
+///
+/// Used by the [`FromRedisValue`] implementation of [`constant_string`](crate::constant_string).
+pub fn from_redis_value<C: ConstantString>(value: &Value) -> Result<C, ParsingError> {
+    crate::parse(&String::from_redis_value_ref(value)?).map_err(|error| error.to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use redis::{FromRedisValue, ToRedisArgs, Value};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[test]
+    fn to_redis_args() {
+        assert_eq!(NotFound.to_redis_args(), vec![b"notFound".to_vec()]);
+        assert_eq!(
+            redis::cmd("XADD")
+                .arg("errors")
+                .arg("*")
+                .arg("code")
+                .arg(NotFound)
+                .get_packed_command(),
+            redis::cmd("XADD")
+                .arg("errors")
+                .arg("*")
+                .arg("code")
+                .arg("notFound")
+                .get_packed_command()
+        );
+    }
+
+    #[test]
+    fn from_redis_value() {
+        for value in [
+            Value::BulkString(b"notFound".to_vec()),
+            Value::SimpleString("not_found".to_owned()),
+        ] {
+            assert!(NotFound::from_redis_value(value).is_ok());
+        }
+        assert!(NotFound::from_redis_value(Value::Nil).is_err());
+        assert!(
+            <Option<NotFound>>::from_redis_value(Value::Nil).is_ok_and(|value| value.is_none())
+        );
+
+        let error = NotFound::from_redis_value_ref(&Value::BulkString(b"missing".to_vec()))
+            .expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"Incompatible type - invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
@@ -0,0 +1,624 @@
+//! Regex integration.
+
+use regex::Regex;
+
+/// Check the syntax of a regular expression pattern at compile time.
+///
+/// Verifies groups, flags and capture group names, character classes and their ranges, repetitions and escapes as
+/// parsed by [`Regex::new`]. Unicode property names, duplicate capture group names and size limits are only checked
+/// by [`compile`]. The `x` flag changes how the rest of the pattern is parsed, so the pattern is not checked after it.
+pub const fn check_syntax(pattern: &str) -> bool {
+    let bytes = pattern.as_bytes();
+    let mut index = 0;
+    let mut groups: usize = 0;
+    // Whether the previous item can be repeated.
+    let mut operand = false;
+
+    while index < bytes.len() {
+        index = match bytes[index] {
+            b'\\' => match escape(bytes, index + 1, false) {
+                Item::Literal(_, next) | Item::Other(next) => next,
+                Item::Invalid => return false,
+            },
+            b'[' => match class(bytes, index + 1) {
+                Some(next) => next,
+                None => return false,
+            },
+            b'(' => match group(bytes, index + 1) {
+                Group::Open(next) => {
+                    groups += 1;
+                    operand = false;
+                    index = next;
+                    continue;
+                }
+                Group::Flags(next) => {
+                    index = next;
+                    continue;
+                }
+                Group::Verbose => return true,
+                Group::Invalid => return false,
+            },
+            b')' => {
+                if groups == 0 {
+                    return false;
+                }
+                groups -= 1;
+                index + 1
+            }
+            b'|' => {
+                operand = false;
+                index += 1;
+                continue;
+            }
+            b'*' | b'+' | b'?' if operand => {
+                index += 1;
+                continue;
+            }
+            b'{' if operand => match repetition(bytes, index + 1) {
+                Some(next) => {
+                    index = next;
+                    continue;
+                }
+                None => return false,
+            },
+            b'*' | b'+' | b'?' | b'{' => return false,
+            _ => index + 1,
+        };
+        operand = true;
+    }
+
+    groups == 0
+}
+
+/// Item of a pattern.
+enum Item {
+    /// Literal character with its code point, followed by the index after it.
+    Literal(u32, usize),
+    /// Any other valid item, followed by the index after it.
+    Other(usize),
+    /// Invalid syntax.
+    Invalid,
+}
+
+/// Opening of a group.
+enum Group {
+    /// Group, followed by the index after its opening.
+    Open(usize),
+    /// Flags applying to the rest of the enclosing group, followed by the index after them.
+    Flags(usize),
+    /// Flags including `x`.
+    Verbose,
+    /// Invalid syntax.
+    Invalid,
+}
+
+/// Parse the literal character at `index`.
+const fn literal(bytes: &[u8], index: usize) -> Item {
+    let len = match bytes[index] {
+        0x00..0x80 => 1,
+        0xc0..0xe0 => 2,
+        0xe0..0xf0 => 3,
+        _ => 4,
+    };
+    if index + len > bytes.len() {
+        return Item::Invalid;
+    }
+
+    let mut code = match len {
+        1 => bytes[index] as u32,
+        2 => bytes[index] as u32 & 0x1f,
+        3 => bytes[index] as u32 & 0x0f,
+        _ => bytes[index] as u32 & 0x07,
+    };
+    let mut offset = 1;
+    while offset < len {
+        code = (code << 6) | (bytes[index + offset] as u32 & 0x3f);
+        offset += 1;
+    }
+
+    Item::Literal(code, index + len)
+}
+
+/// Parse the escape sequence after a backslash at `index`.
+const fn escape(bytes: &[u8], index: usize, in_class: bool) -> Item {
+    if index >= bytes.len() {
+        return Item::Invalid;
+    }
+
+    let next = index + 1;
+    match bytes[index] {
+        b'a' => Item::Literal(0x07, next),
+        b'f' => Item::Literal(0x0c, next),
+        b'n' => Item::Literal(0x0a, next),
+        b'r' => Item::Literal(0x0d, next),
+        b't' => Item::Literal(0x09, next),
+        b'v' => Item::Literal(0x0b, next),
+        b'x' => hex(bytes, next, 2),
+        b'u' => hex(bytes, next, 4),
+        b'U' => hex(bytes, next, 8),
+        b'd' | b'D' | b's' | b'S' | b'w' | b'W' => Item::Other(next),
+        b'p' | b'P' => property(bytes, next),
+        b'b' if !in_class => word_boundary(bytes, next),
+        b'A' | b'B' | b'z' | b'<' | b'>' if !in_class => Item::Other(next),
+        byte if byte.is_ascii()
+            && !byte.is_ascii_alphanumeric()
+            && byte != b'<'
+            && byte != b'>' =>
+        {
+            Item::Literal(byte as u32, next)
+        }
+        _ => Item::Invalid,
+    }
+}
+
+/// Parse a hexadecimal escape of `digits` digits or between braces at `index`.
+const fn hex(bytes: &[u8], mut index: usize, digits: usize) -> Item {
+    let braced = index < bytes.len() && bytes[index] == b'{';
+    if braced {
+        index += 1;
+    }
+
+    let mut code: u32 = 0;
+    let mut count = 0;
+    while !braced && count < digits || braced && (index >= bytes.len() || bytes[index] != b'}') {
+        if index >= bytes.len() {
+            return Item::Invalid;
+        }
+        let digit = match bytes[index] {
+            byte @ b'0'..=b'9' => byte - b'0',
+            byte @ b'a'..=b'f' => byte - b'a' + 10,
+            byte @ b'A'..=b'F' => byte - b'A' + 10,
+            _ => return Item::Invalid,
+        };
+        code = code * 16 + digit as u32;
+        if code > 0x10ffff {
+            return Item::Invalid;
+        }
+        count += 1;
+        index += 1;
+    }
+    if braced {
+        if count == 0 {
+            return Item::Invalid;
+        }
+        index += 1;
+    }
+
+    match code {
+        0xd800..0xe000 => Item::Invalid,
+        code => Item::Literal(code, index),
+    }
+}
+
+/// Parse the name of a Unicode property after `\p` or `\P` at `index`.
+const fn property(bytes: &[u8], mut index: usize) -> Item {
+    if index >= bytes.len() {
+        return Item::Invalid;
+    }
+    if bytes[index] != b'{' {
+        return match literal(bytes, index) {
+            Item::Literal(_, next) => Item::Other(next),
+            item => item,
+        };
+    }
+
+    while index < bytes.len() {
+        if bytes[index] == b'}' {
+            return Item::Other(index + 1);
+        }
+        index += 1;
+    }
+    Item::Invalid
+}
+
+/// Parse the special word boundary name after `\b` at `index`, if any.
+const fn word_boundary(bytes: &[u8], index: usize) -> Item {
+    const fn is_name(byte: u8) -> bool {
+        byte.is_ascii_alphabetic() || byte == b'-'
+    }
+
+    if index + 1 >= bytes.len() || bytes[index] != b'{' || !is_name(bytes[index + 1]) {
+        return Item::Other(index);
+    }
+
+    let start = index + 1;
+    let mut end = start;
+    while end < bytes.len() && is_name(bytes[end]) {
+        end += 1;
+    }
+    if end >= bytes.len() || bytes[end] != b'}' {
+        return Item::Invalid;
+    }
+
+    let names: [&[u8]; 4] = [b"start", b"end", b"start-half", b"end-half"];
+    let mut name = 0;
+    while name < names.len() {
+        if eq(bytes, start, end, names[name]) {
+            return Item::Other(end + 1);
+        }
+        name += 1;
+    }
+    Item::Invalid
+}
+
+/// Whether `bytes[start..end]` equals `other`.
+const fn eq(bytes: &[u8], start: usize, end: usize, other: &[u8]) -> bool {
+    if end - start != other.len() {
+        return false;
+    }
+
+    let mut index = 0;
+    while index < other.len() {
+        if bytes[start + index] != other[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Parse the opening of a group after `(` at `index`.
+const fn group(bytes: &[u8], mut index: usize) -> Group {
+    if index >= bytes.len() || bytes[index] != b'?' {
+        return Group::Open(index);
+    }
+    index += 1;
+
+    if index < bytes.len() && bytes[index] == b'<' {
+        return capture_name(bytes, index + 1);
+    }
+    if index + 1 < bytes.len() && bytes[index] == b'P' && bytes[index + 1] == b'<' {
+        return capture_name(bytes, index + 2);
+    }
+
+    let mut flags: u8 = 0;
+    let mut negated = false;
+    let mut dangling = false;
+    while index < bytes.len() {
+        let flag = match bytes[index] {
+            b'i' => 1,
+            b'm' => 2,
+            b's' => 4,
+            b'R' => 8,
+            b'U' => 16,
+            b'u' => 32,
+            b'x' => 64,
+            b'-' if !negated => {
+                negated = true;
+                dangling = true;
+                index += 1;
+                continue;
+            }
+            b':' if !dangling => return Group::Open(index + 1),
+            b')' if !dangling && (flags != 0 || negated) => return Group::Flags(index + 1),
+            _ => return Group::Invalid,
+        };
+        if flags & flag != 0 {
+            return Group::Invalid;
+        }
+        if flag == 64 && !negated {
+            return Group::Verbose;
+        }
+        flags |= flag;
+        dangling = false;
+        index += 1;
+    }
+    Group::Invalid
+}
+
+/// Parse a capture group name and its closing `>` at `index`.
+const fn capture_name(bytes: &[u8], mut index: usize) -> Group {
+    let start = index;
+    while index < bytes.len() && bytes[index] != b'>' {
+        let byte = bytes[index];
+        let valid = byte == b'_'
+            || byte.is_ascii_alphabetic()
+            || index > start && (byte.is_ascii_digit() || matches!(byte, b'.' | b'[' | b']'));
+        if byte.is_ascii() && !valid {
+            return Group::Invalid;
+        }
+        index += 1;
+    }
+
+    if index == start || index >= bytes.len() {
+        Group::Invalid
+    } else {
+        Group::Open(index + 1)
+    }
+}
+
+/// Parse a character class after `[` at `index`, returning the index after its closing `]`.
+const fn class(bytes: &[u8], mut index: usize) -> Option<usize> {
+    if index < bytes.len() && bytes[index] == b'^' {
+        index += 1;
+    }
+    // A `]` directly after `[` or `[^` is a literal, but not the start of a range.
+    if index < bytes.len() && bytes[index] == b']' {
+        index += 1;
+    }
+    // Code point of the previous literal, which a `-` turns into the start of a range.
+    let mut previous = None;
+
+    while index < bytes.len() {
+        let item = match bytes[index] {
+            b']' => return Some(index + 1),
+            b'[' => match class(bytes, index + 1) {
+                Some(next) => Item::Other(next),
+                None => return None,
+            },
+            b'\\' => escape(bytes, index + 1, true),
+            // Intersection, difference and symmetric difference.
+            byte @ (b'&' | b'-' | b'~') if index + 1 < bytes.len() && bytes[index + 1] == byte => {
+                Item::Other(index + 2)
+            }
+            b'-' if index + 1 < bytes.len() && bytes[index + 1] != b']' => {
+                if let Some(start) = previous {
+                    let end = if bytes[index + 1] == b'\\' {
+                        escape(bytes, index + 2, true)
+                    } else {
+                        literal(bytes, index + 1)
+                    };
+                    match end {
+                        Item::Literal(end, next) if start <= end => {
+                            previous = None;
+                            index = next;
+                            continue;
+                        }
+                        _ => return None,
+                    }
+                }
+                Item::Literal(b'-' as u32, index + 1)
+            }
+            _ => literal(bytes, index),
+        };
+
+        match item {
+            Item::Literal(code, next) => {
+                previous = Some(code);
+                index = next;
+            }
+            Item::Other(next) => {
+                previous = None;
+                index = next;
+            }
+            Item::Invalid => return None,
+        }
+    }
+    None
+}
+
+/// Parse a counted repetition after `{` at `index`, returning the index after its closing `}`.
+const fn repetition(bytes: &[u8], index: usize) -> Option<usize> {
+    let (min, index) = match decimal(bytes, space(bytes, index)) {
+        Some(decimal) => decimal,
+        None => return None,
+    };
+    let mut index = space(bytes, index);
+
+    if index < bytes.len() && bytes[index] == b',' {
+        index = space(bytes, index + 1);
+        if index < bytes.len() && bytes[index] != b'}' {
+            let max;
+            (max, index) = match decimal(bytes, index) {
+                Some(decimal) => decimal,
+                None => return None,
+            };
+            if min > max {
+                return None;
+            }
+            index = space(bytes, index);
+        }
+    }
+
+    if index < bytes.len() && bytes[index] == b'}' {
+        Some(index + 1)
+    } else {
+        None
+    }
+}
+
+/// Skip whitespace in a counted repetition at `index`, including any non-ASCII character.
+const fn space(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len() && (bytes[index].is_ascii_whitespace() || !bytes[index].is_ascii()) {
+        index += 1;
+    }
+    index
+}
+
+/// Parse a decimal number at `index`, returning it and the index after it.
+const fn decimal(bytes: &[u8], mut index: usize) -> Option<(u32, usize)> {
+    let start = index;
+    let mut value: u64 = 0;
+    while index < bytes.len() && bytes[index].is_ascii_digit() {
+        value = value * 10 + (bytes[index] - b'0') as u64;
+        if value > u32::MAX as u64 {
+            return None;
+        }
+        index += 1;
+    }
+
+    if index == start {
+        None
+    } else {
+        Some((value as u32, index))
+    }
+}
+
+/// Compile a constant regular expression pattern.
+///
+/// # Panics
+/// Panics if the pattern is not a valid regular expression.
+pub fn compile(pattern: &'static str) -> Regex {
+    Regex::new(pattern).expect("constant pattern should be a valid regular expression")
+}
+
+/// Implement a constant string containing a regular expression pattern.
+///
+/// The syntax of the pattern is checked at compile time by [`check_syntax`](crate::regex::check_syntax). A `regex()`
+/// associated function returns the compiled [`Regex`], which is compiled once on first use. All other behavior is
+/// identical to [`constant_string`](crate::constant_string).
+///
+/// # Panics
+/// `regex()` panics if the pattern fails to compile despite its syntax check, e.g. for an unknown Unicode property
+/// name, a duplicate capture group name or a pattern exceeding the size limit. Call it in a test to catch these.
+///
+/// # Example
+/// ```
+/// # use constant_string::constant_regex;
+/// #
+/// constant_regex!(SlugPattern, SLUG_PATTERN, "^[a-z0-9]+(?:-[a-z0-9]+)*$");
+///
+/// assert!(SlugPattern::regex().is_match("constant-string"));
+/// ```
+///
+/// Invalid patterns fail to compile:
+/// ```compile_fail
+/// # use constant_string::constant_regex;
+/// #
+/// constant_regex!(SlugPattern, SLUG_PATTERN, "^([a-z0-9]+$");
+/// ```
+///
+/// ```compile_fail
+/// # use constant_string::constant_regex;
+/// #
+/// constant_regex!(SlugPattern, SLUG_PATTERN, "*[a-z0-9]");
+/// ```
+#[macro_export]
+macro_rules! constant_regex {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        $crate::constant_string!($name, $code_name, $code);
+
+        const _: () = ::core::assert!(
+            $crate::regex::check_syntax($code),
+            ::core::concat!("invalid regular expression ", ::core::stringify!($code))
+        );
+
+        impl $name {
+            #[doc = ::core::concat!("Compiled regular expression for [`", ::core::stringify!($name), "`].")]
+            ///
+            /// # Panics
+            /// Panics if the pattern fails to compile, which its syntax check at compile time doesn't rule out.
+            pub fn regex() -> &'static $crate::__private::regex::Regex {
+                static REGEX: ::std::sync::LazyLock<$crate::__private::regex::Regex> =
+                    ::std::sync::LazyLock::new(|| $crate::regex::compile($code_name));
+
+                &REGEX
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use regex::Regex;
+
+    use super::check_syntax;
+
+    constant_regex!(SlugPattern, SLUG_PATTERN, "^[a-z0-9]+(?:-[a-z0-9]+)*$");
+
+    #[test]
+    fn syntax() {
+        for pattern in [
+            "^[a-z]+$",
+            r"^\(\)$",
+            "[])(]",
+            "[^]]",
+            "[a[b]c]",
+            "[[:alpha:]-]",
+            "[a-z-9]",
+            "[a&&b--c~~d]",
+            "[---]",
+            "a**?",
+            "a{2}{3,}?",
+            "a{ 2 , 3 }",
+            "^*$+",
+            r"\b*\b{2}\b{start-half}",
+            r"\x41\x{1F600}\u00e9\U0001F600\pL\p{Greek}",
+            r"\.\-\ \#\<\>",
+            "(?i)a(?-s:b)(?P<name>c)(?<other.1>d)",
+            "(?x) a * # comment (",
+            "a|",
+        ] {
+            assert!(check_syntax(pattern), "{pattern:?}");
+            assert!(Regex::new(pattern).is_ok(), "{pattern:?}");
+        }
+
+        for pattern in [
+            "(a",
+            "a)",
+            "[a-z",
+            "[]",
+            r"a\",
+            "*a",
+            "(+a)",
+            "a|?b",
+            "(?i)*a",
+            "{2}",
+            "a{",
+            "a{x}",
+            "a{,2}",
+            "a{3,2}",
+            "a{99999999999}",
+            "[z-a]",
+            r"[\x41-\x40]",
+            r"[a-\d]",
+            r"[\b]",
+            r"\q",
+            r"\1",
+            r"\x4",
+            r"\x{}",
+            r"\x{110000}",
+            r"\u{d800}",
+            r"\p",
+            r"\p{L",
+            r"\b{middle}",
+            "(?)",
+            "(?-)",
+            "(?i-)",
+            "(?ii)",
+            "(?q)",
+            "(?P=name)",
+            "(?<>a)",
+            "(?<1>a)",
+            "(?<a",
+        ] {
+            assert!(!check_syntax(pattern), "{pattern:?}");
+            assert!(Regex::new(pattern).is_err(), "{pattern:?}");
+        }
+    }
+
+    #[test]
+    fn syntax_matches_regex() {
+        const TOKENS: &[&str] = &[
+            "a", "Z", "0", "9", "_", " ", "\n", "\u{e9}", "(", ")", "[", "]", "{", "}", "*", "+",
+            "?", "|", "\\", "^", "$", ".", "-", "&", "~", ":", ",", "<", ">", "#", "=", "P", "i",
+            "x", "u", "b", "d", "p", "{2}", "(?",
+        ];
+
+        // Patterns of random tokens, rejected only if they are invalid.
+        let mut state: u32 = 0x2545_f491;
+        for _ in 0..20_000 {
+            let mut pattern = String::new();
+            for _ in 0..=(state % 8) {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                pattern.push_str(TOKENS[state as usize % TOKENS.len()]);
+            }
+
+            if !check_syntax(&pattern) {
+                assert!(Regex::new(&pattern).is_err(), "{pattern:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn constant_regex() {
+        assert_eq!(SlugPattern.deref(), "^[a-z0-9]+(?:-[a-z0-9]+)*$");
+        assert!(SlugPattern::regex().is_match("constant-string"));
+        assert!(!SlugPattern::regex().is_match("Constant String"));
+        assert!(std::ptr::eq(SlugPattern::regex(), SlugPattern::regex()));
+    }
+}
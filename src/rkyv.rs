@@ -0,0 +1,93 @@
+//! Rkyv integration.
+//!
+//! Constant strings are archived as an [`ArchivedString`], so archives are validated like any other string and can be
+//! accessed without deserializing. Deserialization validates the archived value against the
+//! [`Options`](crate::Options) of the constant string.
+
+use rkyv::{
+    Place, SerializeUnsized,
+    rancor::{Fallible, Source},
+    string::{ArchivedString, StringResolver},
+};
+
+use crate::ConstantString;
+
+/// Resolve the archived string of the constant string `C`.
+///
+/// Used by the [`Archive`](rkyv::Archive) implementation of [`constant_string`](crate::constant_string).
+pub fn resolve<C: ConstantString>(resolver: StringResolver, out: Place<ArchivedString>) {
+    ArchivedString::resolve_from_str(C::VALUE, resolver, out);
+}
+
+/// Serialize the constant string `C` as a string.
+///
+/// Used by the [`Serialize`](rkyv::Serialize) implementation of [`constant_string`](crate::constant_string).
+pub fn serialize<C: ConstantString, S: Fallible + ?Sized>(
+    serializer: &mut S,
+) -> Result<StringResolver, S::Error>
+where
+    S::Error: Source,
+    str: SerializeUnsized<S>,
+{
+    ArchivedString::serialize_from_str(C::VALUE, serializer)
+}
+
+/// Deserialize the constant string `C` from an archived string, failing with a [`ParseError`](crate::ParseError) on
+/// a mismatch.
+///
+/// Used by the [`Deserialize`](rkyv::Deserialize) implementation of [`constant_string`](crate::constant_string).
+pub fn deserialize<C: ConstantString, D: Fallible + ?Sized>(
+    archived: &ArchivedString,
+) -> Result<C, D::Error>
+where
+    D::Error: Source,
+{
+    crate::parse(archived.as_str()).map_err(Source::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::{Archive, Deserialize, Serialize, rancor};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(Archive, Debug, Deserialize, PartialEq, Serialize)]
+    struct Error {
+        code: NotFound,
+        message: String,
+    }
+
+    #[test]
+    fn archive() {
+        let error = Error {
+            code: NotFound,
+            message: "Not found.".to_owned(),
+        };
+        let bytes = rkyv::to_bytes::<rancor::Error>(&error).expect("serializable value");
+
+        let archived = rkyv::access::<ArchivedError, rancor::Error>(&bytes).expect("valid archive");
+        assert_eq!(archived.code.as_str(), "notFound");
+        assert_eq!(
+            rkyv::deserialize::<Error, rancor::Error>(archived).expect("deserializable value"),
+            error
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let bytes =
+            rkyv::to_bytes::<rancor::Error>(&"not_found".to_owned()).expect("serializable value");
+        assert!(rkyv::from_bytes::<NotFound, rancor::Error>(&bytes).is_ok());
+
+        let bytes =
+            rkyv::to_bytes::<rancor::Error>(&"missing".to_owned()).expect("serializable value");
+        let error =
+            rkyv::from_bytes::<NotFound, rancor::Error>(&bytes).expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
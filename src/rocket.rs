@@ -0,0 +1,136 @@
+//! Rocket integration.
+//!
+//! Constant strings can be used as path segments, query and form fields, and `uri!` arguments. A segment that
+//! doesn't match the constant fails [`FromParam`](rocket::request::FromParam), so the request is forwarded to the
+//! next matching route.
+
+use std::fmt;
+
+use rocket::{
+    form::{self, ValueField},
+    http::uri::fmt::{Formatter, Part},
+};
+
+use crate::{ConstantString, ParseError, parse};
+
+/// Parse the constant string `C` from a path segment, applying its [`Options`](crate::Options).
+///
+/// Used by the [`FromParam`](rocket::request::FromParam) implementation of [`constant_string`](crate::constant_string).
+pub fn from_param<C: ConstantString>(param: &str) -> Result<C, ParseError> {
+    parse(param)
+}
+
+/// Parse the constant string `C` from a form field, applying its [`Options`](crate::Options).
+///
+/// Used by the [`FromFormField`](rocket::form::FromFormField) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn from_value<'v, C: ConstantString>(field: ValueField<'v>) -> form::Result<'v, C> {
+    parse(field.value).map_err(|error| form::Error::validation(error.to_string()).into())
+}
+
+/// Write the constant string `C` to a URI, percent-encoded.
+///
+/// Used by the [`UriDisplay`](rocket::http::uri::fmt::UriDisplay) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn fmt<C: ConstantString, P: Part>(f: &mut Formatter<'_, P>) -> fmt::Result {
+    f.write_value(C::VALUE)
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::{
+        FromForm, Route,
+        form::Form,
+        get,
+        http::{ContentType, Status},
+        local::blocking::Client,
+        post, routes, uri,
+    };
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[get("/errors/<code>")]
+    fn error(code: NotFound) -> String {
+        String::from(&*code)
+    }
+
+    #[get("/errors/<_code>", rank = 2)]
+    fn other_error(_code: &str) -> &'static str {
+        "other"
+    }
+
+    #[get("/search?<code>")]
+    fn search(code: NotFound) -> String {
+        String::from(&*code)
+    }
+
+    #[derive(FromForm)]
+    struct Error {
+        code: NotFound,
+    }
+
+    #[post("/errors", data = "<error>")]
+    fn create_error(error: Form<Error>) -> String {
+        String::from(&*error.code)
+    }
+
+    fn client(routes: Vec<Route>) -> Client {
+        Client::untracked(rocket::build().mount("/", routes)).expect("valid rocket instance")
+    }
+
+    #[test]
+    fn from_param() {
+        let client = client(routes![error, other_error]);
+
+        for path in ["/errors/notFound", "/errors/not_found"] {
+            assert_eq!(
+                client.get(path).dispatch().into_string().as_deref(),
+                Some("notFound")
+            );
+        }
+        assert_eq!(
+            client
+                .get("/errors/other")
+                .dispatch()
+                .into_string()
+                .as_deref(),
+            Some("other")
+        );
+    }
+
+    #[test]
+    fn from_form_field() {
+        let client = client(routes![search, create_error]);
+
+        assert_eq!(
+            client
+                .get("/search?code=not_found")
+                .dispatch()
+                .into_string()
+                .as_deref(),
+            Some("notFound")
+        );
+        assert_eq!(
+            client.get("/search?code=other").dispatch().status(),
+            Status::UnprocessableEntity
+        );
+
+        let response = client
+            .post("/errors")
+            .header(ContentType::Form)
+            .body("code=notFound")
+            .dispatch();
+        assert_eq!(response.into_string().as_deref(), Some("notFound"));
+    }
+
+    #[test]
+    fn uri_display() {
+        assert_eq!(uri!(error(NotFound)).to_string(), "/errors/notFound");
+        assert_eq!(
+            uri!(search(code = NotFound)).to_string(),
+            "/search?code=notFound"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Rusqlite integration.
+//!
+//! Constant strings are written as text and the stored value is validated against their
+//! [`Options`](crate::Options) when read.
+
+use rusqlite::types::{FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
+
+use crate::ConstantString;
+
+/// Text value of the constant string `C`.
+///
+/// Used by the [`ToSql`](rusqlite::ToSql) implementation of [`constant_string`](crate::constant_string).
+pub fn to_sql<C: ConstantString>() -> ToSqlOutput<'static> {
+    ToSqlOutput::Borrowed(ValueRef::Text(C::VALUE.as_bytes()))
+}
+
+/// Read the constant string `C` from a text value, failing with a [`ParseError`](crate::ParseError) on a mismatch.
+///
+/// Used by the [`FromSql`](rusqlite::types::FromSql) implementation of [`constant_string`](crate::constant_string).
+pub fn column_result<C: ConstantString>(value: ValueRef<'_>) -> FromSqlResult<C> {
+    crate::parse(value.as_str()?).map_err(|error| FromSqlError::Other(Box::new(error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound");
+
+    #[test]
+    fn sqlite() {
+        let connection = Connection::open_in_memory().expect("in-memory database");
+
+        let (constant, text): (NotFound, String) = connection
+            .query_row("SELECT ?1, ?1", [NotFound], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .expect("decodable row");
+        assert_eq!(constant, NotFound);
+        assert_eq!(text, "notFound");
+
+        let error = connection
+            .query_row("SELECT 'missing'", [], |row| row.get::<_, NotFound>(0))
+            .expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"Conversion error from type Text at index: 0, invalid value "missing", expected constant "notFound" for NotFound"#
+        );
+        assert!(
+            connection
+                .query_row("SELECT 1", [], |row| row.get::<_, NotFound>(0))
+                .is_err()
+        );
+    }
+}
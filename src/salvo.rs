@@ -0,0 +1,117 @@
+//! Salvo integration.
+
+use salvo_oapi::{
+    BasicType, Components, Deprecated, Object, Ref, RefOr, Schema,
+    naming::{NameRule, assign_name},
+};
+
+use crate::{ConstantString, schema};
+
+/// Schema of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// The [`title`](crate::Options::title) option is not supported by `salvo-oapi` and is ignored.
+pub fn schema<C: ConstantString>() -> Schema {
+    let options = C::OPTIONS;
+    let mut object = Object::with_type(BasicType::String);
+
+    object.description = options.description.map(ToOwned::to_owned);
+    object.deprecated = options.deprecated.then_some(Deprecated::True);
+    object.default_value = (!options.skip_schema_default).then(|| C::VALUE.into());
+    if !options.skip_schema_examples {
+        object.examples = vec![C::VALUE.into()];
+    }
+    object.read_only = options.read_only.then_some(true);
+    object.write_only = options.write_only.then_some(true);
+    object.pattern = options.schema_pattern.then(|| schema::pattern(C::VALUE));
+    object.min_length = options.schema_length.then(|| schema::length(C::VALUE));
+    object.max_length = options.schema_length.then(|| schema::length(C::VALUE));
+    for (key, value) in options.extensions {
+        object
+            .extensions
+            .insert(schema::extension_key(key), (*value).into());
+    }
+
+    if !options.skip_schema_enum {
+        if options.schema_const {
+            object
+                .extensions
+                .insert("const".to_owned(), C::VALUE.into());
+        } else {
+            object.enum_values = Some(vec![C::VALUE.into()]);
+        }
+    }
+
+    object.into()
+}
+
+/// Component name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// The name is registered with the `salvo-oapi` namer, which keeps the first name assigned to a type.
+pub fn name<C: ConstantString + 'static>() -> String {
+    assign_name::<C>(NameRule::Force(C::OPTIONS.schema_name.unwrap_or(C::NAME)))
+}
+
+/// Register the schema of the constant string `C` in `components` and reference it.
+///
+/// Used by the [`ToSchema`](salvo_oapi::ToSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn to_schema<C: ConstantString + 'static>(components: &mut Components) -> RefOr<Schema> {
+    let name = name::<C>();
+    let reference = RefOr::Ref(Ref::from_schema_name(&name));
+
+    if !components.schemas.contains_key(&name) {
+        components.schemas.insert(name, schema::<C>());
+    }
+
+    reference
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo_oapi::{Components, ToSchema};
+    use serde_json::json;
+
+    use crate::constant_string;
+
+    #[test]
+    fn to_schema() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+        constant_string!(
+            Described,
+            DESCRIBED,
+            "described",
+            schema_const,
+            schema_name = "DescribedCode",
+            description = "Described constant.",
+            skip_schema_default,
+            skip_schema_examples,
+        );
+
+        let mut components = Components::new();
+
+        assert_eq!(
+            serde_json::to_value(NotFound::to_schema(&mut components)).expect("serializable value"),
+            json!({ "$ref": "#/components/schemas/NotFound" })
+        );
+        assert_eq!(
+            serde_json::to_value(Described::to_schema(&mut components))
+                .expect("serializable value"),
+            json!({ "$ref": "#/components/schemas/DescribedCode" })
+        );
+        assert_eq!(
+            serde_json::to_value(&components.schemas).expect("serializable value"),
+            json!({
+                "DescribedCode": {
+                    "type": "string",
+                    "description": "Described constant.",
+                    "const": "described",
+                },
+                "NotFound": {
+                    "type": "string",
+                    "default": "notFound",
+                    "enum": ["notFound"],
+                    "examples": ["notFound"],
+                },
+            })
+        );
+    }
+}
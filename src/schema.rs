@@ -0,0 +1,187 @@
+//! Helpers shared by schema integrations.
+
+#[cfg(any(feature = "aide", feature = "schemars"))]
+use serde_json::{Map, Value};
+
+#[cfg(any(feature = "aide", feature = "schemars"))]
+use crate::ConstantString;
+
+/// Anchored regular expression matching exactly `value`.
+pub(crate) fn pattern(value: &str) -> String {
+    let mut pattern = String::with_capacity(value.len() + 2);
+
+    pattern.push('^');
+    for c in value.chars() {
+        if matches!(
+            c,
+            '\\' | '.'
+                | '+'
+                | '*'
+                | '?'
+                | '('
+                | ')'
+                | '|'
+                | '['
+                | ']'
+                | '{'
+                | '}'
+                | '^'
+                | '$'
+                | '/'
+        ) {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('$');
+
+    pattern
+}
+
+/// Length of `value` as defined by JSON Schema, in Unicode code points.
+pub(crate) fn length(value: &str) -> usize {
+    value.chars().count()
+}
+
+/// Key of a vendor extension, prefixed with `x-` if it isn't already.
+#[cfg(any(
+    feature = "aide",
+    feature = "apistos",
+    feature = "okapi",
+    feature = "salvo",
+    feature = "schemars"
+))]
+pub(crate) fn extension_key(key: &str) -> String {
+    if key.starts_with("x-") {
+        key.to_owned()
+    } else {
+        format!("x-{key}")
+    }
+}
+
+/// JSON Schema of the constant string `C` as a JSON object, applying its [`Options`](crate::Options).
+#[cfg(any(feature = "aide", feature = "schemars"))]
+pub(crate) fn json<C: ConstantString>() -> Map<String, Value> {
+    let options = C::OPTIONS;
+    let mut schema = Map::new();
+
+    schema.insert("type".to_owned(), "string".into());
+    if let Some(title) = options.title {
+        schema.insert("title".to_owned(), title.into());
+    }
+    if let Some(description) = options.description {
+        schema.insert("description".to_owned(), description.into());
+    }
+    if options.deprecated {
+        schema.insert("deprecated".to_owned(), true.into());
+    }
+    if !options.skip_schema_enum {
+        if options.schema_const {
+            schema.insert("const".to_owned(), C::VALUE.into());
+        } else {
+            schema.insert("enum".to_owned(), Value::from([C::VALUE]));
+        }
+    }
+    if !options.skip_schema_default {
+        schema.insert("default".to_owned(), C::VALUE.into());
+    }
+    if !options.skip_schema_examples {
+        schema.insert("examples".to_owned(), Value::from([C::VALUE]));
+    }
+    if options.read_only {
+        schema.insert("readOnly".to_owned(), true.into());
+    }
+    if options.write_only {
+        schema.insert("writeOnly".to_owned(), true.into());
+    }
+    if options.schema_pattern {
+        schema.insert("pattern".to_owned(), pattern(C::VALUE).into());
+    }
+    if options.schema_length {
+        schema.insert("minLength".to_owned(), length(C::VALUE).into());
+        schema.insert("maxLength".to_owned(), length(C::VALUE).into());
+    }
+    for (key, value) in options.extensions {
+        schema.insert(extension_key(key), (*value).into());
+    }
+
+    schema
+}
+
+/// Define `schema<C>()`, building the JSON Schema of a constant string with the schemars 0.8 at the given path.
+///
+/// Shared by integrations re-exporting a schemars 0.8, or a fork of it.
+#[cfg(any(feature = "apistos", feature = "okapi"))]
+macro_rules! schemars_0_8_schema {
+    ($(#[$meta:meta])* $($schemars:ident)::+) => {
+        $(#[$meta])*
+        pub fn schema<C: crate::ConstantString>() -> $($schemars)::+::schema::Schema {
+            let options = C::OPTIONS;
+            let length = options
+                .schema_length
+                .then(|| u32::try_from(crate::schema::length(C::VALUE)).ok())
+                .flatten();
+
+            let mut object = $($schemars)::+::schema::SchemaObject {
+                metadata: Some(Box::new($($schemars)::+::schema::Metadata {
+                    title: options.title.map(ToOwned::to_owned),
+                    description: options.description.map(ToOwned::to_owned),
+                    default: (!options.skip_schema_default).then(|| C::VALUE.into()),
+                    deprecated: options.deprecated,
+                    read_only: options.read_only,
+                    write_only: options.write_only,
+                    examples: if options.skip_schema_examples {
+                        Vec::new()
+                    } else {
+                        vec![C::VALUE.into()]
+                    },
+                    ..Default::default()
+                })),
+                instance_type: Some($($schemars)::+::schema::InstanceType::String.into()),
+                string: Some(Box::new($($schemars)::+::schema::StringValidation {
+                    max_length: length,
+                    min_length: length,
+                    pattern: options.schema_pattern.then(|| crate::schema::pattern(C::VALUE)),
+                })),
+                ..Default::default()
+            };
+
+            if !options.skip_schema_enum {
+                if options.schema_const {
+                    object.const_value = Some(C::VALUE.into());
+                } else {
+                    object.enum_values = Some(vec![C::VALUE.into()]);
+                }
+            }
+            for (key, value) in options.extensions {
+                object
+                    .extensions
+                    .insert(crate::schema::extension_key(key), (*value).into());
+            }
+
+            object.into()
+        }
+    };
+}
+
+#[cfg(any(feature = "apistos", feature = "okapi"))]
+pub(crate) use schemars_0_8_schema;
+
+#[cfg(test)]
+mod tests {
+    use super::{length, pattern};
+
+    #[test]
+    fn code_point_length() {
+        assert_eq!(length("notFound"), 8);
+        assert_eq!(length("café"), 4);
+    }
+
+    #[test]
+    fn anchored_pattern() {
+        assert_eq!(pattern("notFound"), "^notFound$");
+        assert_eq!(pattern("user.created"), "^user\\.created$");
+        assert_eq!(pattern("a+b (c)?"), "^a\\+b \\(c\\)\\?$");
+        assert_eq!(pattern("application/json"), "^application\\/json$");
+    }
+}
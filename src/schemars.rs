@@ -0,0 +1,69 @@
+//! Schemars integration.
+
+use std::borrow::Cow;
+
+use schemars::Schema;
+
+use crate::{ConstantString, schema};
+
+/// JSON Schema of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`JsonSchema`](schemars::JsonSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn schema<C: ConstantString>() -> Schema {
+    schema::json::<C>().into()
+}
+
+/// Schema name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`JsonSchema`](schemars::JsonSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> Cow<'static, str> {
+    Cow::Borrowed(C::OPTIONS.schema_name.unwrap_or(C::NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::{JsonSchema, schema_for};
+    use serde_json::json;
+
+    use crate::constant_string;
+
+    #[test]
+    fn schema() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+        constant_string!(
+            Described,
+            DESCRIBED,
+            "described",
+            schema_const,
+            schema_name = "DescribedCode",
+            description = "Described constant.",
+            skip_schema_default,
+            skip_schema_examples,
+            extensions = { "category": "client" },
+        );
+
+        assert_eq!(NotFound::schema_name(), "NotFound");
+        assert_eq!(
+            schema_for!(NotFound),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "NotFound",
+                "type": "string",
+                "enum": ["notFound"],
+                "default": "notFound",
+                "examples": ["notFound"],
+            })
+        );
+
+        assert_eq!(Described::schema_name(), "DescribedCode");
+        assert_eq!(
+            super::schema::<Described>(),
+            json!({
+                "type": "string",
+                "description": "Described constant.",
+                "const": "described",
+                "x-category": "client",
+            })
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! ScyllaDB integration.
+//!
+//! Implements the value traits from [`scylla-cql-core`](https://docs.rs/scylla-cql-core/latest/scylla_cql_core/),
+//! which the [`scylla`](https://docs.rs/scylla/latest/scylla/) driver re-exports. Constant strings are written to
+//! `ascii` and `text` columns, and the stored value is validated against their [`Options`](crate::Options) when read.
+
+use scylla_cql_core::{
+    deserialize::{DeserializationError, FrameSlice, TypeCheckError, value::DeserializeValue},
+    frame::response::result::ColumnType,
+    serialize::{
+        SerializationError,
+        value::SerializeValue,
+        writers::{CellWriter, WrittenCellProof},
+    },
+};
+
+use crate::ConstantString;
+
+/// Serialize the constant string `C` as text.
+///
+/// Used by the [`SerializeValue`] implementation of [`constant_string`](crate::constant_string).
+pub fn serialize<'b, C: ConstantString>(
+    typ: &ColumnType,
+    writer: CellWriter<'b>,
+) -> Result<WrittenCellProof<'b>, SerializationError> {
+    C::VALUE.serialize(typ, writer)
+}
+
+/// Check that the column type `typ` is a string type.
+///
+/// Used by the [`DeserializeValue`] implementation of [`constant_string`](crate::constant_string).
+pub fn type_check(typ: &ColumnType) -> Result<(), TypeCheckError> {
+    <&str as DeserializeValue>::type_check(typ)
+}
+
+/// Deserialize the constant string `C` from a string value, failing with a [`ParseError`](crate::ParseError) on a
+/// mismatch.
+///
+/// Used by the [`DeserializeValue`] implementation of [`constant_string`](crate::constant_string).
+pub fn deserialize<'frame, 'metadata, C: ConstantString>(
+    typ: &'metadata ColumnType<'metadata>,
+    v: Option<FrameSlice<'frame>>,
+) -> Result<C, DeserializationError> {
+    crate::parse(<&str as DeserializeValue>::deserialize(typ, v)?)
+        .map_err(DeserializationError::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use scylla_cql_core::{
+        deserialize::{FrameSlice, value::DeserializeValue},
+        frame::response::result::{ColumnType, NativeType},
+        serialize::{value::SerializeValue, writers::CellWriter},
+    };
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    const TEXT: ColumnType<'static> = ColumnType::Native(NativeType::Text);
+    const INT: ColumnType<'static> = ColumnType::Native(NativeType::Int);
+
+    #[test]
+    fn serialize() {
+        let mut buf = Vec::new();
+        NotFound
+            .serialize(&TEXT, CellWriter::new_without_size(&mut buf))
+            .expect("serializable value");
+        assert_eq!(buf, b"notFound");
+
+        assert!(
+            NotFound
+                .serialize(&INT, CellWriter::new(&mut Vec::new()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert!(<NotFound as DeserializeValue>::type_check(&TEXT).is_ok());
+        assert!(<NotFound as DeserializeValue>::type_check(&INT).is_err());
+
+        for value in [&b"notFound"[..], b"not_found"] {
+            assert!(NotFound::deserialize(&TEXT, Some(FrameSlice::new_borrowed(value))).is_ok());
+        }
+        assert!(NotFound::deserialize(&TEXT, None).is_err());
+        assert!(<Option<NotFound>>::deserialize(&TEXT, None).is_ok_and(|value| value.is_none()));
+
+        let error = NotFound::deserialize(&TEXT, Some(FrameSlice::new_borrowed(b"missing")))
+            .expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"DeserializationError: invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
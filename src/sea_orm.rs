@@ -0,0 +1,97 @@
+//! SeaORM integration.
+//!
+//! Constant strings can be used as entity column types backed by string columns: the constant is written as the
+//! value and the stored value is validated against its [`Options`](crate::Options) when read.
+
+use std::sync::Arc;
+
+use sea_orm::{
+    ColIdx, DbErr, QueryResult, TryGetError, TryGetable, Value,
+    sea_query::{ColumnType, StringLen, ValueType, ValueTypeErr},
+};
+
+use crate::ConstantString;
+
+/// Read the constant string `C` from a query result, failing with a [`ParseError`](crate::ParseError) on a mismatch.
+///
+/// Used by the [`TryGetable`] implementation of [`constant_string`](crate::constant_string).
+pub fn try_get_by<C: ConstantString, I: ColIdx>(
+    res: &QueryResult,
+    index: I,
+) -> Result<C, TryGetError> {
+    let value = String::try_get_by(res, index)?;
+
+    crate::parse(&value).map_err(|error| {
+        TryGetError::DbErr(DbErr::TryIntoErr {
+            from: "String",
+            into: C::NAME,
+            source: Arc::new(error),
+        })
+    })
+}
+
+/// Convert a value into the constant string `C`.
+///
+/// Used by the [`ValueType`] implementation of [`constant_string`](crate::constant_string).
+pub fn try_from<C: ConstantString>(value: Value) -> Result<C, ValueTypeErr> {
+    let value = <String as ValueType>::try_from(value)?;
+
+    crate::parse(&value).map_err(|_| ValueTypeErr)
+}
+
+/// Column type of constant strings, a string of unspecified length.
+///
+/// Used by the [`ValueType`] implementation of [`constant_string`](crate::constant_string).
+pub fn column_type() -> ColumnType {
+    ColumnType::String(StringLen::None)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{
+        ConnectionTrait, DbBackend, MockDatabase, Statement, Value, sea_query::ValueType,
+    };
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound");
+
+    #[test]
+    fn value() {
+        assert_eq!(Value::from(NotFound), Value::from("notFound"));
+        assert!(<NotFound as ValueType>::try_from("notFound".into()).is_ok());
+        assert!(<NotFound as ValueType>::try_from("missing".into()).is_err());
+        assert!(<NotFound as ValueType>::try_from(1.into()).is_err());
+    }
+
+    #[tokio::test]
+    async fn try_get() {
+        let connection = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([
+                [BTreeMap::from([("code", Value::from(NotFound))])],
+                [BTreeMap::from([("code", Value::from("missing"))])],
+            ])
+            .into_connection();
+        let statement = Statement::from_string(DbBackend::Sqlite, "SELECT code FROM errors");
+
+        let row = connection
+            .query_one_raw(statement.clone())
+            .await
+            .expect("successful query")
+            .expect("row");
+        assert_eq!(row.try_get::<NotFound>("", "code"), Ok(NotFound));
+
+        let row = connection
+            .query_one_raw(statement)
+            .await
+            .expect("successful query")
+            .expect("row");
+        assert_eq!(
+            row.try_get::<NotFound>("", "code")
+                .map_err(|error| error.to_string()),
+            Err(r#"Error converting `String` into `NotFound`: invalid value "missing", expected constant "notFound" for NotFound"#.to_owned())
+        );
+    }
+}
@@ -1,29 +1,923 @@
 //! Serde integration.
 
-use std::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
 
-use serde::de::{Error, Unexpected, Visitor};
+use serde::{
+    Deserialize, Deserializer,
+    de::{DeserializeSeed, EnumAccess, Error, SeqAccess, Unexpected, VariantAccess, Visitor},
+};
+
+use crate::{
+    ConstantString, ConstantStringSet, MismatchErrorFn, Options,
+    parse::{eq_ignore_case, matches_constant},
+};
+
+/// Serde visitor for a [`ConstantString`], applying its [`Options`](crate::Options).
+///
+/// Used by the [`Deserialize`](serde::Deserialize) implementation of [`constant_string`](crate::constant_string).
+pub struct ConstantStringVisitor<C>(PhantomData<C>);
+
+impl<C: ConstantString> ConstantStringVisitor<C> {
+    /// Create a visitor for the constant string `C`.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    fn check<E>(&self, v: &str, unexpected: Unexpected) -> Result<(), E>
+    where
+        E: Error,
+    {
+        if C::OPTIONS.unchecked || matches_constant::<C>(v) {
+            Ok(())
+        } else if C::OPTIONS.lenient {
+            if let Some(on_mismatch) = C::OPTIONS.on_mismatch {
+                on_mismatch(C::VALUE, v);
+            }
+            Ok(())
+        } else if let Some(mismatch_error) = C::OPTIONS.mismatch_error {
+            Err(E::custom(MismatchError {
+                mismatch_error,
+                expected: C::VALUE,
+                found: v,
+            }))
+        } else {
+            Err(E::invalid_value(unexpected, self))
+        }
+    }
+}
+
+struct MismatchError<'a> {
+    mismatch_error: MismatchErrorFn,
+    expected: &'static str,
+    found: &'a str,
+}
+
+impl fmt::Display for MismatchError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.mismatch_error)(self.expected, self.found, f)
+    }
+}
+
+impl<C: ConstantString> Default for ConstantStringVisitor<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'de, C: ConstantString> Visitor<'de> for ConstantStringVisitor<C> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(expecting) = C::OPTIONS.expecting {
+            return formatter.write_str(expecting);
+        }
+
+        write!(formatter, "constant {:?}", C::VALUE)?;
+        for alias in C::OPTIONS.aliases {
+            write!(formatter, " or alias {alias:?}")?;
+        }
+        write!(formatter, " for {}", C::NAME)?;
+        C::OPTIONS.describe(formatter)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.check(v, Unexpected::Str(v))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(v)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(&v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match str::from_utf8(v) {
+            Ok(found) => self.check(found, Unexpected::Bytes(v)),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if C::OPTIONS.null {
+            Ok(())
+        } else {
+            Err(E::invalid_type(Unexpected::Option, &self))
+        }
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        // Compact constants are encoded as units, and some formats encode `null` as a unit.
+        if C::OPTIONS.compact || C::OPTIONS.null {
+            Ok(())
+        } else {
+            Err(E::invalid_type(Unexpected::Unit, &self))
+        }
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let ((), variant_access) = data.variant_seed(VariantSeed(self))?;
+
+        variant_access.unit_variant()
+    }
+}
+
+/// Seed for the variant name of a unit variant, accepted if it is the type name or matches the constant.
+struct VariantSeed<C>(ConstantStringVisitor<C>);
+
+impl<'de, C: ConstantString> DeserializeSeed<'de> for VariantSeed<C> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de, C: ConstantString> Visitor<'de> for VariantSeed<C> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v == C::NAME {
+            Ok(())
+        } else {
+            self.0.check(v, Unexpected::Str(v))
+        }
+    }
+}
+
+/// Policy for comparing a deserialized string with an expected string.
+///
+/// Implemented for closures `Fn(expected: &str, found: &str) -> bool` and for [`Options`], which combines the
+/// `case_insensitive`, `nfc` and `trim` options.
+pub trait MatchPolicy {
+    /// Whether `found` matches `expected`.
+    fn matches(&self, expected: &str, found: &str) -> bool;
+
+    /// Describe the policy after the expected value in error messages, e.g. ` (case-insensitive)`.
+    fn describe(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let _ = formatter;
+        Ok(())
+    }
+}
+
+/// Match if both strings are equal.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Exact;
+
+impl MatchPolicy for Exact {
+    fn matches(&self, expected: &str, found: &str) -> bool {
+        expected == found
+    }
+}
+
+/// Match if both strings are equal after converting them to lowercase.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CaseInsensitive;
+
+impl MatchPolicy for CaseInsensitive {
+    fn matches(&self, expected: &str, found: &str) -> bool {
+        eq_ignore_case(expected.chars(), found.chars())
+    }
+
+    fn describe(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, " (case-insensitive)")
+    }
+}
+
+/// Match with policy `P` after trimming leading and trailing whitespace from the found string.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Trimmed<P = Exact>(pub P);
+
+impl<P: MatchPolicy> MatchPolicy for Trimmed<P> {
+    fn matches(&self, expected: &str, found: &str) -> bool {
+        self.0.matches(expected, found.trim())
+    }
+
+    fn describe(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.describe(formatter)
+    }
+}
+
+impl<F> MatchPolicy for F
+where
+    F: Fn(&str, &str) -> bool,
+{
+    fn matches(&self, expected: &str, found: &str) -> bool {
+        self(expected, found)
+    }
+}
+
+impl MatchPolicy for Options {
+    fn matches(&self, expected: &str, found: &str) -> bool {
+        crate::parse::matches(self, expected, found)
+    }
+
+    fn describe(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::parse::describe(self.case_insensitive, self.nfc, self.trim, formatter)
+    }
+}
+
+/// Serde visitor for a static string, compared with [`MatchPolicy`] `P`.
+pub struct MatchStrVisitor<P>(pub &'static str, pub P);
+
+impl<'de, P: MatchPolicy> Visitor<'de> for MatchStrVisitor<P> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self.0)?;
+        self.1.describe(formatter)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if self.1.matches(self.0, v) {
+            Ok(())
+        } else {
+            Err(E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(v)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(&v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match str::from_utf8(v) {
+            Ok(found) if self.1.matches(self.0, found) => Ok(()),
+            _ => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+/// Implement [`Visitor`] for a static string visitor by delegating to [`MatchStrVisitor`] with a policy.
+macro_rules! match_str_visitor {
+    ($visitor:ident, $policy:expr) => {
+        impl<'de> Visitor<'de> for $visitor {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                Visitor::expecting(&MatchStrVisitor(self.0, $policy), formatter)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                MatchStrVisitor(self.0, $policy).visit_str(v)
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                MatchStrVisitor(self.0, $policy).visit_borrowed_str(v)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                MatchStrVisitor(self.0, $policy).visit_string(v)
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                MatchStrVisitor(self.0, $policy).visit_bytes(v)
+            }
+        }
+    };
+}
 
 /// Serde visitor for a static string.
 ///
-/// Based on `MustBeStrVisitor` from [`monostate`](https://crates.io/crates/monostate).
+/// Based on `MustBeStrVisitor` from [`monostate`](https://crates.io/crates/monostate). See [`MatchStrVisitor`] for
+/// other [`MatchPolicy`] comparisons.
 pub struct MustBeStrVisitor(pub &'static str);
 
-impl<'de> Visitor<'de> for MustBeStrVisitor {
+match_str_visitor!(MustBeStrVisitor, Exact);
+
+/// Serde visitor for a static string, ignoring case.
+///
+/// Matches if both strings are equal after converting them to lowercase.
+pub struct MustBeStrIgnoreCaseVisitor(pub &'static str);
+
+match_str_visitor!(MustBeStrIgnoreCaseVisitor, CaseInsensitive);
+
+/// Serde visitor for a static unsigned integer.
+pub struct MustBeU64Visitor(pub u64);
+
+impl<'de> Visitor<'de> for MustBeU64Visitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "integer `{}`", self.0)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) if v == self.0 => Ok(()),
+            _ => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+}
+
+/// Serde visitor for a static signed integer.
+pub struct MustBeI64Visitor(pub i64);
+
+impl<'de> Visitor<'de> for MustBeI64Visitor {
     type Value = ();
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "{:?}", self.0)
+        write!(formatter, "integer `{}`", self.0)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(Unexpected::Signed(v), &self))
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match i64::try_from(v) {
+            Ok(v) if v == self.0 => Ok(()),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+/// Serde visitor for a static boolean.
+pub struct MustBeBoolVisitor(pub bool);
+
+impl<'de> Visitor<'de> for MustBeBoolVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "boolean `{}`", self.0)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(Unexpected::Bool(v), &self))
+        }
+    }
+}
+
+/// Serde visitor for a static character.
+///
+/// Also accepts a string of exactly this character, as used by formats without a character type.
+pub struct MustBeCharVisitor(pub char);
+
+impl<'de> Visitor<'de> for MustBeCharVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "character {:?}", self.0)
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(Unexpected::Char(v), &self))
+        }
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let mut chars = v.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.visit_char(c),
+            _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+/// Serde visitor for a static byte string.
+///
+/// Also accepts a sequence of bytes, as used by formats without a byte string type.
+pub struct MustBeBytesVisitor(pub &'static [u8]);
+
+impl<'de> Visitor<'de> for MustBeBytesVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "bytes {:?}", self.0)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
     where
         E: Error,
     {
         if v == self.0 {
             Ok(())
         } else {
-            Err(E::invalid_value(Unexpected::Str(v), &self))
+            Err(E::invalid_value(Unexpected::Bytes(v), &self))
+        }
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        for expected in self.0 {
+            if seq.next_element::<u8>()? != Some(*expected) {
+                return Err(A::Error::invalid_value(Unexpected::Seq, &self));
+            }
+        }
+
+        if seq.next_element::<u8>()?.is_some() {
+            Err(A::Error::invalid_value(Unexpected::Seq, &self))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Default value of a constant string, for use with `#[serde(default = "constant_string::serde::default")]`.
+pub fn default<C: ConstantString>() -> C {
+    C::default()
+}
+
+/// Deserialize a constant string, defaulting to the constant for `null`.
+///
+/// For use with `#[serde(default, deserialize_with = "constant_string::serde::deserialize_or_default")]`.
+pub fn deserialize_or_default<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+where
+    C: ConstantString + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<C>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+/// Deserialize the bits of the members of the set `S` from a sequence of their values, applying their options.
+///
+/// Used by the [`Deserialize`] implementation of [`constant_string_set`](crate::constant_string_set).
+pub fn deserialize_set<'de, S, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    S: ConstantStringSet,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(SetVisitor::<S>(PhantomData))
+}
+
+/// Visitor for a sequence of members of the set `S`.
+struct SetVisitor<S>(PhantomData<S>);
+
+impl<'de, S: ConstantStringSet> Visitor<'de> for SetVisitor<S> {
+    type Value = u64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of members")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bits = 0;
+        while let Some(bit) = seq.next_element_seed(MemberSeed::<S>(PhantomData))? {
+            bits |= bit;
+        }
+
+        Ok(bits)
+    }
+}
+
+/// Seed for a member of the set `S`, deserialized as the bit of the member.
+struct MemberSeed<S>(PhantomData<S>);
+
+impl<'de, S: ConstantStringSet> DeserializeSeed<'de> for MemberSeed<S> {
+    type Value = u64;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, S: ConstantStringSet> Visitor<'de> for MemberSeed<S> {
+    type Value = u64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("one of")?;
+        for (index, value) in S::ALL.iter().enumerate() {
+            formatter.write_str(if index == 0 { " " } else { ", " })?;
+            write!(formatter, "{value:?}")?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        S::bit(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{
+        Deserializer,
+        de::{
+            IntoDeserializer,
+            value::{
+                BorrowedStrDeserializer, BytesDeserializer, EnumAccessDeserializer, Error,
+                StrDeserializer, UnitDeserializer,
+            },
+        },
+    };
+
+    use super::{
+        CaseInsensitive, ConstantStringVisitor, Exact, MatchStrVisitor, MustBeBoolVisitor,
+        MustBeBytesVisitor, MustBeCharVisitor, MustBeI64Visitor, MustBeStrIgnoreCaseVisitor,
+        MustBeStrVisitor, MustBeU64Visitor, Trimmed,
+    };
+    use crate::constant_string;
+
+    const VISITOR: MustBeStrVisitor = MustBeStrVisitor("constant");
+
+    #[test]
+    fn visit_str() {
+        let deserializer: BorrowedStrDeserializer<Error> = BorrowedStrDeserializer::new("constant");
+        assert!(deserializer.deserialize_any(VISITOR).is_ok());
+
+        let deserializer: BorrowedStrDeserializer<Error> = BorrowedStrDeserializer::new("other");
+        assert_eq!(
+            deserializer
+                .deserialize_any(VISITOR)
+                .map_err(|error| error.to_string()),
+            Err("invalid value: string \"other\", expected \"constant\"".to_owned())
+        );
+    }
+
+    #[test]
+    fn constant_string_visitor() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+
+        let deserializer: BorrowedStrDeserializer<Error> = BorrowedStrDeserializer::new("notFound");
+        assert!(
+            deserializer
+                .deserialize_any(ConstantStringVisitor::<NotFound>::new())
+                .is_ok()
+        );
+
+        let deserializer: BorrowedStrDeserializer<Error> = BorrowedStrDeserializer::new("other");
+        assert_eq!(
+            deserializer
+                .deserialize_any(ConstantStringVisitor::<NotFound>::new())
+                .map_err(|error| error.to_string()),
+            Err(
+                "invalid value: string \"other\", expected constant \"notFound\" for NotFound"
+                    .to_owned()
+            )
+        );
+
+        let deserializer = IntoDeserializer::<Error>::into_deserializer(1u32);
+        assert_eq!(
+            deserializer
+                .deserialize_any(ConstantStringVisitor::<NotFound>::new())
+                .map_err(|error| error.to_string()),
+            Err(
+                "invalid type: integer `1`, expected constant \"notFound\" for NotFound".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn constant_string_visitor_bytes() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+        constant_string!(Replacement, REPLACEMENT, "\u{fffd}");
+        constant_string!(Empty, EMPTY, "");
+
+        assert!(
+            BytesDeserializer::<Error>::new(b"notFound")
+                .deserialize_any(ConstantStringVisitor::<NotFound>::new())
+                .is_ok()
+        );
+        assert_eq!(
+            BytesDeserializer::<Error>::new(b"\xff")
+                .deserialize_any(ConstantStringVisitor::<Replacement>::new())
+                .map_err(|error| error.to_string()),
+            Err(
+                "invalid value: byte array, expected constant \"\u{fffd}\" for Replacement"
+                    .to_owned()
+            )
+        );
+        assert!(
+            BytesDeserializer::<Error>::new(b"\xff")
+                .deserialize_any(ConstantStringVisitor::<Empty>::new())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn constant_string_visitor_unit() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+
+        constant_string!(Compact, COMPACT, "compact", compact);
+        constant_string!(Nullable, NULLABLE, "nullable", null);
+
+        let deserializer: UnitDeserializer<Error> = UnitDeserializer::new();
+        assert_eq!(
+            deserializer
+                .deserialize_any(ConstantStringVisitor::<NotFound>::new())
+                .map_err(|error| error.to_string()),
+            Err("invalid type: unit value, expected constant \"notFound\" for NotFound".to_owned())
+        );
+        assert!(
+            UnitDeserializer::<Error>::new()
+                .deserialize_any(ConstantStringVisitor::<Compact>::new())
+                .is_ok()
+        );
+        assert!(
+            UnitDeserializer::<Error>::new()
+                .deserialize_any(ConstantStringVisitor::<Nullable>::new())
+                .is_ok()
+        );
+
+        for variant in ["NotFound", "notFound"] {
+            let deserializer = EnumAccessDeserializer::new(StrDeserializer::<Error>::new(variant));
+            assert!(
+                deserializer
+                    .deserialize_any(ConstantStringVisitor::<NotFound>::new())
+                    .is_ok()
+            );
+        }
+
+        let deserializer = EnumAccessDeserializer::new(StrDeserializer::<Error>::new("Other"));
+        assert_eq!(
+            deserializer
+                .deserialize_any(ConstantStringVisitor::<NotFound>::new())
+                .map_err(|error| error.to_string()),
+            Err(
+                "invalid value: string \"Other\", expected constant \"notFound\" for NotFound"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn visit_string() {
+        let deserializer = IntoDeserializer::<Error>::into_deserializer("constant".to_owned());
+        assert!(deserializer.deserialize_any(VISITOR).is_ok());
+
+        let deserializer = IntoDeserializer::<Error>::into_deserializer("other".to_owned());
+        assert!(deserializer.deserialize_any(VISITOR).is_err());
+    }
+
+    #[test]
+    fn visit_bytes() {
+        let deserializer: BytesDeserializer<Error> = BytesDeserializer::new(b"constant");
+        assert!(deserializer.deserialize_any(VISITOR).is_ok());
+
+        let deserializer: BytesDeserializer<Error> = BytesDeserializer::new(b"other");
+        assert_eq!(
+            deserializer
+                .deserialize_any(VISITOR)
+                .map_err(|error| error.to_string()),
+            Err("invalid value: byte array, expected \"constant\"".to_owned())
+        );
+    }
+
+    #[test]
+    fn ignore_case() {
+        for value in ["notFound", "NOTFOUND", "notfound"] {
+            let deserializer: BorrowedStrDeserializer<Error> = BorrowedStrDeserializer::new(value);
+            assert!(
+                deserializer
+                    .deserialize_any(MustBeStrIgnoreCaseVisitor("notFound"))
+                    .is_ok()
+            );
+        }
+
+        let deserializer: BytesDeserializer<Error> = BytesDeserializer::new(b"NotFound");
+        assert!(
+            deserializer
+                .deserialize_any(MustBeStrIgnoreCaseVisitor("notFound"))
+                .is_ok()
+        );
+
+        let deserializer: BorrowedStrDeserializer<Error> =
+            BorrowedStrDeserializer::new("not_found");
+        assert_eq!(
+            deserializer
+                .deserialize_any(MustBeStrIgnoreCaseVisitor("notFound"))
+                .map_err(|error| error.to_string()),
+            Err(
+                "invalid value: string \"not_found\", expected \"notFound\" (case-insensitive)"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn match_policy() {
+        let deserializer: BorrowedStrDeserializer<Error> =
+            BorrowedStrDeserializer::new(" NOTFOUND\n");
+        assert!(
+            deserializer
+                .deserialize_any(MatchStrVisitor("notFound", Trimmed(CaseInsensitive)))
+                .is_ok()
+        );
+
+        let deserializer: BorrowedStrDeserializer<Error> = BorrowedStrDeserializer::new(" other ");
+        assert_eq!(
+            deserializer
+                .deserialize_any(MatchStrVisitor("notFound", Trimmed(Exact)))
+                .map_err(|error| error.to_string()),
+            Err("invalid value: string \" other \", expected \"notFound\"".to_owned())
+        );
+
+        let prefix = |expected: &str, found: &str| found.starts_with(expected);
+        let deserializer: BorrowedStrDeserializer<Error> =
+            BorrowedStrDeserializer::new("notFound.v2");
+        assert!(
+            deserializer
+                .deserialize_any(MatchStrVisitor("notFound", prefix))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn other_literals() {
+        assert!(
+            IntoDeserializer::<Error>::into_deserializer(404u16)
+                .deserialize_any(MustBeU64Visitor(404))
+                .is_ok()
+        );
+        assert!(
+            IntoDeserializer::<Error>::into_deserializer(404i64)
+                .deserialize_any(MustBeU64Visitor(404))
+                .is_ok()
+        );
+        assert_eq!(
+            IntoDeserializer::<Error>::into_deserializer(-1i64)
+                .deserialize_any(MustBeU64Visitor(404))
+                .map_err(|error| error.to_string()),
+            Err("invalid value: integer `-1`, expected integer `404`".to_owned())
+        );
+
+        assert!(
+            IntoDeserializer::<Error>::into_deserializer(5u8)
+                .deserialize_any(MustBeI64Visitor(5))
+                .is_ok()
+        );
+        assert_eq!(
+            IntoDeserializer::<Error>::into_deserializer(-5i32)
+                .deserialize_any(MustBeI64Visitor(5))
+                .map_err(|error| error.to_string()),
+            Err("invalid value: integer `-5`, expected integer `5`".to_owned())
+        );
+
+        assert!(
+            IntoDeserializer::<Error>::into_deserializer(true)
+                .deserialize_any(MustBeBoolVisitor(true))
+                .is_ok()
+        );
+        assert_eq!(
+            IntoDeserializer::<Error>::into_deserializer(false)
+                .deserialize_any(MustBeBoolVisitor(true))
+                .map_err(|error| error.to_string()),
+            Err("invalid value: boolean `false`, expected boolean `true`".to_owned())
+        );
+
+        assert!(
+            IntoDeserializer::<Error>::into_deserializer('x')
+                .deserialize_any(MustBeCharVisitor('x'))
+                .is_ok()
+        );
+        assert!(
+            BorrowedStrDeserializer::<Error>::new("x")
+                .deserialize_any(MustBeCharVisitor('x'))
+                .is_ok()
+        );
+        assert_eq!(
+            BorrowedStrDeserializer::<Error>::new("xy")
+                .deserialize_any(MustBeCharVisitor('x'))
+                .map_err(|error| error.to_string()),
+            Err("invalid value: string \"xy\", expected character 'x'".to_owned())
+        );
+
+        assert!(
+            BytesDeserializer::<Error>::new(b"\x01\x02")
+                .deserialize_any(MustBeBytesVisitor(b"\x01\x02"))
+                .is_ok()
+        );
+        assert!(
+            IntoDeserializer::<Error>::into_deserializer(vec![1u8, 2])
+                .deserialize_any(MustBeBytesVisitor(b"\x01\x02"))
+                .is_ok()
+        );
+        for bytes in [vec![1u8], vec![1, 2, 3], vec![2, 1]] {
+            assert!(
+                IntoDeserializer::<Error>::into_deserializer(bytes)
+                    .deserialize_any(MustBeBytesVisitor(b"\x01\x02"))
+                    .is_err()
+            );
         }
+        assert_eq!(
+            IntoDeserializer::<Error>::into_deserializer(1u8)
+                .deserialize_any(MustBeBytesVisitor(b"\x01"))
+                .map_err(|error| error.to_string()),
+            Err("invalid type: integer `1`, expected bytes [1]".to_owned())
+        );
     }
 }
@@ -0,0 +1,166 @@
+//! Serde Reflection integration.
+//!
+//! Constant strings are serialized as plain strings, so a traced registry records them as [`Format::Str`] without
+//! their value, and tracing the deserialization of a type holding a constant fails on the default string of the
+//! tracer. [`trace`] records a constant as a newtype struct named after its type around its format, with its value
+//! as a sample. Tracing a type holding constants succeeds after tracing a value of it with [`tracer_config`].
+//!
+//! # Example
+//! ```
+//! # extern crate serde;
+//! # extern crate serde_reflection;
+//! #
+//! use constant_string::{constant_string, serde_reflection::{trace, tracer_config, value}};
+//! use serde::{Deserialize, Serialize};
+//! use serde_reflection::{ContainerFormat, Format, Samples, Tracer};
+//!
+//! constant_string!(UserCreatedEventType, USER_CREATED_EVENT_TYPE, "user.created");
+//!
+//! #[derive(Default, Deserialize, Serialize)]
+//! struct UserCreatedEvent {
+//!     r#type: UserCreatedEventType,
+//!     id: u32,
+//! }
+//!
+//! let mut tracer = Tracer::new(tracer_config());
+//! let mut samples = Samples::new();
+//! trace::<UserCreatedEventType>(&mut tracer, &mut samples).expect("traceable constant");
+//! tracer.trace_value(&mut samples, &UserCreatedEvent::default()).expect("traceable value");
+//! tracer.trace_type::<UserCreatedEvent>(&samples).expect("traceable type");
+//!
+//! let registry = tracer.registry().expect("complete registry");
+//! assert_eq!(
+//!     registry.get("UserCreatedEventType"),
+//!     Some(&ContainerFormat::NewTypeStruct(Box::new(Format::Str)))
+//! );
+//! assert_eq!(value(&samples, UserCreatedEventType), Some("user.created"));
+//! ```
+
+use serde::{Serialize, Serializer};
+use serde_reflection::{Format, Samples, Tracer, TracerConfig, Value};
+
+use crate::ConstantString;
+
+/// Constant string `C` serialized as a newtype struct named after its type.
+struct Constant<C>(C);
+
+impl<C: ConstantString + Serialize> Serialize for Constant<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(C::NAME, &self.0)
+    }
+}
+
+/// Trace the constant string `C`.
+///
+/// Registers `C` as a newtype struct named after its type around its format, [`Format::Str`] or [`Format::Unit`] for
+/// a `compact` constant in a binary format, and records its value in `samples`. Returns the format of the newtype
+/// struct.
+pub fn trace<C>(tracer: &mut Tracer, samples: &mut Samples) -> serde_reflection::Result<Format>
+where
+    C: ConstantString + Serialize,
+{
+    tracer
+        .trace_value(samples, &Constant(C::default()))
+        .map(|(format, _)| format)
+}
+
+/// Tracer configuration recording samples of structs and tuple structs.
+///
+/// Tracing the deserialization of a type holding a constant string then replays a traced value of that type instead
+/// of the default string of the tracer, which the constant rejects.
+pub fn tracer_config() -> TracerConfig {
+    TracerConfig::default()
+        .record_samples_for_structs(true)
+        .record_samples_for_tuple_structs(true)
+}
+
+/// Value of the constant string `C` traced in `samples` by [`trace`].
+///
+/// Returns `None` if `C` was not traced, or was traced as a unit since it is `compact`.
+pub fn value<C: ConstantString>(samples: &Samples, _: C) -> Option<&str> {
+    match samples.value(C::NAME) {
+        Some(Value::Str(value)) => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_reflection::{ContainerFormat, Format, Named, Samples, Tracer, TracerConfig};
+
+    use super::{trace, tracer_config, value};
+    use crate::constant_string;
+
+    constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+    constant_string!(CompactErrorCode, COMPACT_ERROR_CODE, "compact", compact);
+
+    #[derive(Default, Deserialize, Serialize)]
+    struct NotFoundError {
+        code: NotFoundErrorCode,
+        message: String,
+    }
+
+    #[test]
+    fn serde_reflection() {
+        let mut tracer = Tracer::new(tracer_config());
+        let mut samples = Samples::new();
+
+        assert_eq!(
+            trace::<NotFoundErrorCode>(&mut tracer, &mut samples).ok(),
+            Some(Format::TypeName("NotFoundErrorCode".to_owned()))
+        );
+        assert!(trace::<CompactErrorCode>(&mut tracer, &mut samples).is_ok());
+        assert!(
+            tracer
+                .trace_value(&mut samples, &NotFoundError::default())
+                .is_ok()
+        );
+        assert!(tracer.trace_type::<NotFoundError>(&samples).is_ok());
+
+        assert_eq!(value(&samples, NotFoundErrorCode), Some("notFound"));
+        assert_eq!(value(&samples, CompactErrorCode), None);
+        assert_eq!(
+            tracer
+                .registry()
+                .ok()
+                .map(|registry| registry.into_iter().collect::<Vec<_>>()),
+            Some(vec![
+                (
+                    "CompactErrorCode".to_owned(),
+                    ContainerFormat::NewTypeStruct(Box::new(Format::Unit))
+                ),
+                (
+                    "NotFoundError".to_owned(),
+                    ContainerFormat::Struct(vec![
+                        Named {
+                            name: "code".to_owned(),
+                            value: Format::Str
+                        },
+                        Named {
+                            name: "message".to_owned(),
+                            value: Format::Str
+                        },
+                    ])
+                ),
+                (
+                    "NotFoundErrorCode".to_owned(),
+                    ContainerFormat::NewTypeStruct(Box::new(Format::Str))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn human_readable() {
+        let mut tracer = Tracer::new(TracerConfig::default().is_human_readable(true));
+        let mut samples = Samples::new();
+
+        assert!(trace::<CompactErrorCode>(&mut tracer, &mut samples).is_ok());
+        assert_eq!(value(&samples, CompactErrorCode), Some("compact"));
+        assert!(tracer.trace_type::<NotFoundError>(&samples).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//! Serde With integration.
+//!
+//! # Example
+//! ```
+//! # extern crate serde;
+//! # extern crate serde_with;
+//! #
+//! use constant_string::{constant_string, serde_with::MustBe};
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//!
+//! constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+//!
+//! #[serde_as]
+//! #[derive(Deserialize, Serialize)]
+//! struct NotFoundError {
+//!     #[serde_as(as = "MustBe<NotFoundErrorCode>")]
+//!     code: String,
+//! }
+//! ```
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::Error};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::ConstantString;
+
+/// Serde With adapter validating a string against the constant string `C`.
+///
+/// Serializing writes the constant if the value matches it under its options, and fails otherwise. Deserializing
+/// accepts the same inputs as `C`.
+pub struct MustBe<C>(PhantomData<C>);
+
+impl<C, T> SerializeAs<T> for MustBe<C>
+where
+    C: ConstantString + Serialize,
+    T: AsRef<str>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if crate::parse::matches_constant::<C>(source.as_ref()) {
+            C::default().serialize(serializer)
+        } else {
+            Err(S::Error::custom(format_args!(
+                "expected constant {:?} for {}, found {:?}",
+                C::VALUE,
+                C::NAME,
+                source.as_ref()
+            )))
+        }
+    }
+}
+
+impl<'de, C, T> DeserializeAs<'de, T> for MustBe<C>
+where
+    C: ConstantString + Deserialize<'de>,
+    T: From<&'static str>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        C::deserialize(deserializer).map(|_| T::from(C::VALUE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_with::serde_as;
+
+    use super::MustBe;
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound");
+
+    #[serde_as]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Error {
+        #[serde_as(as = "MustBe<NotFound>")]
+        code: String,
+        #[serde_as(as = "Vec<MustBe<NotFound>>")]
+        codes: Vec<&'static str>,
+    }
+
+    #[test]
+    fn must_be() {
+        let error = Error {
+            code: "notFound".to_owned(),
+            codes: vec!["notFound", "notFound"],
+        };
+        let json = r#"{"code":"notFound","codes":["notFound","notFound"]}"#;
+
+        assert_eq!(
+            json,
+            serde_json::to_string(&error).expect("serializable value")
+        );
+        assert_eq!(
+            error,
+            serde_json::from_str(json).expect("deserializable value")
+        );
+
+        assert_eq!(
+            serde_json::to_string(&Error {
+                code: "other".to_owned(),
+                codes: vec![],
+            })
+            .map_err(|error| error.to_string()),
+            Err("expected constant \"notFound\" for NotFound, found \"other\"".to_owned())
+        );
+        assert!(serde_json::from_str::<Error>(r#"{"code":"notFound","codes":["other"]}"#).is_err());
+    }
+
+    #[test]
+    fn must_be_options() {
+        constant_string!(
+            Missing,
+            MISSING,
+            "missing",
+            aliases = ["notFound"],
+            case_insensitive
+        );
+
+        #[serde_as]
+        #[derive(Serialize)]
+        struct Error {
+            #[serde_as(as = "Vec<MustBe<Missing>>")]
+            codes: Vec<&'static str>,
+        }
+
+        assert_eq!(
+            r#"{"codes":["missing","missing","missing"]}"#,
+            serde_json::to_string(&Error {
+                codes: vec!["missing", "MISSING", "notfound"],
+            })
+            .expect("serializable value")
+        );
+        assert!(
+            serde_json::to_string(&Error {
+                codes: vec!["other"],
+            })
+            .is_err()
+        );
+    }
+}
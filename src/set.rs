@@ -0,0 +1,345 @@
+use crate::ConstantString;
+
+/// Set of constant string types, defined by [`constant_string_set`](crate::constant_string_set).
+///
+/// Each member has a bit in a `u64`, so a set has at most 64 members.
+pub trait ConstantStringSet: Copy + Default + 'static {
+    /// Values of the members, in the order of their bits.
+    const ALL: &'static [&'static str];
+
+    /// Bit of the member matching `value`, its value or one of its aliases under its options.
+    fn bit(value: &str) -> Option<u64>;
+}
+
+/// Constant string type in the set `S`.
+pub trait ConstantSetMember<S: ConstantStringSet>: ConstantString {
+    /// Bit of the member in the set.
+    const BIT: u64;
+}
+
+/// Define a set of constant string types, backed by a bitmask.
+///
+/// Defines a `Copy` struct with `insert`, `remove` and `contains` taking a member, `union`, `intersection` and `iter`
+/// over the values of the members in the set, and [`From`] for each member. A set has at most 64 members.
+///
+/// With the `serde` feature, the set is serialized as an array of the values of its members and deserialized from an
+/// array of values or aliases of members under their options. With the `utoipa` feature, its schema is an array of
+/// unique items allowing the values of the members in `enum`.
+///
+/// # Example
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use constant_string::{constant_string, constant_string_set};
+///
+/// constant_string!(ReadScope, READ_SCOPE, "read");
+/// constant_string!(WriteScope, WRITE_SCOPE, "write");
+/// constant_string!(AdminScope, ADMIN_SCOPE, "admin");
+///
+/// constant_string_set! {
+///     /// Scopes of an access token.
+///     pub struct Scopes;
+///     ReadScope, WriteScope, AdminScope
+/// }
+///
+/// let mut scopes = Scopes::from(ReadScope);
+/// scopes.insert(WriteScope);
+/// assert!(scopes.contains(WriteScope));
+/// assert!(!scopes.contains(AdminScope));
+/// assert_eq!(scopes.iter().collect::<Vec<_>>(), ["read", "write"]);
+/// assert_eq!(
+///     serde_json::to_string(&scopes).expect("serializable value"),
+///     r#"["read","write"]"#
+/// );
+/// ```
+#[macro_export]
+macro_rules! constant_string_set {
+    (
+        $(#[$meta:meta])* $vis:vis struct $name:ident;
+        $($constant:ty),+ $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::default::Default,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+            ::core::cmp::PartialEq,
+        )]
+        $vis struct $name(u64);
+
+        const _: () = ::core::assert!(
+            $name::ALL.len() <= 64,
+            ::core::concat!("constant string set ", ::core::stringify!($name), " has more than 64 members")
+        );
+
+        #[allow(dead_code, deprecated)]
+        impl $name {
+            /// Values of the members.
+            pub const ALL: &'static [&'static str] = &[$(<$constant as $crate::ConstantString>::VALUE),+];
+
+            /// Set without members.
+            pub const EMPTY: Self = Self(0);
+
+            /// Set of all members.
+            pub const FULL: Self = Self(u64::MAX >> (64 - Self::ALL.len()));
+
+            /// Insert the member `C`, returning whether it was not in the set.
+            pub fn insert<C: $crate::ConstantSetMember<Self>>(&mut self, _: C) -> bool {
+                let inserted = self.0 & C::BIT == 0;
+                self.0 |= C::BIT;
+                inserted
+            }
+
+            /// Remove the member `C`, returning whether it was in the set.
+            pub fn remove<C: $crate::ConstantSetMember<Self>>(&mut self, _: C) -> bool {
+                let removed = self.0 & C::BIT != 0;
+                self.0 &= !C::BIT;
+                removed
+            }
+
+            /// Whether the member `C` is in the set.
+            pub fn contains<C: $crate::ConstantSetMember<Self>>(&self, _: C) -> bool {
+                self.0 & C::BIT != 0
+            }
+
+            /// Set of the members in either set.
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            /// Set of the members in both sets.
+            pub const fn intersection(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            /// Whether the set has no members.
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Number of members in the set.
+            pub const fn len(self) -> usize {
+                self.0.count_ones() as usize
+            }
+
+            /// Iterate over the values of the members in the set.
+            pub fn iter(self) -> impl ::core::iter::Iterator<Item = &'static str> {
+                ::core::iter::Iterator::filter_map(
+                    ::core::iter::Iterator::enumerate(Self::ALL.iter()),
+                    move |(index, value)| (self.0 & (1 << index) != 0).then_some(*value),
+                )
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::ConstantStringSet for $name {
+            const ALL: &'static [&'static str] = Self::ALL;
+
+            fn bit(value: &str) -> ::core::option::Option<u64> {
+                $(
+                    if $crate::__private::matches_constant::<$constant>(value) {
+                        return ::core::option::Option::Some(
+                            <$constant as $crate::ConstantSetMember<Self>>::BIT,
+                        );
+                    }
+                )+
+
+                ::core::option::Option::None
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_set().entries(self.iter()).finish()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.union(other)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, other: Self) {
+                *self = self.union(other);
+            }
+        }
+
+        $(
+            #[allow(deprecated)]
+            impl ::core::convert::From<$constant> for $name {
+                fn from(_: $constant) -> Self {
+                    Self(<$constant as $crate::ConstantSetMember<Self>>::BIT)
+                }
+            }
+        )+
+
+        $crate::constant_string_set!(@bits $name [] $($constant),+);
+
+        $crate::constant_string_set_serde!($name);
+
+        $crate::constant_string_set_utoipa!($name);
+    };
+    (@bits $name:ident [$($before:ty),*]) => {};
+    (@bits $name:ident [$($before:ty),*] $constant:ty $(, $rest:ty)*) => {
+        #[allow(deprecated)]
+        impl $crate::ConstantSetMember<$name> for $constant {
+            const BIT: u64 = 1 << <[()]>::len(&[$($crate::constant_string_set!(@unit $before)),*]);
+        }
+
+        $crate::constant_string_set!(@bits $name [$($before,)* $constant] $($rest),*);
+    };
+    (@unit $before:ty) => {
+        ()
+    };
+}
+
+/// Implement [`serde`] traits for a constant string set.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_set_serde {
+    ($name:ident) => {
+        #[allow(deprecated)]
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::serde::deserialize_set::<Self, D>(deserializer).map(Self)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.collect_seq(self.iter())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_set_serde {
+    ($($tt:tt)*) => {};
+}
+
+/// Implement [`utoipa`] traits for a constant string set.
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_set_utoipa {
+    ($name:ident) => {
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa::PartialSchema for $name {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<
+                $crate::__private::utoipa::openapi::schema::Schema,
+            > {
+                $crate::utoipa::set_schema(::core::iter::Iterator::copied(Self::ALL.iter()))
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa::ToSchema for $name {}
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_string_set_utoipa {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constant_string;
+
+    constant_string!(ReadScope, READ_SCOPE, "read", aliases = ["r"]);
+    constant_string!(WriteScope, WRITE_SCOPE, "write");
+    constant_string!(AdminScope, ADMIN_SCOPE, "admin");
+
+    constant_string_set! {
+        struct Scopes;
+        ReadScope, WriteScope, AdminScope,
+    }
+
+    #[test]
+    fn constant_string_set() {
+        let mut scopes = Scopes::EMPTY;
+        assert!(scopes.is_empty());
+        assert!(scopes.insert(AdminScope));
+        assert!(!scopes.insert(AdminScope));
+        assert!(scopes.insert(ReadScope));
+        assert!(scopes.contains(ReadScope));
+        assert!(!scopes.contains(WriteScope));
+        assert_eq!(scopes.len(), 2);
+        assert_eq!(scopes.iter().collect::<Vec<_>>(), ["read", "admin"]);
+        assert_eq!(format!("{scopes:?}"), r#"{"read", "admin"}"#);
+
+        assert!(scopes.remove(ReadScope));
+        assert!(!scopes.remove(ReadScope));
+        assert_eq!(scopes, Scopes::from(AdminScope));
+
+        let mut scopes = Scopes::from(ReadScope) | Scopes::from(WriteScope);
+        assert_eq!(
+            scopes.intersection(Scopes::from(WriteScope)),
+            WriteScope.into()
+        );
+        scopes |= AdminScope.into();
+        assert_eq!(scopes, Scopes::FULL);
+        assert_eq!(Scopes::ALL, ["read", "write", "admin"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        let scopes = Scopes::from(ReadScope) | Scopes::from(AdminScope);
+        assert_eq!(
+            to_string(&scopes).ok().as_deref(),
+            Some(r#"["read","admin"]"#)
+        );
+        assert_eq!(to_string(&Scopes::EMPTY).ok().as_deref(), Some("[]"));
+        assert_eq!(
+            from_str::<Scopes>(r#"["admin","r","admin"]"#).ok(),
+            Some(scopes)
+        );
+        assert_eq!(
+            from_str::<Scopes>(r#"["read","other"]"#).map_err(|error| error.to_string()),
+            Err(r#"invalid value: string "other", expected one of "read", "write", "admin" at line 1 column 15"#.to_owned())
+        );
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        assert_eq!(Scopes::name(), "Scopes");
+        assert_eq!(
+            serde_json::to_value(Scopes::schema()).ok(),
+            Some(serde_json::json!({
+                "type": "array",
+                "items": {
+                    "type": "string",
+                    "enum": ["read", "write", "admin"],
+                },
+                "uniqueItems": true,
+            }))
+        );
+    }
+}
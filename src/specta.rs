@@ -0,0 +1,36 @@
+//! Specta integration.
+//!
+//! Constant strings are exported as string literal types.
+//!
+//! # Example
+//! ```
+//! # extern crate specta;
+//! #
+//! use constant_string::constant_string;
+//! use specta::{
+//!     Type,
+//!     ts::{ExportConfiguration, inline},
+//! };
+//!
+//! constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+//!
+//! #[derive(Type)]
+//! struct NotFoundError {
+//!     code: NotFoundErrorCode,
+//! }
+//!
+//! let config = ExportConfiguration::default();
+//! assert_eq!(inline::<NotFoundErrorCode>(&config).expect("exportable type"), r#""notFound""#);
+//! assert_eq!(inline::<NotFoundError>(&config).expect("exportable type"), r#"{ code: "notFound" }"#);
+//! ```
+
+use specta::{DataType, LiteralType};
+
+use crate::ConstantString;
+
+/// Data type of the constant string `C`, as a string literal type.
+///
+/// Used by the [`Type`](specta::Type) implementation of [`constant_string`](crate::constant_string).
+pub fn data_type<C: ConstantString>() -> DataType {
+    LiteralType::String(C::VALUE.to_owned()).into()
+}
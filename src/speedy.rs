@@ -0,0 +1,102 @@
+//! Speedy integration.
+//!
+//! Constant strings are written as a length-prefixed string and the read value is validated against their
+//! [`Options`](crate::Options).
+
+use std::borrow::Cow;
+
+use speedy::{Context, Error, Readable, Reader, Writable, Writer};
+
+use crate::ConstantString;
+
+/// Write the constant string `C` as a string.
+///
+/// Used by the [`Writable`] implementation of [`constant_string`](crate::constant_string).
+pub fn write_to<C: ConstantString, X: Context, W: ?Sized + Writer<X>>(
+    writer: &mut W,
+) -> Result<(), X::Error> {
+    C::VALUE.write_to(writer)
+}
+
+/// Number of bytes needed to write the constant string `C`.
+///
+/// Used by the [`Writable`] implementation of [`constant_string`](crate::constant_string).
+pub fn bytes_needed<C: ConstantString, X: Context>() -> Result<usize, X::Error> {
+    Writable::<X>::bytes_needed(C::VALUE)
+}
+
+/// Read the constant string `C` from a string, failing with a [`ParseError`](crate::ParseError) message on a
+/// mismatch.
+///
+/// Used by the [`Readable`] implementation of [`constant_string`](crate::constant_string).
+pub fn read_from<'a, C: ConstantString, X: Context, R: Reader<'a, X>>(
+    reader: &mut R,
+) -> Result<C, X::Error> {
+    let value: Cow<'a, str> = reader.read_value()?;
+
+    crate::parse(&value).map_err(|error| Error::custom(error).into())
+}
+
+/// Minimum number of bytes needed to read the constant string `C`.
+///
+/// Used by the [`Readable`] implementation of [`constant_string`](crate::constant_string).
+pub fn minimum_bytes_needed<X: Context>() -> usize {
+    <String as Readable<'static, X>>::minimum_bytes_needed()
+}
+
+#[cfg(test)]
+mod tests {
+    use speedy::{LittleEndian, Readable, Writable};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[derive(Debug, PartialEq, Readable, Writable)]
+    struct Error {
+        code: NotFound,
+        status: u16,
+    }
+
+    #[test]
+    fn write() {
+        let error = Error {
+            code: NotFound,
+            status: 404,
+        };
+        let bytes = error
+            .write_to_vec_with_ctx(LittleEndian {})
+            .expect("writable value");
+
+        assert_eq!(bytes, b"\x08\0\0\0notFound\x94\x01");
+        assert_eq!(
+            Writable::<LittleEndian>::bytes_needed(&NotFound).expect("writable value"),
+            12
+        );
+        assert_eq!(
+            Error::read_from_buffer_with_ctx(LittleEndian {}, &bytes).expect("readable value"),
+            error
+        );
+    }
+
+    #[test]
+    fn read() {
+        let bytes = "not_found"
+            .write_to_vec_with_ctx(LittleEndian {})
+            .expect("writable value");
+        assert!(NotFound::read_from_buffer_with_ctx(LittleEndian {}, &bytes).is_ok());
+        assert!(
+            NotFound::read_from_stream_unbuffered_with_ctx(LittleEndian {}, &bytes[..]).is_ok()
+        );
+
+        let bytes = "missing"
+            .write_to_vec_with_ctx(LittleEndian {})
+            .expect("writable value");
+        let error = NotFound::read_from_buffer_with_ctx(LittleEndian {}, &bytes)
+            .expect_err("mismatched value");
+        assert_eq!(
+            error.to_string(),
+            r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! SQLx integration.
+//!
+//! Constant strings are encoded as text and validated against their [`Options`](crate::Options) on decode, for any
+//! database where `str` is a SQLx type, such as Postgres, MySQL and SQLite.
+
+use sqlx::{Database, Decode, Encode, encode::IsNull, error::BoxDynError};
+
+use crate::ConstantString;
+
+/// Encode the constant string `C` as text.
+///
+/// Used by the [`Encode`] implementation of [`constant_string`](crate::constant_string).
+pub fn encode<'q, C, DB>(buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError>
+where
+    C: ConstantString,
+    DB: Database,
+    &'q str: Encode<'q, DB>,
+{
+    <&str as Encode<'q, DB>>::encode_by_ref(&C::VALUE, buf)
+}
+
+/// Decode the constant string `C` from text, failing with a [`ParseError`](crate::ParseError) on a mismatch.
+///
+/// Used by the [`Decode`] implementation of [`constant_string`](crate::constant_string).
+pub fn decode<'r, C, DB>(value: DB::ValueRef<'r>) -> Result<C, BoxDynError>
+where
+    C: ConstantString,
+    DB: Database,
+    &'r str: Decode<'r, DB>,
+{
+    let value = <&str as Decode<'r, DB>>::decode(value)?;
+
+    Ok(crate::parse(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Connection, SqliteConnection};
+
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[tokio::test]
+    async fn sqlite() {
+        let mut connection = SqliteConnection::connect("sqlite::memory:")
+            .await
+            .expect("in-memory database");
+
+        let constant: (NotFound, String) = sqlx::query_as("SELECT ?, ?")
+            .bind(NotFound)
+            .bind(NotFound)
+            .fetch_one(&mut connection)
+            .await
+            .expect("decodable row");
+        assert_eq!(constant, (NotFound, "notFound".to_owned()));
+
+        let alias: NotFound = sqlx::query_scalar("SELECT 'not_found'")
+            .fetch_one(&mut connection)
+            .await
+            .expect("decodable row");
+        assert_eq!(alias, NotFound);
+
+        let error = sqlx::query_scalar::<_, NotFound>("SELECT 'missing'")
+            .fetch_one(&mut connection)
+            .await
+            .expect_err("mismatched value");
+        assert!(
+            error.to_string().contains(
+                r#"invalid value "missing", expected constant "notFound" or alias "not_found" for NotFound"#
+            ),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::{error::Error, fmt};
+
+/// Error parsing an enum defined by [`constant_tagged_enum`](crate::constant_tagged_enum) from a value that doesn't
+/// match any of its tags.
+#[derive(Clone, Debug)]
+pub struct TagError {
+    name: &'static str,
+    tags: &'static [&'static str],
+    found: String,
+}
+
+impl TagError {
+    #[doc(hidden)]
+    pub fn new(name: &'static str, tags: &'static [&'static str], found: &str) -> Self {
+        Self {
+            name,
+            tags,
+            found: found.to_owned(),
+        }
+    }
+
+    /// Name of the enum.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Values of the tags of the enum.
+    pub fn tags(&self) -> &'static [&'static str] {
+        self.tags
+    }
+
+    /// Value that didn't match.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl fmt::Display for TagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown tag {:?}, expected ", self.found)?;
+
+        for (index, tag) in self.tags.iter().enumerate() {
+            match index {
+                0 => {}
+                index if index + 1 == self.tags.len() => f.write_str(" or ")?,
+                _ => f.write_str(", ")?,
+            }
+            write!(f, "{tag:?}")?;
+        }
+
+        write!(f, " for {}", self.name)
+    }
+}
+
+impl Error for TagError {}
+
+/// Define an enum with variants tagged by constant string types.
+///
+/// Each variant wraps a payload with a property holding its constant string tag, such as a
+/// [`ConstantError`](crate::ConstantError). The enum gets `TAGS` with the values of the tags, `code()` returning the
+/// tag of the variant, and [`FromStr`](core::str::FromStr) over the tags if every payload implements [`Default`].
+///
+/// With the `serde` feature, the enum is serialized as its payload and deserialized as the first variant whose
+/// payload matches, which the constant string tags make unambiguous. This requires `serde` as a direct dependency, as
+/// its derive macros refer to it by name. With the `utoipa` feature, its schema is a `oneOf` of the payloads
+/// discriminated by the `tag` property, and the payloads are registered as components.
+///
+/// # Example
+#[cfg_attr(all(feature = "serde", feature = "utoipa"), doc = "```")]
+#[cfg_attr(not(all(feature = "serde", feature = "utoipa")), doc = "```ignore")]
+/// # extern crate serde;
+/// # extern crate utoipa;
+/// #
+/// use constant_string::{constant_error, constant_tagged_enum};
+/// use utoipa::OpenApi;
+///
+/// constant_error!(NotFoundError, NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound", status = 404, message = "Entity not found");
+/// constant_error!(ConflictError, ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict", status = 409, message = "Entity exists");
+///
+/// constant_tagged_enum! {
+///     /// Error of an entity.
+///     #[derive(Debug)]
+///     pub enum EntityError(tag = "code") {
+///         NotFound(NotFoundError) = NotFoundErrorCode,
+///         Conflict(ConflictError) = ConflictErrorCode,
+///     }
+/// }
+///
+/// let error: EntityError = "conflict".parse().expect("known tag");
+/// assert_eq!(error.code(), "conflict");
+/// assert_eq!(
+///     serde_json::to_string(&error).expect("serializable value"),
+///     r#"{"code":"conflict","message":"Entity exists"}"#
+/// );
+/// assert!(matches!(
+///     serde_json::from_str(r#"{"code":"notFound","message":"Missing"}"#),
+///     Ok(EntityError::NotFound(_))
+/// ));
+///
+/// #[derive(OpenApi)]
+/// #[openapi(components(schemas(EntityError)))]
+/// struct ApiDoc;
+/// ```
+#[macro_export]
+macro_rules! constant_tagged_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident(tag = $tag:literal) {
+            $($(#[$variant_meta:meta])* $variant:ident($payload:ty) = $constant:ty),+ $(,)?
+        }
+    ) => {
+        $crate::constant_tagged_enum_serde! {
+            $(#[$meta])*
+            $vis enum $name {
+                $($(#[$variant_meta])* $variant($payload)),+
+            }
+        }
+
+        #[allow(dead_code, deprecated)]
+        impl $name {
+            /// Values of the tags of the variants.
+            pub const TAGS: &'static [&'static str] =
+                &[$(<$constant as $crate::ConstantString>::VALUE),+];
+
+            /// Value of the tag of the variant.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => <$constant as $crate::ConstantString>::VALUE),+
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::str::FromStr for $name
+        where
+            $(for<'a> $payload: ::core::default::Default),+
+        {
+            type Err = $crate::TagError;
+
+            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                $(
+                    if $crate::__private::matches_constant::<$constant>(value) {
+                        return ::core::result::Result::Ok(Self::$variant(
+                            ::core::default::Default::default(),
+                        ));
+                    }
+                )+
+
+                ::core::result::Result::Err($crate::TagError::new(
+                    ::core::stringify!($name),
+                    Self::TAGS,
+                    value,
+                ))
+            }
+        }
+
+        $crate::constant_tagged_enum_utoipa!($name, $tag, $($payload = $constant),+);
+    };
+}
+
+/// Define a tagged enum implementing [`serde`] traits.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_tagged_enum_serde {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $($(#[$variant_meta:meta])* $variant:ident($payload:ty)),+ }) => {
+        $(#[$meta])*
+        #[derive($crate::__private::serde::Deserialize, $crate::__private::serde::Serialize)]
+        #[serde(untagged)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant($payload)),+
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_tagged_enum_serde {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $($(#[$variant_meta:meta])* $variant:ident($payload:ty)),+ }) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant($payload)),+
+        }
+    };
+}
+
+/// Implement [`utoipa`] traits for a tagged enum.
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_tagged_enum_utoipa {
+    ($name:ident, $tag:literal, $($payload:ty = $constant:ty),+) => {
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa::PartialSchema for $name {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<
+                $crate::__private::utoipa::openapi::schema::Schema,
+            > {
+                $crate::constant_one_of!($tag, $($constant => $payload),+)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::__private::utoipa::ToSchema for $name {
+            fn schemas(
+                schemas: &mut ::std::vec::Vec<(
+                    ::std::string::String,
+                    $crate::__private::utoipa::openapi::RefOr<
+                        $crate::__private::utoipa::openapi::schema::Schema,
+                    >,
+                )>,
+            ) {
+                $(
+                    schemas.push((
+                        <$payload as $crate::__private::utoipa::ToSchema>::name().into_owned(),
+                        <$payload as $crate::__private::utoipa::PartialSchema>::schema(),
+                    ));
+                    <$payload as $crate::__private::utoipa::ToSchema>::schemas(schemas);
+                )+
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! constant_tagged_enum_utoipa {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constant_error;
+
+    constant_error!(
+        NotFoundError,
+        NotFoundErrorCode,
+        NOT_FOUND_ERROR_CODE,
+        "notFound",
+        status = 404,
+        message = "Entity not found",
+        aliases = ["not_found"],
+    );
+    constant_error!(
+        ConflictError,
+        ConflictErrorCode,
+        CONFLICT_ERROR_CODE,
+        "conflict",
+        status = 409,
+        message = "Entity exists",
+    );
+
+    constant_tagged_enum! {
+        #[derive(Debug, PartialEq)]
+        enum EntityError(tag = "code") {
+            NotFound(NotFoundError) = NotFoundErrorCode,
+            Conflict(ConflictError) = ConflictErrorCode,
+        }
+    }
+
+    #[test]
+    fn constant_tagged_enum() {
+        assert_eq!(EntityError::TAGS, ["notFound", "conflict"]);
+        assert_eq!(
+            EntityError::Conflict(ConflictError::default()).code(),
+            "conflict"
+        );
+        assert_eq!(
+            "not_found".parse::<EntityError>().ok(),
+            Some(EntityError::NotFound(NotFoundError::default()))
+        );
+        assert_eq!(
+            "missing"
+                .parse::<EntityError>()
+                .map_err(|error| error.to_string())
+                .err()
+                .as_deref(),
+            Some(r#"unknown tag "missing", expected "notFound" or "conflict" for EntityError"#)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json::{from_str, to_string};
+
+        assert_eq!(
+            to_string(&EntityError::NotFound(NotFoundError::default()))
+                .ok()
+                .as_deref(),
+            Some(r#"{"code":"notFound","message":"Entity not found"}"#)
+        );
+        assert_eq!(
+            from_str::<EntityError>(r#"{"code":"conflict","message":"Exists"}"#).ok(),
+            Some(EntityError::Conflict(ConflictError::with_message("Exists")))
+        );
+        assert!(from_str::<EntityError>(r#"{"code":"missing","message":"Missing"}"#).is_err());
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        let mut schemas = Vec::new();
+        EntityError::schemas(&mut schemas);
+        assert_eq!(
+            schemas
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            ["NotFoundError", "ConflictError"]
+        );
+        assert_eq!(
+            serde_json::to_value(EntityError::schema())
+                .ok()
+                .and_then(|schema| schema.get("discriminator").cloned()),
+            Some(serde_json::json!({
+                "propertyName": "code",
+                "mapping": {
+                    "notFound": "#/components/schemas/NotFoundError",
+                    "conflict": "#/components/schemas/ConflictError",
+                },
+            }))
+        );
+    }
+}
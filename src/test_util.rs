@@ -0,0 +1,85 @@
+//! Test utilities.
+//!
+//! # Example
+//! ```
+//! use constant_string::{constant_string, test_util};
+//!
+//! constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound", aliases = ["not_found"]);
+//!
+//! test_util::assert_roundtrip::<NotFoundErrorCode>();
+//! test_util::assert_rejects::<NotFoundErrorCode>("NOT_FOUND");
+//! ```
+
+use std::fmt::Debug;
+
+use serde::{
+    Deserialize, Serialize,
+    de::value::{Error, StrDeserializer},
+};
+use serde_test::{Configure, Token, assert_de_tokens, assert_tokens};
+
+use crate::ConstantString;
+
+/// Tokens of the constant string `C` for human-readable formats.
+pub fn tokens<C: ConstantString>() -> [Token; 1] {
+    [Token::Str(C::VALUE)]
+}
+
+/// Tokens of the constant string `C` for formats that are not human-readable.
+pub fn compact_tokens<C: ConstantString>() -> [Token; 1] {
+    if C::OPTIONS.compact {
+        [Token::Unit]
+    } else {
+        tokens::<C>()
+    }
+}
+
+/// Assert that the constant string `C` serializes to and deserializes from its [`tokens`] and [`compact_tokens`], and
+/// deserializes from each alias.
+pub fn assert_roundtrip<C>()
+where
+    C: ConstantString + Debug + PartialEq + Serialize + for<'de> Deserialize<'de>,
+{
+    assert_tokens(&C::default().readable(), &tokens::<C>());
+    assert_tokens(&C::default().compact(), &compact_tokens::<C>());
+
+    for alias in C::OPTIONS.aliases {
+        assert_de_tokens(&C::default().readable(), &[Token::Str(alias)]);
+    }
+}
+
+/// Assert that the constant string `C` fails to deserialize from `input`.
+pub fn assert_rejects<C>(input: &str)
+where
+    C: ConstantString + for<'de> Deserialize<'de>,
+{
+    assert!(
+        C::deserialize(StrDeserializer::<Error>::new(input)).is_err(),
+        "expected {} to reject {:?}",
+        C::NAME,
+        input
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::Token;
+
+    use super::{assert_rejects, assert_roundtrip, compact_tokens, tokens};
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+    constant_string!(Compact, COMPACT, "compact", compact);
+
+    #[test]
+    fn test_util() {
+        assert_roundtrip::<NotFound>();
+        assert_roundtrip::<Compact>();
+        assert_rejects::<NotFound>("NOT_FOUND");
+        assert_rejects::<Compact>("other");
+
+        assert_eq!(tokens::<NotFound>(), [Token::Str("notFound")]);
+        assert_eq!(compact_tokens::<NotFound>(), [Token::Str("notFound")]);
+        assert_eq!(compact_tokens::<Compact>(), [Token::Unit]);
+    }
+}
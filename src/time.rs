@@ -0,0 +1,75 @@
+//! Time integration.
+
+/// Implement a constant string containing a [`time`](https://docs.rs/time/latest/time/) format description.
+///
+/// The format description is parsed at compile time and available as the `FORMAT` associated constant. All other
+/// behavior is identical to [`constant_string`](crate::constant_string).
+///
+/// Unlike the other integrations, `time` must be a direct dependency, as its `format_description!` macro expands to
+/// `::time` paths.
+///
+/// # Example
+/// ```
+/// # extern crate time;
+/// #
+/// use constant_string::constant_time_format;
+/// use time::macros::date;
+///
+/// constant_time_format!(DateFormat, DATE_FORMAT, "[year]-[month]-[day]");
+///
+/// assert_eq!(
+///     date!(2024 - 12 - 31).format(DateFormat::FORMAT).expect("formattable date"),
+///     "2024-12-31"
+/// );
+/// ```
+///
+/// Invalid format descriptions fail to compile:
+/// ```compile_fail
+/// # use constant_string::constant_time_format;
+/// #
+/// constant_time_format!(DateFormat, DATE_FORMAT, "[year]-[month]-[dya]");
+/// ```
+#[macro_export]
+macro_rules! constant_time_format {
+    // `format_description!` requires a plain string literal token, which `literal` fragments are not.
+    ($name:ident, $code_name:ident, $code:tt) => {
+        $crate::constant_string!($name, $code_name, $code);
+
+        impl $name {
+            #[doc = ::core::concat!("Parsed format description `", $code, "`.")]
+            pub const FORMAT: &'static [::time::format_description::BorrowedFormatItem<'static>] =
+                ::time::macros::format_description!($code);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use time::macros::{datetime, format_description};
+
+    constant_time_format!(
+        TimestampFormat,
+        TIMESTAMP_FORMAT,
+        "[year]-[month]-[day]T[hour]:[minute]:[second]"
+    );
+
+    #[test]
+    fn constant_time_format() {
+        assert_eq!(
+            TimestampFormat.deref(),
+            "[year]-[month]-[day]T[hour]:[minute]:[second]"
+        );
+        assert_eq!(
+            TimestampFormat::FORMAT,
+            format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]")
+        );
+        assert_eq!(
+            datetime!(2024-12-31 23:59:58)
+                .format(TimestampFormat::FORMAT)
+                .expect("formattable date time"),
+            "2024-12-31T23:59:58"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! ts-rs integration.
+//!
+//! Constant strings are exported as TypeScript string literal types.
+//!
+//! # Example
+//! ```
+//! # extern crate ts_rs;
+//! #
+//! use constant_string::constant_string;
+//! use ts_rs::{Config, TS};
+//!
+//! constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+//!
+//! #[derive(TS)]
+//! struct NotFoundError {
+//!     code: NotFoundErrorCode,
+//! }
+//!
+//! let config = Config::default();
+//! assert_eq!(NotFoundErrorCode::decl(&config), r#"type NotFoundErrorCode = "notFound";"#);
+//! assert_eq!(NotFoundError::inline(&config), "{ code: NotFoundErrorCode, }");
+//! ```
+
+use std::path::PathBuf;
+
+use crate::{ConstantString, typescript};
+
+/// TypeScript type of the constant string `C`, as a string literal type.
+///
+/// Used by the [`TS`](ts_rs::TS) implementation of [`constant_string`](crate::constant_string).
+pub fn inline<C: ConstantString>() -> String {
+    typescript::string_literal(C::VALUE)
+}
+
+/// TypeScript declaration of the constant string `C`, as an alias of its string literal type.
+///
+/// Used by the [`TS`](ts_rs::TS) implementation of [`constant_string`](crate::constant_string).
+pub fn decl<C: ConstantString>() -> String {
+    format!("type {} = {};", C::NAME, inline::<C>())
+}
+
+/// Path the declaration of the constant string `C` is exported to, relative to the export directory.
+///
+/// Used by the [`TS`](ts_rs::TS) implementation of [`constant_string`](crate::constant_string).
+pub fn output_path<C: ConstantString>() -> PathBuf {
+    PathBuf::from(format!("{}.ts", C::NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use ts_rs::{Config, TS};
+
+    use crate::constant_string;
+
+    constant_string!(
+        NotFound,
+        NOT_FOUND,
+        "notFound",
+        description = "Resource not found."
+    );
+
+    #[test]
+    fn ts() {
+        let config = Config::default();
+
+        assert_eq!(NotFound::name(&config), "NotFound");
+        assert_eq!(NotFound::inline(&config), r#""notFound""#);
+        assert_eq!(NotFound::decl(&config), r#"type NotFound = "notFound";"#);
+        assert_eq!(NotFound::docs().as_deref(), Some("Resource not found."));
+        assert_eq!(
+            NotFound::output_path(),
+            Some(std::path::PathBuf::from("NotFound.ts"))
+        );
+    }
+}
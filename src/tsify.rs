@@ -0,0 +1,206 @@
+//! Tsify integration.
+//!
+//! Constant strings are declared as string literal types in the TypeScript generated by `wasm-bindgen`, so they can
+//! be used as fields of types deriving [`Tsify`](tsify::Tsify). Values are escaped as JSON strings, like the string
+//! literals of the TypeScript codegen.
+//!
+//! # Example
+//! ```
+//! # extern crate tsify;
+//! #
+//! use constant_string::constant_string;
+//! use tsify::Tsify;
+//!
+//! constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+//!
+//! assert_eq!(NotFoundErrorCode::DECL, r#"export type NotFoundErrorCode = "notFound";"#);
+//! ```
+
+const DECL_PREFIX: &[u8] = b"export type ";
+const DECL_INFIX: &[u8] = b" = ";
+const DECL_SUFFIX: &[u8] = b";";
+
+/// Length of the declaration of the type `name` as the string literal type of `value`.
+///
+/// Used by the [`Tsify`](tsify::Tsify) implementation of [`constant_string`](crate::constant_string).
+pub const fn decl_len(name: &str, value: &str) -> usize {
+    let bytes = value.as_bytes();
+    let mut len = DECL_PREFIX.len() + name.len() + DECL_INFIX.len() + 2 + DECL_SUFFIX.len();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let (escape, width) = escape(bytes, index);
+        len += match escape {
+            Escape::None => width,
+            Escape::Char(_) => 2,
+            Escape::Unicode(_) => 6,
+        };
+        index += width;
+    }
+
+    len
+}
+
+/// Declaration of the type `name` as the string literal type of `value`, of length [`decl_len`].
+///
+/// The value is escaped as a JSON string, like the string literals of the TypeScript codegen. Used by the
+/// [`Tsify`](tsify::Tsify) implementation of [`constant_string`](crate::constant_string).
+pub const fn decl<const N: usize>(name: &str, value: &str) -> [u8; N] {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    let mut decl = [0; N];
+    let mut at = write(&mut decl, 0, DECL_PREFIX);
+    at = write(&mut decl, at, name.as_bytes());
+    at = write(&mut decl, at, DECL_INFIX);
+    at = write(&mut decl, at, b"\"");
+
+    let bytes = value.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        let (escape, width) = escape(bytes, index);
+        match escape {
+            Escape::None => {
+                let mut offset = 0;
+                while offset < width {
+                    decl[at] = bytes[index + offset];
+                    at += 1;
+                    offset += 1;
+                }
+            }
+            Escape::Char(c) => {
+                at = write(&mut decl, at, &[b'\\', c]);
+            }
+            Escape::Unicode(code) => {
+                at = write(
+                    &mut decl,
+                    at,
+                    &[
+                        b'\\',
+                        b'u',
+                        HEX[(code >> 12) as usize & 0xf],
+                        HEX[(code >> 8) as usize & 0xf],
+                        HEX[(code >> 4) as usize & 0xf],
+                        HEX[code as usize & 0xf],
+                    ],
+                );
+            }
+        }
+        index += width;
+    }
+
+    at = write(&mut decl, at, b"\"");
+    write(&mut decl, at, DECL_SUFFIX);
+
+    decl
+}
+
+/// Declaration written by [`decl`] as a string.
+///
+/// Used by the [`Tsify`](tsify::Tsify) implementation of [`constant_string`](crate::constant_string).
+pub const fn decl_str(decl: &'static [u8]) -> &'static str {
+    match str::from_utf8(decl) {
+        Ok(decl) => decl,
+        // Escapes are ASCII and the rest is copied from a string, so the declaration is valid UTF-8.
+        Err(_) => "",
+    }
+}
+
+/// Escape of a character in a TypeScript string literal.
+enum Escape {
+    None,
+    Char(u8),
+    Unicode(u16),
+}
+
+/// Escape of the character starting at `index` of `bytes`, with the number of bytes it covers.
+///
+/// Quotes, backslashes, control characters and line separators are escaped, like the TypeScript codegen.
+const fn escape(bytes: &[u8], index: usize) -> (Escape, usize) {
+    match bytes[index] {
+        b'"' => (Escape::Char(b'"'), 1),
+        b'\\' => (Escape::Char(b'\\'), 1),
+        b'\n' => (Escape::Char(b'n'), 1),
+        b'\r' => (Escape::Char(b'r'), 1),
+        b'\t' => (Escape::Char(b't'), 1),
+        byte @ (0x00..=0x1f | 0x7f) => (Escape::Unicode(byte as u16), 1),
+        // U+0080 to U+009F, encoded as `C2 80` to `C2 9F`.
+        0xc2 if index + 1 < bytes.len() && bytes[index + 1] <= 0x9f => {
+            (Escape::Unicode(bytes[index + 1] as u16), 2)
+        }
+        // U+2028 and U+2029, encoded as `E2 80 A8` and `E2 80 A9`.
+        0xe2 if index + 2 < bytes.len()
+            && bytes[index + 1] == 0x80
+            && matches!(bytes[index + 2], 0xa8 | 0xa9) =>
+        {
+            (
+                Escape::Unicode(0x2000 | (bytes[index + 2] - 0x80) as u16),
+                3,
+            )
+        }
+        _ => (Escape::None, 1),
+    }
+}
+
+/// Write `bytes` to `buffer` at `at`, returning the index after them.
+const fn write<const N: usize>(buffer: &mut [u8; N], at: usize, bytes: &[u8]) -> usize {
+    let mut index = 0;
+    while index < bytes.len() {
+        buffer[at + index] = bytes[index];
+        index += 1;
+    }
+    at + bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use tsify::Tsify;
+
+    use crate::constant_string;
+
+    #[test]
+    fn escaped_decl() {
+        constant_string!(Plain, PLAIN, "not-found é");
+        constant_string!(Quoted, QUOTED, r#"say "hi""#);
+        constant_string!(Backslash, BACKSLASH, r"a\b");
+        constant_string!(Control, CONTROL, "a\nb\t\u{1}\u{7f}");
+        constant_string!(C1Control, C1_CONTROL, "a\u{85}b");
+        constant_string!(LineSeparator, LINE_SEPARATOR, "a\u{2028}b\u{2029}");
+
+        assert_eq!(Plain::DECL, r#"export type Plain = "not-found é";"#);
+        assert_eq!(Quoted::DECL, r#"export type Quoted = "say \"hi\"";"#);
+        assert_eq!(Backslash::DECL, r#"export type Backslash = "a\\b";"#);
+        assert_eq!(
+            Control::DECL,
+            r#"export type Control = "a\nb\t\u0001\u007f";"#
+        );
+        assert_eq!(C1Control::DECL, r#"export type C1Control = "a\u0085b";"#);
+        assert_eq!(
+            LineSeparator::DECL,
+            r#"export type LineSeparator = "a\u2028b\u2029";"#
+        );
+    }
+
+    #[cfg(any(feature = "codegen", feature = "ts-rs"))]
+    #[test]
+    fn typescript_string_literal() {
+        use crate::typescript::string_literal;
+
+        constant_string!(
+            Escaped,
+            ESCAPED,
+            "\"\\\n\r\t\u{1}\u{7f}\u{85}\u{2028}\u{2029} é"
+        );
+
+        assert_eq!(
+            Escaped::DECL,
+            format!("export type Escaped = {};", string_literal(ESCAPED))
+        );
+    }
+
+    #[test]
+    fn decl() {
+        constant_string!(NotFound, NOT_FOUND, "notFound");
+
+        assert_eq!(NotFound::DECL, r#"export type NotFound = "notFound";"#);
+    }
+}
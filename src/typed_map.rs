@@ -0,0 +1,186 @@
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+use crate::{ConstantString, parse::matches_constant};
+
+/// Map with constant string types as keys.
+///
+/// Values are inserted and looked up by type, and looked up at runtime by string, matching the value or aliases of
+/// the key under its [`Options`](crate::Options). Keys are identified by their value, so types with the same value
+/// share an entry.
+///
+/// # Example
+/// ```
+/// use constant_string::{TypedMap, constant_string};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound", aliases = ["not_found"]);
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// let mut handlers = TypedMap::<fn() -> u16>::new();
+/// handlers.insert::<NotFoundErrorCode>(|| 404);
+/// handlers.insert::<ConflictErrorCode>(|| 409);
+///
+/// assert_eq!(handlers.get::<NotFoundErrorCode>().map(|handler| handler()), Some(404));
+/// assert_eq!(handlers.get_by_str("not_found").map(|handler| handler()), Some(404));
+/// assert!(handlers.get_by_str("missing").is_none());
+/// ```
+#[derive(Clone)]
+pub struct TypedMap<V> {
+    entries: BTreeMap<&'static str, Entry<V>>,
+}
+
+#[derive(Clone)]
+struct Entry<V> {
+    matches: fn(&str) -> bool,
+    value: V,
+}
+
+impl<V> TypedMap<V> {
+    /// Empty map.
+    pub const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Insert a value for the key `C`, returning the previous value.
+    pub fn insert<C: ConstantString>(&mut self, value: V) -> Option<V> {
+        self.entries
+            .insert(
+                C::VALUE,
+                Entry {
+                    matches: matches_constant::<C>,
+                    value,
+                },
+            )
+            .map(|entry| entry.value)
+    }
+
+    /// Value for the key `C`.
+    pub fn get<C: ConstantString>(&self) -> Option<&V> {
+        self.entries.get(C::VALUE).map(|entry| &entry.value)
+    }
+
+    /// Mutable value for the key `C`.
+    pub fn get_mut<C: ConstantString>(&mut self) -> Option<&mut V> {
+        self.entries.get_mut(C::VALUE).map(|entry| &mut entry.value)
+    }
+
+    /// Remove the value for the key `C`, returning it.
+    pub fn remove<C: ConstantString>(&mut self) -> Option<V> {
+        self.entries.remove(C::VALUE).map(|entry| entry.value)
+    }
+
+    /// Whether the map has a value for the key `C`.
+    pub fn contains<C: ConstantString>(&self) -> bool {
+        self.entries.contains_key(C::VALUE)
+    }
+
+    /// Value for the key matching `value`, its value or one of its aliases under its options.
+    pub fn get_by_str(&self, value: &str) -> Option<&V> {
+        self.key(value)
+            .and_then(|key| self.entries.get(key))
+            .map(|entry| &entry.value)
+    }
+
+    /// Mutable value for the key matching `value`, its value or one of its aliases under its options.
+    pub fn get_by_str_mut(&mut self, value: &str) -> Option<&mut V> {
+        self.key(value)
+            .and_then(|key| self.entries.get_mut(key))
+            .map(|entry| &mut entry.value)
+    }
+
+    /// Number of keys.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Values of the keys, with the key values, in order of the key values.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &V)> {
+        self.entries.iter().map(|(key, entry)| (*key, &entry.value))
+    }
+
+    /// Key matching `value`, preferring an exact match.
+    fn key(&self, value: &str) -> Option<&'static str> {
+        self.entries
+            .get_key_value(value)
+            .or_else(|| {
+                self.entries
+                    .iter()
+                    .find(|(_, entry)| (entry.matches)(value))
+            })
+            .map(|(key, _)| *key)
+    }
+}
+
+impl<V> Default for TypedMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for TypedMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypedMap;
+    use crate::constant_string;
+
+    constant_string!(
+        NotFoundErrorCode,
+        NOT_FOUND_ERROR_CODE,
+        "notFound",
+        aliases = ["not_found"],
+    );
+    constant_string!(
+        ConflictErrorCode,
+        CONFLICT_ERROR_CODE,
+        "conflict",
+        case_insensitive,
+    );
+    constant_string!(
+        OtherNotFoundErrorCode,
+        OTHER_NOT_FOUND_ERROR_CODE,
+        "notFound"
+    );
+
+    #[test]
+    fn typed_map() {
+        let mut map = TypedMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert::<NotFoundErrorCode>(404), None);
+        assert_eq!(map.insert::<ConflictErrorCode>(409), None);
+        assert_eq!(map.len(), 2);
+        assert!(map.contains::<NotFoundErrorCode>());
+
+        assert_eq!(map.get::<NotFoundErrorCode>(), Some(&404));
+        assert_eq!(map.get::<OtherNotFoundErrorCode>(), Some(&404));
+        assert_eq!(map.get_by_str("notFound"), Some(&404));
+        assert_eq!(map.get_by_str("not_found"), Some(&404));
+        assert_eq!(map.get_by_str("CONFLICT"), Some(&409));
+        assert_eq!(map.get_by_str("missing"), None);
+
+        if let Some(value) = map.get_by_str_mut("Conflict") {
+            *value = 410;
+        }
+        assert_eq!(map.get::<ConflictErrorCode>(), Some(&410));
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            [("conflict", &410), ("notFound", &404)]
+        );
+        assert_eq!(format!("{map:?}"), r#"{"conflict": 410, "notFound": 404}"#);
+
+        assert_eq!(map.remove::<NotFoundErrorCode>(), Some(404));
+        assert!(!map.contains::<NotFoundErrorCode>());
+        assert_eq!(map.get_by_str("not_found"), None);
+    }
+}
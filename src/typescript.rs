@@ -0,0 +1,36 @@
+//! Helpers shared by TypeScript integrations.
+
+/// TypeScript string literal of `value`, escaped as a JSON string.
+pub(crate) fn string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() || matches!(c, '\u{2028}' | '\u{2029}') => {
+                literal.push_str(&format!("\\u{:04x}", u32::from(c)));
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::string_literal;
+
+    #[test]
+    fn escaped_string_literal() {
+        assert_eq!(string_literal("notFound"), r#""notFound""#);
+        assert_eq!(string_literal(r#"say "hi"\"#), r#""say \"hi\"\\""#);
+        assert_eq!(string_literal("a\nb\u{1}"), r#""a\nb\u0001""#);
+    }
+}
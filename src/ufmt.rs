@@ -0,0 +1,90 @@
+//! Ufmt integration.
+//!
+//! Constant strings are displayed as their value and debugged as their quoted value, like their [`Display`] and
+//! [`Debug`] implementations, without the [`core::fmt`] machinery.
+//!
+//! [`Debug`]: core::fmt::Debug
+//! [`Display`]: core::fmt::Display
+
+use ufmt::{Formatter, uWrite};
+
+/// Write `value` quoted and escaped, like its [`Debug`](core::fmt::Debug) implementation.
+///
+/// Characters are escaped with [`char::escape_debug`], except single quotes which [`str`] leaves unescaped.
+///
+/// Used by the [`uDebug`](ufmt::uDebug) implementation of [`constant_string`](crate::constant_string), as ufmt
+/// doesn't implement it for [`str`].
+pub fn debug_str<W: uWrite + ?Sized>(
+    value: &str,
+    f: &mut Formatter<'_, W>,
+) -> Result<(), W::Error> {
+    f.write_char('"')?;
+    for c in value.chars() {
+        if c == '\'' {
+            f.write_char(c)?;
+        } else {
+            for c in c.escape_debug() {
+                f.write_char(c)?;
+            }
+        }
+    }
+    f.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use ufmt::{Formatter, uDebug, uWrite, uwrite};
+
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound");
+    constant_string!(Quoted, QUOTED, "say \"hi\"\n");
+    constant_string!(Apostrophe, APOSTROPHE, "it's");
+    constant_string!(Accented, ACCENTED, "\u{301}accented\u{301}");
+
+    #[test]
+    fn ufmt() {
+        let mut output = String::new();
+        uwrite!(
+            output,
+            "{} {:?} {} {}",
+            NotFound,
+            NotFound,
+            MaybeConstant(NotFound),
+            ConstantOr::<NotFound>::Other("other".to_owned())
+        )
+        .ok();
+        assert_eq!(output, r#"notFound "notFound" notFound other"#);
+
+        let mut output = String::new();
+        uwrite!(output, "{:?}", Quoted).ok();
+        assert_eq!(output, format!("{:?}", Quoted));
+    }
+
+    #[test]
+    fn debug() {
+        for value in [
+            NOT_FOUND,
+            QUOTED,
+            APOSTROPHE,
+            ACCENTED,
+            "\u{0}\u{7f}\u{85}\u{200b}\u{feff}",
+        ] {
+            let mut output = String::new();
+            uwrite!(output, "{:?}", Debugged(value)).ok();
+            assert_eq!(output, format!("{value:?}"));
+        }
+
+        let mut output = String::new();
+        uwrite!(output, "{:?}", Apostrophe).ok();
+        assert_eq!(output, r#""it's""#);
+    }
+
+    struct Debugged(&'static str);
+
+    impl uDebug for Debugged {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            super::debug_str(self.0, f)
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! UniFFI integration.
+//!
+//! UniFFI generates its scaffolding in the crate exposing the types, so constant strings are registered as
+//! [custom types](https://mozilla.github.io/uniffi-rs/latest/types/custom_types.html) with
+//! [`constant_custom_type`](crate::constant_custom_type) instead of by [`constant_string`](crate::constant_string).
+//! They are lowered as strings and lifted from strings, failing with a [`ParseError`] for other values.
+
+use crate::{ConstantString, ParseError, parse};
+
+/// Lower the constant string `C` to a string.
+///
+/// Used by the custom type conversion of [`constant_custom_type`](crate::constant_custom_type).
+pub fn lower<C: ConstantString>(_: C) -> String {
+    C::VALUE.to_owned()
+}
+
+/// Lift the constant string `C` from a string, applying its [`Options`](crate::Options).
+///
+/// Used by the custom type conversion of [`constant_custom_type`](crate::constant_custom_type).
+pub fn try_lift<C: ConstantString>(value: String) -> Result<C, ParseError> {
+    parse(&value)
+}
+
+/// Register constant string types as UniFFI custom types of `String`.
+///
+/// Expands to [`uniffi::custom_type`](https://docs.rs/uniffi/latest/uniffi/macro.custom_type.html) for each type,
+/// so it must be used in a crate depending on `uniffi` and calling `uniffi::setup_scaffolding!()`. For UDL, declare
+/// the types as `typedef string`.
+///
+/// # Example
+/// ```
+/// # extern crate uniffi;
+/// #
+/// use constant_string::{constant_custom_type, constant_string};
+/// use uniffi::{Lift, Lower};
+///
+/// uniffi::setup_scaffolding!();
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// constant_custom_type!(NotFoundErrorCode, ConflictErrorCode);
+///
+/// #[derive(uniffi::Record)]
+/// pub struct Error {
+///     code: NotFoundErrorCode,
+///     message: String,
+/// }
+///
+/// # fn main() {
+/// let buffer = <NotFoundErrorCode as Lower<UniFfiTag>>::lower(NotFoundErrorCode);
+/// assert!(<NotFoundErrorCode as Lift<UniFfiTag>>::try_lift(buffer).is_ok());
+///
+/// let buffer = <String as Lower<UniFfiTag>>::lower("notFound".to_owned());
+/// assert!(<ConflictErrorCode as Lift<UniFfiTag>>::try_lift(buffer).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! constant_custom_type {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            $crate::__private::uniffi::custom_type!($name, ::std::string::String, {
+                lower: |value| $crate::uniffi::lower(value),
+                try_lift: |value| ::core::result::Result::Ok($crate::uniffi::try_lift(value)?),
+            });
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lower, try_lift};
+    use crate::constant_string;
+
+    constant_string!(NotFound, NOT_FOUND, "notFound", aliases = ["not_found"]);
+
+    #[test]
+    fn lower_and_lift() {
+        assert_eq!(lower(NotFound), "notFound");
+        assert_eq!(
+            try_lift::<NotFound>("not_found".to_owned()).ok(),
+            Some(NotFound)
+        );
+        assert_eq!(
+            try_lift::<NotFound>("other".to_owned())
+                .map_err(|error| error.to_string())
+                .err()
+                .as_deref(),
+            Some(
+                r#"invalid value "other", expected constant "notFound" or alias "not_found" for NotFound"#
+            )
+        );
+    }
+}
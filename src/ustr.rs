@@ -0,0 +1,52 @@
+//! Ustr integration.
+//!
+//! Constant strings convert into [`Ustr`] by interning the constant once per type and caching the result, so later
+//! conversions are a load and comparisons with other interned strings are pointer comparisons.
+
+use ustr::Ustr;
+
+use crate::ConstantString;
+
+/// Intern the constant string `C` ahead of use, e.g. at startup, returning the interned string.
+pub fn pre_intern<C: ConstantString + Into<Ustr>>() -> Ustr {
+    C::default().into()
+}
+
+/// Intern `values` ahead of use, e.g. the values of a group of constant strings at startup.
+pub fn pre_intern_all<'a>(values: impl IntoIterator<Item = &'a str>) {
+    for value in values {
+        ustr::ustr(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ustr::{Ustr, existing_ustr, ustr};
+
+    use super::{pre_intern, pre_intern_all};
+    use crate::{ConstantOr, MaybeConstant, constant_string};
+
+    constant_string!(NotFound, NOT_FOUND, "notFound");
+    constant_string!(Conflict, CONFLICT, "conflictOfUstr");
+
+    fn interned(value: impl Into<Ustr>) -> Ustr {
+        value.into()
+    }
+
+    #[test]
+    fn intern() {
+        let not_found = pre_intern::<NotFound>();
+        assert_eq!(not_found, ustr("notFound"));
+        assert_eq!(interned(NotFound).as_char_ptr(), not_found.as_char_ptr());
+        assert_eq!(interned(MaybeConstant(NotFound)), not_found);
+        assert_eq!(interned(ConstantOr::<NotFound>::default()), not_found);
+        assert_eq!(
+            interned(ConstantOr::<NotFound>::Other("other".to_owned())),
+            ustr("other")
+        );
+
+        assert_eq!(existing_ustr("conflictOfUstr"), None);
+        pre_intern_all([CONFLICT]);
+        assert_eq!(existing_ustr("conflictOfUstr"), Some(interned(Conflict)));
+    }
+}
@@ -0,0 +1,581 @@
+//! Utoipa integration.
+
+use std::borrow::Cow;
+
+use utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{
+        ContentBuilder, Deprecated, Ref, RefOr, Required, ResponseBuilder,
+        extensions::ExtensionsBuilder,
+        path::{Parameter, ParameterBuilder, ParameterIn},
+        response::Response,
+        schema::{
+            ArrayBuilder, Discriminator, ObjectBuilder, OneOfBuilder, Schema, SchemaType, Type,
+        },
+    },
+};
+
+use crate::{ConstantErrorCode, ConstantString, schema};
+
+/// Schema of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`PartialSchema`](utoipa::PartialSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn schema<C: ConstantString>() -> RefOr<Schema> {
+    object::<C>(false).build().into()
+}
+
+/// Schema of the constant string `C` that also allows `null`, for optional fields.
+///
+/// Uses the OpenAPI 3.1 `type: [string, "null"]` representation, with `null` in the allowed values.
+///
+/// # Example
+/// ```
+/// # extern crate utoipa;
+/// #
+/// use constant_string::constant_string;
+/// use utoipa::ToSchema;
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+///
+/// #[derive(ToSchema)]
+/// struct NotFoundError {
+///     #[schema(schema_with = constant_string::utoipa::nullable_schema::<NotFoundErrorCode>)]
+///     code: Option<NotFoundErrorCode>,
+/// }
+/// ```
+pub fn nullable_schema<C: ConstantString>() -> RefOr<Schema> {
+    object::<C>(true).build().into()
+}
+
+fn object<C: ConstantString>(nullable: bool) -> ObjectBuilder {
+    let options = C::OPTIONS;
+    let builder = ObjectBuilder::new()
+        .schema_type(if nullable {
+            SchemaType::from_iter([Type::String, Type::Null])
+        } else {
+            SchemaType::Type(Type::String)
+        })
+        .title(options.title)
+        .description(options.description)
+        .deprecated(options.deprecated.then_some(Deprecated::True))
+        .default((!options.skip_schema_default).then(|| C::VALUE.into()))
+        .examples((!options.skip_schema_examples).then_some(C::VALUE))
+        .read_only(options.read_only.then_some(true))
+        .write_only(options.write_only.then_some(true))
+        .pattern(options.schema_pattern.then(|| schema::pattern(C::VALUE)))
+        .min_length(options.schema_length.then(|| schema::length(C::VALUE)))
+        .max_length(options.schema_length.then(|| schema::length(C::VALUE)));
+
+    let mut extensions = options
+        .extensions
+        .iter()
+        .fold(ExtensionsBuilder::new(), |extensions, (key, value)| {
+            extensions.add(*key, *value)
+        })
+        .build();
+
+    let builder = if options.skip_schema_enum {
+        builder
+    } else if nullable {
+        builder.enum_values(Some([Some(C::VALUE), None]))
+    } else if options.schema_const {
+        extensions.insert("const".to_owned(), C::VALUE.into());
+        builder
+    } else {
+        builder.enum_values(Some([C::VALUE]))
+    };
+
+    builder.extensions((!extensions.is_empty()).then_some(extensions))
+}
+
+/// Component name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the [`ToSchema`](utoipa::ToSchema) implementation of [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> Cow<'static, str> {
+    Cow::Borrowed(C::OPTIONS.schema_name.unwrap_or(C::NAME))
+}
+
+/// Parameter of the constant string `C`, with its schema inlined.
+///
+/// The parameter is named by the [`parameter_name`](crate::Options::parameter_name) option, or the type name, and
+/// is in the query unless `parameter_in` is given. Used by the [`IntoParams`](utoipa::IntoParams) implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn parameter<C: ConstantString>(parameter_in: Option<ParameterIn>) -> Parameter {
+    let options = C::OPTIONS;
+
+    ParameterBuilder::new()
+        .name(options.parameter_name.unwrap_or(C::NAME))
+        .parameter_in(parameter_in.unwrap_or(ParameterIn::Query))
+        .description(options.description)
+        .deprecated(options.deprecated.then_some(Deprecated::True))
+        .required(Required::True)
+        .schema(Some(schema::<C>()))
+        .build()
+}
+
+/// `oneOf` schema of components discriminated by a constant string property.
+///
+/// Each variant pairs the constant value of the discriminator property with the schema component name. See
+/// [`constant_one_of`](crate::constant_one_of) to derive both from types.
+pub fn one_of<I, N>(property_name: &str, variants: I) -> RefOr<Schema>
+where
+    I: IntoIterator<Item = (&'static str, N)>,
+    N: Into<String>,
+{
+    let mut builder = OneOfBuilder::new();
+    let mut mapping = Vec::new();
+
+    for (value, name) in variants {
+        let reference = Ref::from_schema_name(name);
+
+        mapping.push((value, reference.ref_location.clone()));
+        builder = builder.item(reference);
+    }
+
+    RefOr::T(Schema::OneOf(
+        builder
+            .discriminator(Some(Discriminator::with_mapping(property_name, mapping)))
+            .build(),
+    ))
+}
+
+/// String schema allowing any of the given constant values, for a field accepting a group of constants.
+///
+/// Used by the [`PartialSchema`](utoipa::PartialSchema) implementation of
+/// [`constant_enum_schema`](crate::constant_enum_schema).
+pub fn enum_schema<I>(values: I) -> RefOr<Schema>
+where
+    I: IntoIterator<Item = &'static str>,
+{
+    ObjectBuilder::new()
+        .schema_type(SchemaType::Type(Type::String))
+        .enum_values(Some(values))
+        .build()
+        .into()
+}
+
+/// Array schema of unique items allowing any of the given constant values, for a set of constants.
+///
+/// Used by the [`PartialSchema`](utoipa::PartialSchema) implementation of
+/// [`constant_string_set`](crate::constant_string_set).
+pub fn set_schema<I>(values: I) -> RefOr<Schema>
+where
+    I: IntoIterator<Item = &'static str>,
+{
+    ArrayBuilder::new()
+        .items(enum_schema(values))
+        .unique_items(true)
+        .build()
+        .into()
+}
+
+/// Object schema of a [`ConstantError`](crate::ConstantError) with the code `C`, using its default message as example.
+///
+/// Used by the [`PartialSchema`](utoipa::PartialSchema) implementation of [`ConstantError`](crate::ConstantError).
+pub fn error_schema<C: ConstantErrorCode + PartialSchema>() -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .property("code", C::schema())
+        .required("code")
+        .property(
+            "message",
+            ObjectBuilder::new()
+                .schema_type(SchemaType::Type(Type::String))
+                .examples([C::MESSAGE]),
+        )
+        .required("message")
+        .build()
+        .into()
+}
+
+/// Object schema of a [`CodedError`](crate::CodedError) with the code `C` inlined and the details `D`.
+///
+/// Used by the [`PartialSchema`](utoipa::PartialSchema) implementation of [`CodedError`](crate::CodedError).
+pub fn coded_error_schema<C: ConstantString + PartialSchema, D: PartialSchema>() -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .property("code", C::schema())
+        .required("code")
+        .property(
+            "message",
+            ObjectBuilder::new().schema_type(SchemaType::Type(Type::String)),
+        )
+        .property("details", D::schema())
+        .build()
+        .into()
+}
+
+/// Object schema of an [`Event`](crate::Event) with the type `T` inlined and the payload `P` as the `data`.
+///
+/// Used by the [`PartialSchema`](utoipa::PartialSchema) implementation of [`Event`](crate::Event).
+pub fn event_schema<T: ConstantString + PartialSchema, P: PartialSchema>() -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .property("type", T::schema())
+        .required("type")
+        .property("data", P::schema())
+        .required("data")
+        .build()
+        .into()
+}
+
+/// Object schema of [`ProblemDetails`](crate::ProblemDetails) with the constant string `T` as the `type` URI.
+///
+/// Used by the [`PartialSchema`](utoipa::PartialSchema) implementation of [`ProblemDetails`](crate::ProblemDetails).
+pub fn problem_details_schema<T: ConstantString + PartialSchema>() -> RefOr<Schema> {
+    let string = || ObjectBuilder::new().schema_type(SchemaType::Type(Type::String));
+
+    ObjectBuilder::new()
+        .property("type", T::schema())
+        .required("type")
+        .property("title", string())
+        .property(
+            "status",
+            ObjectBuilder::new().schema_type(SchemaType::Type(Type::Integer)),
+        )
+        .property("detail", string())
+        .property("instance", string())
+        .build()
+        .into()
+}
+
+/// JSON response referencing the schema component of `T`.
+///
+/// Used by the [`ToResponse`](utoipa::ToResponse) implementation of [`constant_response`](crate::constant_response).
+pub fn response<T: ToSchema>(description: &str) -> Response {
+    ResponseBuilder::new()
+        .description(description)
+        .content(
+            "application/json",
+            ContentBuilder::new()
+                .schema(Some(Ref::from_schema_name(T::name())))
+                .build(),
+        )
+        .build()
+}
+
+/// Implement [`ToResponse`](utoipa::ToResponse) for an error type with a constant string code.
+///
+/// The response is registered under the type name and references the schema component of the type.
+///
+/// # Example
+/// ```
+/// # extern crate utoipa;
+/// #
+/// use constant_string::{constant_response, constant_string};
+/// use utoipa::{OpenApi, ToSchema};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+///
+/// #[derive(ToSchema)]
+/// struct NotFoundError {
+///     code: NotFoundErrorCode,
+///     message: String,
+/// }
+///
+/// constant_response!(NotFoundError, description = "Resource not found.");
+///
+/// #[derive(OpenApi)]
+/// #[openapi(components(schemas(NotFoundErrorCode, NotFoundError), responses(NotFoundError)))]
+/// struct ApiDoc;
+/// ```
+#[macro_export]
+macro_rules! constant_response {
+    ($name:ident, description = $description:literal $(,)?) => {
+        impl<'__r> $crate::__private::utoipa::ToResponse<'__r> for $name {
+            fn response() -> (
+                &'__r str,
+                $crate::__private::utoipa::openapi::RefOr<
+                    $crate::__private::utoipa::openapi::response::Response,
+                >,
+            ) {
+                (
+                    ::core::stringify!($name),
+                    ::core::convert::Into::into($crate::utoipa::response::<Self>($description)),
+                )
+            }
+        }
+    };
+}
+
+/// Build a `oneOf` schema of error types discriminated by their constant string code.
+///
+/// Takes the discriminator property name and pairs of constant string type and schema type. The mapping is derived
+/// from the constant values and the component names.
+///
+/// # Example
+/// ```
+/// # extern crate utoipa;
+/// #
+/// use constant_string::{constant_one_of, constant_string};
+/// use utoipa::ToSchema;
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// #[derive(ToSchema)]
+/// struct NotFoundError {
+///     code: NotFoundErrorCode,
+/// }
+///
+/// #[derive(ToSchema)]
+/// struct ConflictError {
+///     code: ConflictErrorCode,
+/// }
+///
+/// let schema = constant_one_of!("code", NotFoundErrorCode => NotFoundError, ConflictErrorCode => ConflictError);
+/// ```
+#[macro_export]
+macro_rules! constant_one_of {
+    ($property_name:expr, $($constant:ty => $schema:ty),+ $(,)?) => {
+        $crate::utoipa::one_of(
+            $property_name,
+            [$((
+                <$constant as $crate::ConstantString>::VALUE,
+                <$schema as $crate::__private::utoipa::ToSchema>::name(),
+            )),+],
+        )
+    };
+}
+
+/// Build a `oneOf` schema of [`Event`](crate::Event) types discriminated by their constant string `type`.
+///
+/// Takes pairs of constant string type and payload type. The variants reference the schema components of the events,
+/// which are registered separately.
+///
+/// # Example
+/// ```
+/// # extern crate utoipa;
+/// #
+/// use constant_string::{Event, constant_event_one_of, constant_string};
+/// use utoipa::{ToSchema, openapi::ComponentsBuilder};
+///
+/// constant_string!(UserCreatedEventType, USER_CREATED_EVENT_TYPE, "user.created");
+/// constant_string!(UserDeletedEventType, USER_DELETED_EVENT_TYPE, "user.deleted");
+///
+/// #[derive(ToSchema)]
+/// struct User {
+///     id: u32,
+/// }
+///
+/// let components = ComponentsBuilder::new()
+///     .schema_from::<Event<UserCreatedEventType, User>>()
+///     .schema_from::<Event<UserDeletedEventType, User>>()
+///     .schema(
+///         "UserEvent",
+///         constant_event_one_of!(UserCreatedEventType => User, UserDeletedEventType => User),
+///     )
+///     .build();
+/// ```
+#[macro_export]
+macro_rules! constant_event_one_of {
+    ($($constant:ty => $payload:ty),+ $(,)?) => {
+        $crate::constant_one_of!("type", $($constant => $crate::Event<$constant, $payload>),+)
+    };
+}
+
+/// Define a schema component allowing the values of a group of constant string types.
+///
+/// The defined type implements [`ToSchema`](utoipa::ToSchema) under its own name, with a string schema listing the
+/// value of every member in `enum`.
+///
+/// # Example
+/// ```
+/// # extern crate utoipa;
+/// #
+/// use constant_string::{constant_enum_schema, constant_string};
+/// use utoipa::{OpenApi, ToSchema};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// constant_enum_schema! {
+///     /// Code of an error.
+///     pub struct ErrorCode;
+///     NotFoundErrorCode, ConflictErrorCode
+/// }
+///
+/// #[derive(ToSchema)]
+/// struct Error {
+///     #[schema(value_type = ErrorCode)]
+///     code: String,
+/// }
+///
+/// #[derive(OpenApi)]
+/// #[openapi(components(schemas(ErrorCode, Error)))]
+/// struct ApiDoc;
+/// ```
+#[macro_export]
+macro_rules! constant_enum_schema {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident; $($constant:ty),+ $(,)?) => {
+        $(#[$meta])*
+        $vis struct $name;
+
+        impl $crate::__private::utoipa::PartialSchema for $name {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<
+                $crate::__private::utoipa::openapi::schema::Schema,
+            > {
+                $crate::utoipa::enum_schema([$(<$constant as $crate::ConstantString>::VALUE),+])
+            }
+        }
+
+        impl $crate::__private::utoipa::ToSchema for $name {}
+    };
+}
+
+/// Register schema components of constant string types.
+///
+/// With a list of types, expands to `(name, schema)` pairs for
+/// [`ComponentsBuilder::schemas_from_iter`](utoipa::openapi::ComponentsBuilder::schemas_from_iter). With a leading
+/// `struct Name;`, defines a [`Modify`](utoipa::Modify) implementation adding the components to an OpenAPI document.
+///
+/// # Example
+/// ```
+/// # extern crate utoipa;
+/// #
+/// use constant_string::{constant_string, constant_string_components};
+/// use utoipa::{OpenApi, openapi::ComponentsBuilder};
+///
+/// constant_string!(NotFoundErrorCode, NOT_FOUND_ERROR_CODE, "notFound");
+/// constant_string!(ConflictErrorCode, CONFLICT_ERROR_CODE, "conflict");
+///
+/// let components = ComponentsBuilder::new()
+///     .schemas_from_iter(constant_string_components!(NotFoundErrorCode, ConflictErrorCode))
+///     .build();
+///
+/// constant_string_components! {
+///     struct ErrorCodes;
+///     NotFoundErrorCode, ConflictErrorCode
+/// }
+///
+/// #[derive(OpenApi)]
+/// #[openapi(modifiers(&ErrorCodes))]
+/// struct ApiDoc;
+///
+/// let openapi = ApiDoc::openapi();
+/// assert!(openapi.components.expect("components").schemas.contains_key("NotFoundErrorCode"));
+/// ```
+#[macro_export]
+macro_rules! constant_string_components {
+    (struct $name:ident; $($constant:ty),* $(,)?) => {
+        #[doc = "Add constant string schema components to an OpenAPI document."]
+        pub struct $name;
+
+        impl $crate::__private::utoipa::Modify for $name {
+            fn modify(&self, openapi: &mut $crate::__private::utoipa::openapi::OpenApi) {
+                ::core::iter::Extend::extend(
+                    &mut openapi
+                        .components
+                        .get_or_insert_with(::core::default::Default::default)
+                        .schemas,
+                    $crate::constant_string_components!($($constant),*),
+                );
+            }
+        }
+    };
+    ($($constant:ty),* $(,)?) => {
+        [$((
+            <$constant as $crate::__private::utoipa::ToSchema>::name().into_owned(),
+            <$constant as $crate::__private::utoipa::PartialSchema>::schema(),
+        )),*]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::{ToResponse, ToSchema};
+
+    use crate::constant_string;
+
+    constant_string!(NotFoundCode, NOT_FOUND_CODE, "notFound");
+
+    #[allow(dead_code)]
+    #[derive(ToSchema)]
+    struct NotFound {
+        code: NotFoundCode,
+    }
+
+    constant_response!(NotFound, description = "Resource not found.");
+
+    #[test]
+    fn nullable_schema() {
+        constant_string!(
+            Nullable,
+            NULLABLE,
+            "nullable",
+            schema_const,
+            skip_schema_default,
+            skip_schema_examples,
+        );
+
+        assert_eq!(
+            r#"{"type":["string","null"],"enum":["nullable",null]}"#,
+            serde_json::to_string(&super::nullable_schema::<Nullable>())
+                .expect("serializable value")
+        );
+    }
+
+    #[allow(dead_code)]
+    #[derive(ToSchema)]
+    struct Conflict {
+        code: ConflictCode,
+    }
+
+    constant_string!(ConflictCode, CONFLICT_CODE, "conflict");
+
+    #[test]
+    fn one_of() {
+        assert_eq!(
+            r##"{"oneOf":[{"$ref":"#/components/schemas/NotFound"},{"$ref":"#/components/schemas/Conflict"}],"discriminator":{"propertyName":"code","mapping":{"conflict":"#/components/schemas/Conflict","notFound":"#/components/schemas/NotFound"}}}"##,
+            serde_json::to_string(&constant_one_of!(
+                "code",
+                NotFoundCode => NotFound,
+                ConflictCode => Conflict,
+            ))
+            .expect("serializable value")
+        );
+    }
+
+    #[test]
+    fn components() {
+        use utoipa::{Modify, openapi::OpenApi};
+
+        constant_string_components! {
+            struct Codes;
+            NotFoundCode, ConflictCode
+        }
+
+        let mut openapi = OpenApi::default();
+        Codes.modify(&mut openapi);
+
+        let schemas = openapi.components.expect("components").schemas;
+        assert_eq!(
+            schemas.keys().collect::<Vec<_>>(),
+            ["ConflictCode", "NotFoundCode"]
+        );
+    }
+
+    #[test]
+    fn enum_schema() {
+        use utoipa::PartialSchema;
+
+        constant_enum_schema! {
+            struct Code;
+            NotFoundCode, ConflictCode
+        }
+
+        assert_eq!(Code::name(), "Code");
+        assert_eq!(
+            r#"{"type":"string","enum":["notFound","conflict"]}"#,
+            serde_json::to_string(&Code::schema()).expect("serializable value")
+        );
+    }
+
+    #[test]
+    fn response() {
+        let (name, response) = NotFound::response();
+
+        assert_eq!(name, "NotFound");
+        assert_eq!(
+            r##"{"description":"Resource not found.","content":{"application/json":{"schema":{"$ref":"#/components/schemas/NotFound"}}}}"##,
+            serde_json::to_string(&response).expect("serializable value")
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Utoipa 4 integration.
+//!
+//! Mirrors the `utoipa` integration for projects still on utoipa 4. OpenAPI 3.1 options, such as
+//! [`schema_const`](crate::Options::schema_const), and schema [`extensions`](crate::Options::extensions) are not
+//! supported by utoipa 4 and are ignored.
+
+use utoipa_4::openapi::{
+    Deprecated, RefOr, Required,
+    path::{Parameter, ParameterBuilder, ParameterIn},
+    schema::{ObjectBuilder, Schema, SchemaType},
+};
+
+use crate::{ConstantString, schema};
+
+/// Schema of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the `PartialSchema` and `ToSchema` implementations of [`constant_string`](crate::constant_string).
+pub fn schema<C: ConstantString>() -> RefOr<Schema> {
+    object::<C>()
+        .enum_values((!C::OPTIONS.skip_schema_enum).then_some([C::VALUE]))
+        .build()
+        .into()
+}
+
+/// Schema of the constant string `C` that also allows `null`, for optional fields.
+///
+/// Uses the OpenAPI 3.0 `nullable: true` representation, with `null` in the allowed values.
+pub fn nullable_schema<C: ConstantString>() -> RefOr<Schema> {
+    object::<C>()
+        .nullable(true)
+        .enum_values((!C::OPTIONS.skip_schema_enum).then_some([Some(C::VALUE), None]))
+        .build()
+        .into()
+}
+
+fn object<C: ConstantString>() -> ObjectBuilder {
+    let options = C::OPTIONS;
+
+    ObjectBuilder::new()
+        .schema_type(SchemaType::String)
+        .title(options.title)
+        .description(options.description)
+        .deprecated(options.deprecated.then_some(Deprecated::True))
+        .default((!options.skip_schema_default).then(|| C::VALUE.into()))
+        .example((!options.skip_schema_examples).then(|| C::VALUE.into()))
+        .read_only(options.read_only.then_some(true))
+        .write_only(options.write_only.then_some(true))
+        .pattern(options.schema_pattern.then(|| schema::pattern(C::VALUE)))
+        .min_length(options.schema_length.then(|| schema::length(C::VALUE)))
+        .max_length(options.schema_length.then(|| schema::length(C::VALUE)))
+}
+
+/// Component name of the constant string `C`, applying its [`Options`](crate::Options).
+///
+/// Used by the `ToSchema` implementation of [`constant_string`](crate::constant_string).
+pub fn name<C: ConstantString>() -> &'static str {
+    C::OPTIONS.schema_name.unwrap_or(C::NAME)
+}
+
+/// Parameter of the constant string `C`, with its schema inlined.
+///
+/// Named and placed like the `utoipa` parameter. Used by the `IntoParams` implementation of
+/// [`constant_string`](crate::constant_string).
+pub fn parameter<C: ConstantString>(parameter_in: Option<ParameterIn>) -> Parameter {
+    let options = C::OPTIONS;
+
+    ParameterBuilder::new()
+        .name(options.parameter_name.unwrap_or(C::NAME))
+        .parameter_in(parameter_in.unwrap_or(ParameterIn::Query))
+        .description(options.description)
+        .deprecated(options.deprecated.then_some(Deprecated::True))
+        .required(Required::True)
+        .schema(Some(schema::<C>()))
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa_4::{IntoParams, PartialSchema, ToSchema};
+
+    use crate::constant_string;
+
+    constant_string!(
+        NotFound,
+        NOT_FOUND,
+        "notFound",
+        schema_name = "ErrorCode.NotFound",
+        description = "Resource not found.",
+    );
+
+    #[test]
+    fn utoipa_4() {
+        let (name, schema) = <NotFound as ToSchema>::schema();
+
+        assert_eq!(name, "ErrorCode.NotFound");
+        assert_eq!(
+            serde_json::to_string(&schema).expect("serializable value"),
+            serde_json::to_string(&<NotFound as PartialSchema>::schema())
+                .expect("serializable value")
+        );
+        assert_eq!(
+            r#"{"type":"string","description":"Resource not found.","default":"notFound","enum":["notFound"],"example":"notFound"}"#,
+            serde_json::to_string(&schema).expect("serializable value")
+        );
+        assert_eq!(
+            r#"[{"name":"NotFound","in":"query","description":"Resource not found.","required":true,"schema":{"type":"string","description":"Resource not found.","default":"notFound","enum":["notFound"],"example":"notFound"}}]"#,
+            serde_json::to_string(&NotFound::into_params(|| None)).expect("serializable value")
+        );
+        assert_eq!(
+            r#"{"type":"string","description":"Resource not found.","default":"notFound","enum":["notFound",null],"example":"notFound","nullable":true}"#,
+            serde_json::to_string(&super::nullable_schema::<NotFound>())
+                .expect("serializable value")
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Semantic version constants.
+
+/// Semantic version parsed from a constant string.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Version {
+    /// Major version.
+    pub major: u64,
+    /// Minor version.
+    pub minor: u64,
+    /// Patch version.
+    pub patch: u64,
+}
+
+impl Version {
+    /// Parse a [semantic version](https://semver.org), e.g. `"2.1.0"` or `"1.0.0-alpha.1+build.5"`.
+    ///
+    /// Returns [`None`] if the value is not a valid semantic version.
+    pub const fn parse(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+
+        let (major, index) = match parse_number(bytes, 0) {
+            Some(result) => result,
+            None => return None,
+        };
+        if index >= bytes.len() || bytes[index] != b'.' {
+            return None;
+        }
+
+        let (minor, index) = match parse_number(bytes, index + 1) {
+            Some(result) => result,
+            None => return None,
+        };
+        if index >= bytes.len() || bytes[index] != b'.' {
+            return None;
+        }
+
+        let (patch, mut index) = match parse_number(bytes, index + 1) {
+            Some(result) => result,
+            None => return None,
+        };
+
+        if index < bytes.len() && bytes[index] == b'-' {
+            index = match parse_identifiers(bytes, index + 1, true) {
+                Some(index) => index,
+                None => return None,
+            };
+        }
+
+        if index < bytes.len() && bytes[index] == b'+' {
+            index = match parse_identifiers(bytes, index + 1, false) {
+                Some(index) => index,
+                None => return None,
+            };
+        }
+
+        if index != bytes.len() {
+            return None;
+        }
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
+/// Parse a numeric identifier without leading zeros, returning the number and the index after it.
+const fn parse_number(bytes: &[u8], start: usize) -> Option<(u64, usize)> {
+    let mut index = start;
+    let mut number: u64 = 0;
+
+    while index < bytes.len() && bytes[index].is_ascii_digit() {
+        number = match number.checked_mul(10) {
+            Some(number) => match number.checked_add((bytes[index] - b'0') as u64) {
+                Some(number) => number,
+                None => return None,
+            },
+            None => return None,
+        };
+        index += 1;
+    }
+
+    if index == start || (bytes[start] == b'0' && index - start > 1) {
+        None
+    } else {
+        Some((number, index))
+    }
+}
+
+/// Parse dot-separated pre-release or build identifiers, returning the index after them.
+const fn parse_identifiers(bytes: &[u8], start: usize, pre_release: bool) -> Option<usize> {
+    let mut index = start;
+
+    loop {
+        let identifier_start = index;
+        let mut numeric = true;
+
+        while index < bytes.len() && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'-')
+        {
+            if !bytes[index].is_ascii_digit() {
+                numeric = false;
+            }
+            index += 1;
+        }
+
+        if index == identifier_start {
+            return None;
+        }
+        if pre_release && numeric && bytes[identifier_start] == b'0' && index - identifier_start > 1
+        {
+            return None;
+        }
+
+        if index < bytes.len() && bytes[index] == b'.' {
+            index += 1;
+        } else {
+            return Some(index);
+        }
+    }
+}
+
+/// Implement a constant string containing a semantic version.
+///
+/// The literal is validated as a [semantic version](https://semver.org) at compile time. The version components are
+/// available as `MAJOR`, `MINOR` and `PATCH` associated constants. All other behavior, including exact string matching
+/// for Serde and the Utoipa schema, is identical to [`constant_string`](crate::constant_string).
+///
+/// # Example
+/// ```
+/// # use constant_string::constant_version;
+/// #
+/// constant_version!(ProtocolVersion, PROTOCOL_VERSION, "2.1.0");
+///
+/// assert_eq!(ProtocolVersion::MAJOR, 2);
+/// assert_eq!(ProtocolVersion::MINOR, 1);
+/// assert_eq!(ProtocolVersion::PATCH, 0);
+/// ```
+///
+/// Invalid versions fail to compile:
+/// ```compile_fail
+/// # use constant_string::constant_version;
+/// #
+/// constant_version!(ProtocolVersion, PROTOCOL_VERSION, "2.1");
+/// ```
+#[macro_export]
+macro_rules! constant_version {
+    ($name:ident, $code_name:ident, $code:literal) => {
+        $crate::constant_string!($name, $code_name, $code);
+
+        impl $name {
+            const VERSION: $crate::version::Version = match $crate::version::Version::parse($code) {
+                ::core::option::Option::Some(version) => version,
+                ::core::option::Option::None => ::core::panic!(::core::concat!(
+                    "invalid semantic version ",
+                    ::core::stringify!($code)
+                )),
+            };
+
+            /// Major version.
+            pub const MAJOR: u64 = Self::VERSION.major;
+
+            /// Minor version.
+            pub const MINOR: u64 = Self::VERSION.minor;
+
+            /// Patch version.
+            pub const PATCH: u64 = Self::VERSION.patch;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use super::Version;
+
+    constant_version!(ProtocolVersion, PROTOCOL_VERSION, "2.1.0-rc.1+build.5");
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Some(Version {
+                major: 2,
+                minor: 1,
+                patch: 0
+            }),
+            Version::parse("2.1.0")
+        );
+        assert_eq!(
+            Some(Version {
+                major: 1,
+                minor: 0,
+                patch: 0
+            }),
+            Version::parse("1.0.0-alpha.1+build.05")
+        );
+
+        assert_eq!(None, Version::parse(""));
+        assert_eq!(None, Version::parse("2.1"));
+        assert_eq!(None, Version::parse("2.1.0."));
+        assert_eq!(None, Version::parse("02.1.0"));
+        assert_eq!(None, Version::parse("2.1.0-"));
+        assert_eq!(None, Version::parse("2.1.0-01"));
+        assert_eq!(None, Version::parse("2.1.0+"));
+        assert_eq!(None, Version::parse("2.1.0-alpha..1"));
+        assert_eq!(None, Version::parse("v2.1.0"));
+        assert_eq!(None, Version::parse("99999999999999999999.0.0"));
+    }
+
+    #[test]
+    fn constant_version() {
+        assert_eq!(ProtocolVersion.deref(), "2.1.0-rc.1+build.5");
+        assert_eq!(ProtocolVersion::MAJOR, 2);
+        assert_eq!(ProtocolVersion::MINOR, 1);
+        assert_eq!(ProtocolVersion::PATCH, 0);
+    }
+}